@@ -106,7 +106,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
 async fn handle_server_message(msg: ServerToClient, player_name: &str) {
     match msg {
-        ServerToClient::Hello { your_id } => {
+        ServerToClient::Hello { your_id, .. } => {
             println!("👋 Welcome! Your ID: {}", your_id);
         }
         ServerToClient::Joined { snapshot, your_seat, your_hand } => {
@@ -120,13 +120,13 @@ async fn handle_server_message(msg: ServerToClient, player_name: &str) {
         ServerToClient::YourHand { hand } => {
             println!("🃏 Your cards updated: {} down cards", hand.down_cards.len());
         }
-        ServerToClient::Error { message } => {
+        ServerToClient::Error { message, .. } => {
             println!("❌ Error: {}", message);
         }
         ServerToClient::Info { message } => {
             println!("ℹ️  {}", message);
         }
-        ServerToClient::Showdown { winners7, winners27, payouts, reveal } => {
+        ServerToClient::Showdown { winners7, winners27, payouts, reveal, actions } => {
             println!("\n🎭 SHOWDOWN!");
             println!("🏆 7-or-under winners: {:?}", winners7);
             println!("🏆 27-or-under winners: {:?}", winners27);
@@ -134,6 +134,9 @@ async fn handle_server_message(msg: ServerToClient, player_name: &str) {
             for (player_id, cards) in reveal {
                 println!("🃏 Player {}: {:?}", player_id, cards);
             }
+            for action in actions {
+                println!("📜 Round {}: {}", action.round, action);
+            }
         }
         ServerToClient::ChatMessage { player_name: sender, message, scope, .. } => {
             println!("💬 [{:?}] {}: {}", scope, sender, message);
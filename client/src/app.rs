@@ -1,11 +1,13 @@
 // client/src/app.rs
-use std::time::Duration;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
 use iced::{Element, Length, Subscription, Task};
 use iced_widget::{button, column, container, horizontal_rule, row, text, text_input, Space};
 
 use uuid::Uuid;
 use rand::Rng;
-use cctmog_protocol::{ClientToServer, GameVariant, MessageScope, Phase, PublicRoom, ServerToClient};
+use cctmog_protocol::{ClientToServer, GameVariant, MessageScope, Phase, ServerToClient, PROTOCOL_VERSION};
+use cctmog_protocol::names::{clean_name, NAME_MAX};
 use iced::Alignment;
 use crate::messages::Msg;
 use crate::{cards_row_svg, CardSize, render_action_bar};
@@ -18,6 +20,151 @@ use crate::ui::shared::{brand_logo, footer};
 
 pub use crate::states::AppState;
 
+/// Below this window width the game view stacks the table above the
+/// action/chat column and uses smaller cards.
+const COMPACT_LAYOUT_WIDTH: f32 = 1200.0;
+
+/// Environment variable holding the streamer delay in milliseconds.
+const STREAM_DELAY_ENV: &str = "CCTMOG_STREAM_DELAY_MS";
+
+/// Environment variable: ask before a bet or raise that commits at least
+/// this percentage of your stack (unset or 0 = never ask).
+const CONFIRM_BET_ENV: &str = "CCTMOG_CONFIRM_BET_PCT";
+
+/// Threshold the "Confirm big bets" toggle uses when the environment
+/// doesn't set one.
+const CONFIRM_BET_DEFAULT_PCT: u64 = 50;
+
+/// Percentage set in `CONFIRM_BET_ENV`, or 0 when unset or unreadable.
+fn confirm_bet_pct_from_env() -> u64 {
+    std::env::var(CONFIRM_BET_ENV)
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(0)
+}
+
+/// Environment variable: set to `1` to ready up automatically between hands.
+const AUTO_READY_ENV: &str = "CCTMOG_AUTO_READY";
+
+/// One-line summary of who's watching, e.g. "Watching: Alice, Bob (+3)".
+fn watching_summary(spectators: &[cctmog_protocol::PublicSpectator]) -> Option<String> {
+    const SHOWN: usize = 2;
+    if spectators.is_empty() {
+        return None;
+    }
+    let names: Vec<&str> = spectators.iter().take(SHOWN).map(|s| s.name.as_str()).collect();
+    let more = spectators.len().saturating_sub(SHOWN);
+    Some(if more > 0 {
+        format!("Watching: {} (+{})", names.join(", "), more)
+    } else {
+        format!("Watching: {}", names.join(", "))
+    })
+}
+
+/// "Waiting for all players to elect to start (3/5)".
+fn election_status(s: &cctmog_protocol::PublicRoom) -> String {
+    format!("Waiting for all players to elect to start ({}/{})", s.elected_players.len(), s.players.len())
+}
+
+/// Commands that only make sense from a seat at the table.
+fn is_gameplay_command(cmd: &ClientToServer) -> bool {
+    matches!(
+        cmd,
+        ClientToServer::SitReady
+            | ClientToServer::SitUnready
+            | ClientToServer::ElectToStart
+            | ClientToServer::DelegateDealer { .. }
+            | ClientToServer::SetStatus { .. }
+            | ClientToServer::StartHand
+            | ClientToServer::ForceStart
+            | ClientToServer::TakeCard
+            | ClientToServer::Stand
+            | ClientToServer::Fold
+            | ClientToServer::ShowCards
+            | ClientToServer::Muck
+            | ClientToServer::SetPreAction { .. }
+            | ClientToServer::RabbitHunt
+            | ClientToServer::Check
+            | ClientToServer::Bet
+            | ClientToServer::Call
+            | ClientToServer::Raise
+            | ClientToServer::Rebuy
+            | ClientToServer::CloseTable
+            | ClientToServer::KickPlayer { .. }
+    )
+}
+
+/// Why the server would turn this betting action down, judged from the last
+/// snapshot. Only the obvious cases; the server still has the final say.
+fn illegal_action(s: &cctmog_protocol::PublicRoom, cmd: &ClientToServer) -> Option<&'static str> {
+    match cmd {
+        ClientToServer::Check if s.current_bet != 0 => Some("Can't check facing a bet"),
+        ClientToServer::Call if s.current_bet == 0 => Some("Nothing to call"),
+        ClientToServer::Bet if s.current_bet != 0 => Some("There's already a bet: raise instead"),
+        ClientToServer::Raise if s.raises_made >= s.max_raises => Some("Raise cap reached for this round"),
+        _ => None,
+    }
+}
+
+/// Environment variable: set to `1` to show your hand sorted by rank.
+const SORT_HAND_ENV: &str = "CCTMOG_SORT_HAND";
+
+/// How often to measure round-trip time to the server while connected.
+const PING_EVERY: Duration = Duration::from_secs(5);
+
+/// Pings with no answer after this long are forgotten.
+const PING_GIVE_UP: Duration = Duration::from_secs(30);
+
+/// Lounge and global messages fetched for the lounge's recent activity panel.
+const RECENT_ACTIVITY_LIMIT: usize = 20;
+
+/// Give up on a connection attempt that hasn't succeeded after this long.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Events that reveal the hand or table state; these are what a stream
+/// delay holds back. Chat, info and errors still arrive immediately.
+fn is_stream_delayed(ev: &ServerToClient) -> bool {
+    matches!(
+        ev,
+        ServerToClient::Joined { .. }
+            | ServerToClient::UpdateState { .. }
+            | ServerToClient::YourHand { .. }
+            | ServerToClient::YourTurn { .. }
+            | ServerToClient::Showdown { .. }
+            | ServerToClient::CardsShown { .. }
+    )
+}
+
+/// One chat line as received, kept with its server timestamp so history
+/// and live messages can be shown in order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChatEntry {
+    pub player_name: String,
+    pub message: String,
+    pub scope: MessageScope,
+    pub timestamp: String, // RFC 3339, as sent by the server
+    pub recipient: Option<Uuid>, // set for private messages
+}
+
+impl ChatEntry {
+    /// "[HH:MM] [Scope] Name: message" for the in-game chat panel.
+    pub fn line(&self) -> String {
+        let scope = match self.scope {
+            MessageScope::Match => "[Match]",
+            MessageScope::Group => "[Group]",
+            MessageScope::Global => "[Global]",
+            MessageScope::Private => "[Private]",
+        };
+        format!(
+            "[{}] {} {}: {}",
+            crate::ui::views::comment_time(&self.timestamp),
+            scope,
+            self.player_name,
+            self.message
+        )
+    }
+}
+
 #[derive(Clone)]
 pub struct App {
     pub app_state: AppState,
@@ -28,10 +175,15 @@ pub struct App {
 
     pub connecting: bool,
     pub connected: bool,
+    pub connect_started: Option<Instant>, // when the current attempt began, for the timeout
+    pub resync_pending: bool, // reconnected to our table; the next snapshot decides the screen
 
     pub your_id: Option<Uuid>,
     pub your_seat: Option<usize>,
     pub your_hand: cctmog_protocol::PrivateHand,
+    pub turn_options: Option<cctmog_protocol::TurnOptions>, // set by the server when it's our turn
+    pub spectating: bool, // joined a full table and waiting for a seat
+    pub watch_only: bool, // joined with JoinAsSpectator, so don't queue for a seat
     pub snapshot: Option<cctmog_protocol::PublicRoom>,
 
     pub tx_out: Option<iced::futures::channel::mpsc::UnboundedSender<ClientToServer>>,
@@ -40,7 +192,9 @@ pub struct App {
     pub auto_started: bool,
 
     // Chat state
-    pub chat_messages: Vec<(String, String, MessageScope, String)>, // (player_name, message, scope, timestamp)
+    pub chat_messages: Vec<ChatEntry>, // oldest first
+    pub recent_activity: Vec<ChatEntry>, // fetched lounge/global history, oldest first
+    pub lounge_roster: Vec<cctmog_protocol::LoungePlayer>, // everyone on the server and where they are
     pub chat_input: String,
     pub chat_scope: MessageScope,
 
@@ -59,6 +213,12 @@ pub struct App {
     pub table_limit_small: String,
     pub table_limit_big: String,
     pub table_max_raises: String,
+    pub table_min_players: String,
+    pub table_rake_percent: String,
+    pub table_rake_cap: String, // empty = uncapped
+    pub table_muck_window: String, // seconds; empty or 0 = reveal at once
+    pub table_auto_dealer: bool,
+    pub table_rabbit_hunt: bool,
     pub table_creation_error: Option<String>,
     pub pending_table_creation: Option<ClientToServer>,
 
@@ -66,6 +226,9 @@ pub struct App {
     pub comment_input: String,
     pub game_comments: Vec<cctmog_protocol::GameComment>,
     pub ready_to_continue: bool,
+    pub hand_actions: Vec<cctmog_protocol::ActionEvent>,
+    pub hand_variant: Option<GameVariant>, // what was played, even if the next game differs
+    pub comment_about: Option<cctmog_protocol::CommentTarget>,
 
     // Embedded server state
     pub embedded_server: Option<crate::embedded_server::EmbeddedServer>,
@@ -95,6 +258,59 @@ pub struct App {
     // Username selection dropdown
     pub available_usernames: Vec<String>,
     pub selected_username: Option<String>,
+
+    // Tutorial state
+    pub tutorial_step: usize,
+
+    // Streamer privacy: hold hand/state events back this long (0 = off)
+    pub stream_delay_ms: u64,
+    pub delayed_events: std::collections::VecDeque<(std::time::Instant, ServerToClient)>,
+
+    // Large bet confirmation: threshold in percent of stack (0 = off)
+    pub confirm_bet_pct: u64,
+    pub pending_bet: Option<(ClientToServer, u64)>, // held back until confirmed, with its size
+
+    // Last betting action we refused to send, until the next state update
+    pub action_warning: Option<String>,
+
+    // Why we were last taken out of a table (or failed to reach one), shown in the lounge
+    pub removed_reason: Option<String>,
+
+    // Our stack after each showdown this session, for the sparkline
+    pub chip_history: Vec<u64>,
+
+    // Net chips won or lost by everyone we've played with this session
+    pub leaderboard: Vec<(Uuid, String, i64)>,
+    pub leaderboard_by_name: bool,
+
+    // Ready up / continue between hands without clicking, unless sitting out
+    pub auto_ready: bool,
+    pub sitting_out: bool,
+
+    // Check/fold automatically every street until turned off
+    pub fold_to_any_bet: bool,
+
+    // Seat status box ("BRB"), sent with SetStatus
+    pub status_input: String,
+
+    // Muck a losing hand as soon as the muck window opens (table toggle)
+    pub auto_muck: bool,
+    pub muck_sent: bool, // already mucked in this showdown
+
+    // Spectator list on the game screen, collapsed by default
+    pub show_spectators: bool,
+    pub dm_recipient: Option<(Uuid, String)>, // chat goes privately to this spectator
+
+    // Show our own cards sorted by rank (display only)
+    pub sort_hand: bool,
+
+    // Set when the server's protocol version doesn't match ours
+    pub version_error: Option<String>,
+
+    // Round-trip time to the server, from Ping/Pong
+    pub pings_in_flight: HashMap<u64, Instant>,
+    pub next_ping_nonce: u64,
+    pub latency_ms: Option<u64>,
 }
 
 impl Default for App {
@@ -115,16 +331,23 @@ impl Default for App {
             name: String::new(), // Will be set when user selects from dropdown
             room: "room-1".into(),
             connecting: false,
+            connect_started: None,
+            resync_pending: false,
             connected: false,
             your_id: None,
             your_seat: None,
             your_hand: cctmog_protocol::PrivateHand { down_cards: vec![] },
+            turn_options: None,
+            spectating: false,
+            watch_only: false,
             snapshot: None,
             tx_out: None,
             log: Vec::new(),
             show_asset_test: false,
             auto_started: false,
             chat_messages: Vec::new(),
+            recent_activity: Vec::new(),
+            lounge_roster: Vec::new(),
             chat_input: String::new(),
             chat_scope: MessageScope::Match,
             available_tables: Vec::new(),
@@ -138,6 +361,12 @@ impl Default for App {
             table_limit_small: "10".to_string(),
             table_limit_big: "20".to_string(),
             table_max_raises: "3".to_string(),
+            table_min_players: "2".to_string(),
+            table_rake_percent: "0".to_string(),
+            table_rake_cap: String::new(),
+            table_muck_window: "10".to_string(),
+            table_auto_dealer: false,
+            table_rabbit_hunt: false,
             table_creation_error: None,
             pending_table_creation: None,
 
@@ -145,6 +374,9 @@ impl Default for App {
             comment_input: String::new(),
             game_comments: Vec::new(),
             ready_to_continue: false,
+            hand_actions: Vec::new(),
+            hand_variant: None,
+            comment_about: None,
 
             // Embedded server defaults
             embedded_server: None,
@@ -174,32 +406,239 @@ impl Default for App {
             // Username selection fields
             available_usernames: available_usernames,
             selected_username: None,
+
+            // Tutorial defaults
+            tutorial_step: 0,
+            stream_delay_ms: std::env::var(STREAM_DELAY_ENV)
+                .ok()
+                .and_then(|v| v.trim().parse().ok())
+                .unwrap_or(0),
+            delayed_events: std::collections::VecDeque::new(),
+            confirm_bet_pct: confirm_bet_pct_from_env(),
+            pending_bet: None,
+            action_warning: None,
+            removed_reason: None,
+            chip_history: Vec::new(),
+            leaderboard: Vec::new(),
+            leaderboard_by_name: false,
+            auto_ready: std::env::var(AUTO_READY_ENV).is_ok_and(|v| v.trim() == "1"),
+            sitting_out: false,
+            fold_to_any_bet: false,
+            status_input: String::new(),
+            auto_muck: false,
+            muck_sent: false,
+            show_spectators: false,
+            dm_recipient: None,
+            sort_hand: std::env::var(SORT_HAND_ENV).is_ok_and(|v| v.trim() == "1"),
+            version_error: None,
+            pings_in_flight: HashMap::new(),
+            next_ping_nonce: 0,
+            latency_ms: None,
         }
     }
 }
 
 impl App {
-    pub fn check_for_available_host(&mut self) {
-        // Check if there's a host announcement file
-        if let Ok(host_info) = std::fs::read_to_string("/tmp/cctmog_host") {
-            if let Some((name, port_str)) = host_info.trim().split_once(':') {
-                if let Ok(port) = port_str.parse::<u16>() {
-                    // Only update if we don't already have this info
-                    if self.host_name.as_ref() != Some(&name.to_string()) || self.host_server_port != Some(port) {
-                        self.host_name = Some(name.to_string());
-                        self.host_server_port = Some(port);
-                        self.log(format!("🔍 Found host: {} on port {}", name, port));
-                    } else {
-                        self.log(format!("✓ Already connected to host: {} on port {}", name, port));
-                    }
-                } else {
-                    self.log("⚠️ Invalid port in host file".to_string());
+    /// Our cards in the order we show them: sorted if the player asked for
+    /// it, otherwise as dealt. The hand itself is never reordered.
+    pub fn display_cards(&self, cards: &[cctmog_protocol::Card]) -> Vec<cctmog_protocol::Card> {
+        let mut cards = cards.to_vec();
+        if self.sort_hand {
+            cards.sort();
+        }
+        cards
+    }
+
+    /// Auto-muck applies once per showdown, while the muck window is open,
+    /// to a hand we still hold that isn't among the winners.
+    pub(crate) fn should_auto_muck(&self, s: &cctmog_protocol::PublicRoom) -> bool {
+        self.auto_muck
+            && !self.muck_sent
+            && s.phase == Phase::Showdown
+            && !s.showdown_winners.is_empty()
+            && self.my_player(s).is_some_and(|me| !me.folded && !s.showdown_winners.contains(&me.id))
+    }
+
+    /// Name of our best hand so far in a community game, e.g. "Pair of
+    /// Kings". `None` before the flop and in 7/27.
+    pub(crate) fn hand_strength(&self, s: &cctmog_protocol::PublicRoom) -> Option<String> {
+        cctmog_protocol::eval::best_community_hand(s.game_variant, &self.your_hand.down_cards, &s.community_cards)
+            .map(|hand| cctmog_protocol::eval::describe(&hand))
+    }
+
+    /// With fold-to-any-bet on, keep a check/fold queued while we're still
+    /// in a betting round. The server plays it once and drops it at the end
+    /// of the street, so it's re-sent on each update.
+    pub(crate) fn wants_check_fold(&self, s: &cctmog_protocol::PublicRoom) -> bool {
+        self.fold_to_any_bet
+            && s.phase == Phase::Acting
+            && s.in_betting
+            && self.my_player(s).is_some_and(|me| !me.folded)
+    }
+
+    /// Our own seat in a snapshot, by id or seat number.
+    pub fn my_player<'a>(&self, s: &'a cctmog_protocol::PublicRoom) -> Option<&'a cctmog_protocol::PublicPlayer> {
+        s.players.iter().find(|p| {
+            self.your_id.map(|id| p.id == id).unwrap_or(false)
+                || self.your_seat.map(|seat| p.seat == seat).unwrap_or(false)
+        })
+    }
+
+    /// True when we're watching rather than seated: our id isn't among the
+    /// snapshot's players. Deliberately ignores `your_seat`, which can be
+    /// left over from an earlier seat.
+    pub(crate) fn is_spectator(&self, s: &cctmog_protocol::PublicRoom) -> bool {
+        self.your_id.is_none_or(|id| s.players.iter().all(|p| p.id != id))
+    }
+
+    /// Add a chat line in timestamp order, after any with the same stamp,
+    /// so a history fetch can merge older messages in behind live ones.
+    pub(crate) fn push_chat(&mut self, entry: ChatEntry) {
+        let at = self.chat_messages.partition_point(|m| m.timestamp <= entry.timestamp);
+        self.chat_messages.insert(at, entry);
+    }
+
+    /// Fold one showdown into the session leaderboard. Everyone in the
+    /// timeline paid the ante plus what they put in; payouts come back on
+    /// top. Antes come from the snapshot, which still shows the hand played.
+    pub(crate) fn record_showdown(&mut self, payouts: &[(Uuid, u64)], actions: &[cctmog_protocol::ActionEvent]) {
+        let ante = self.snapshot.as_ref().map_or(0, |s| s.ante);
+        let mut net: Vec<(Uuid, String, i64)> = vec![];
+        for a in actions {
+            match net.iter_mut().find(|(id, _, _)| *id == a.player_id) {
+                Some((_, _, chips)) => *chips -= a.amount as i64,
+                None => net.push((a.player_id, a.player_name.clone(), -(ante as i64) - a.amount as i64)),
+            }
+        }
+        for &(id, won) in payouts {
+            match net.iter_mut().find(|(pid, _, _)| *pid == id) {
+                Some((_, _, chips)) => *chips += won as i64,
+                None => {
+                    let name = self
+                        .snapshot
+                        .as_ref()
+                        .and_then(|s| s.players.iter().find(|p| p.id == id))
+                        .map_or_else(|| "?".to_string(), |p| p.name.clone());
+                    net.push((id, name, won as i64 - ante as i64));
                 }
-            } else {
-                self.log("⚠️ Invalid format in host file".to_string());
             }
+        }
+
+        for (id, name, chips) in net {
+            match self.leaderboard.iter_mut().find(|(pid, _, _)| *pid == id) {
+                Some(row) => {
+                    row.1 = name; // latest name wins
+                    row.2 += chips;
+                }
+                None => self.leaderboard.push((id, name, chips)),
+            }
+        }
+    }
+
+    /// Leaderboard rows, biggest winner first or alphabetical.
+    pub(crate) fn leaderboard_rows(&self) -> Vec<(&str, i64)> {
+        let mut rows: Vec<(&str, i64)> = self.leaderboard.iter().map(|(_, name, net)| (name.as_str(), *net)).collect();
+        if self.leaderboard_by_name {
+            rows.sort_by_key(|(name, _)| name.to_lowercase());
         } else {
-            self.log("🔍 No host available - no host file found".to_string());
+            rows.sort_by_key(|(_, net)| std::cmp::Reverse(*net));
+        }
+        rows
+    }
+
+    /// (ready, seated) during the comments phase, for the Continue button.
+    pub(crate) fn continue_progress(&self) -> Option<(usize, usize)> {
+        let s = self.snapshot.as_ref().filter(|s| s.phase == Phase::Comments)?;
+        Some((s.players.iter().filter(|p| p.ready).count(), s.players.len()))
+    }
+
+    /// Post-hand comments grouped by what they're about: general remarks
+    /// first, then each round or play in the order it was first mentioned.
+    pub(crate) fn comment_groups(&self) -> Vec<(Option<&cctmog_protocol::CommentTarget>, Vec<&cctmog_protocol::GameComment>)> {
+        let mut groups: Vec<(Option<&cctmog_protocol::CommentTarget>, Vec<&cctmog_protocol::GameComment>)> = vec![];
+        for comment in &self.game_comments {
+            match groups.iter_mut().find(|(about, _)| *about == comment.about.as_ref()) {
+                Some((_, comments)) => comments.push(comment),
+                None => groups.push((comment.about.as_ref(), vec![comment])),
+            }
+        }
+        groups.sort_by_key(|(about, _)| about.is_some());
+        groups
+    }
+
+    /// Mirrors the server: the dealer once one is set, otherwise whoever
+    /// sits in the dealer seat.
+    pub(crate) fn is_dealer_or_host(&self, s: &cctmog_protocol::PublicRoom) -> bool {
+        self.your_id.is_some()
+            && s.current_dealer_id.or_else(|| s.players.get(s.dealer_seat).map(|p| p.id)) == self.your_id
+    }
+
+    /// True when the player after the one acting now is us, i.e. we're
+    /// "on deck". Skips players who can't act this round: folded, standing
+    /// in a draw round, or all-in during betting.
+    pub(crate) fn next_to_act_is_me(&self, s: &cctmog_protocol::PublicRoom) -> bool {
+        let me = match self.my_player(s) {
+            Some(me) => me,
+            None => return false,
+        };
+        if s.phase != Phase::Acting || s.to_act_seat == me.seat || me.folded {
+            return false;
+        }
+
+        let mut order: Vec<&cctmog_protocol::PublicPlayer> = s.players.iter().collect();
+        order.sort_by_key(|p| p.seat);
+        let can_act = |p: &cctmog_protocol::PublicPlayer| {
+            !p.folded && if s.in_betting { p.chips > 0 } else { !p.standing }
+        };
+        let after = order.iter().position(|p| p.seat == s.to_act_seat).map_or(0, |i| i + 1);
+        order
+            .iter()
+            .cycle()
+            .skip(after)
+            .take(order.len())
+            .find(|p| p.seat != s.to_act_seat && can_act(p))
+            .is_some_and(|p| p.seat == me.seat)
+    }
+
+    /// Chips this bet or raise would put in, if it's big enough to need
+    /// confirming under `confirm_bet_pct`.
+    pub fn bet_needing_confirm(&self, is_raise: bool) -> Option<u64> {
+        if self.confirm_bet_pct == 0 {
+            return None;
+        }
+        let opts = self.turn_options.as_ref()?;
+        let amount = if is_raise {
+            opts.can_call.unwrap_or(0) + opts.can_raise?
+        } else {
+            opts.can_bet?
+        };
+        let me = self.my_player(self.snapshot.as_ref()?)?;
+        (amount * 100 >= self.confirm_bet_pct * me.chips).then_some(amount)
+    }
+
+    /// Pick up a host from the lounge's volunteered hosts (sent with every
+    /// `LoungeUpdate`), skipping ourselves.
+    pub fn check_for_available_host(&mut self) {
+        let found = self
+            .available_hosts
+            .iter()
+            .find(|(name, _)| *name != self.name)
+            .cloned();
+        match found {
+            Some((name, port)) => {
+                // Only log when the host actually changes
+                if self.host_name.as_ref() != Some(&name) || self.host_server_port != Some(port) {
+                    self.log(format!("🔍 Found host: {} on port {}", name, port));
+                    self.host_name = Some(name);
+                    self.host_server_port = Some(port);
+                }
+            }
+            None => {
+                if self.host_name.take().is_some() {
+                    self.host_server_port = None;
+                    self.log("🔍 No host available".to_string());
+                }
+            }
         }
     }
 
@@ -257,6 +696,20 @@ impl App {
         match msg {
             // Handle splash screen timer
             Msg::Tick => {
+                if !self.connecting {
+                    self.connect_started = None;
+                } else if self.connect_started.get_or_insert_with(Instant::now).elapsed() >= CONNECT_TIMEOUT {
+                    let reason = format!(
+                        "Couldn't reach {} after {}s. Is the server running?",
+                        self.url,
+                        CONNECT_TIMEOUT.as_secs()
+                    );
+                    self.log(format!("[ws error] {}", reason));
+                    let task = self.update(Msg::BackToHome);
+                    self.removed_reason = Some(reason);
+                    return task;
+                }
+
                 if self.app_state == AppState::Splash {
                     if let Some(start_time) = self.splash_start_time {
                         if start_time.elapsed() >= Duration::from_secs(3) {
@@ -271,9 +724,6 @@ impl App {
                             self.dealer_splash_start_time = None;
                         }
                     }
-                } else if self.app_state == AppState::Lounge && !self.is_hosting {
-                    // Periodic host discovery for non-hosting clients
-                    self.check_for_available_host();
                 }
             }
 
@@ -300,6 +750,10 @@ impl App {
                 self.connecting = true;
             }
 
+            Msg::FetchChatHistory => {
+                self.send(ClientToServer::FetchChatHistory { limit: RECENT_ACTIVITY_LIMIT });
+            }
+
             Msg::BrowseTables => {
                 self.app_state = AppState::TableBrowser;
                 self.send(ClientToServer::ListTables);
@@ -311,10 +765,16 @@ impl App {
                 return self.update(Msg::ConnectToggle);
             }
 
+            Msg::CancelConnect => {
+                self.log("connection cancelled");
+                return self.update(Msg::BackToHome);
+            }
+
             Msg::BackToHome => {
                 // Reset connection state and go back to lounge
                 self.app_state = AppState::Lounge;
                 self.connecting = false;
+                self.connect_started = None;
                 self.connected = false;
                 self.tx_out = None;
                 self.snapshot = None;
@@ -352,14 +812,15 @@ impl App {
                 self.connecting = false;
                 self.log(format!("connected to {}", self.url));
 
+                // Back at a table we were already at: the server may have moved
+                // on (dealer or game picked), so ask where things stand
+                let rejoining = self.room != "lounge" && self.snapshot.as_ref().is_some_and(|s| s.room == self.room);
+
                 // Only auto-join if we're in a connecting state that expects to join a room
                 if self.app_state == AppState::ConnectOverlay {
                     if self.in_lounge && self.room == "lounge" {
-                        // Join lounge instead of regular game room
-                        println!("🚪 Auto-joining lounge");
-                        self.send(ClientToServer::JoinLounge {
-                            name: self.name.clone(),
-                        });
+                        // The subscription already sent JoinLounge on connect;
+                        // sending it again would register us twice
                         self.log("🚪 Joining lounge");
                         self.app_state = AppState::Lounge; // Go back to lounge state
                     } else {
@@ -392,24 +853,68 @@ impl App {
                     self.app_state = AppState::Game;
                     self.log("connected to game");
                 }
+
+                if rejoining {
+                    self.resync_pending = true;
+                    self.send(ClientToServer::Resync);
+                }
             }
 
-            Msg::WsEvent(ev) => match ev {
-                ServerToClient::Hello { your_id } => {
+            Msg::WsEvent(ev) if self.stream_delay_ms > 0 && is_stream_delayed(&ev) => {
+                let delay = Duration::from_millis(self.stream_delay_ms);
+                self.delayed_events.push_back((std::time::Instant::now() + delay, ev));
+                return Task::perform(tokio::time::sleep(delay), |_| Msg::ReleaseDelayedEvents);
+            }
+            Msg::ReleaseDelayedEvents => {
+                // Replay everything that's due, in arrival order
+                let now = std::time::Instant::now();
+                let mut tasks = vec![];
+                while self.delayed_events.front().is_some_and(|(due, _)| *due <= now) {
+                    if let Some((_, ev)) = self.delayed_events.pop_front() {
+                        tasks.push(self.update(Msg::DelayedWsEvent(ev)));
+                    }
+                }
+                return Task::batch(tasks);
+            }
+            Msg::WsEvent(ev) | Msg::DelayedWsEvent(ev) => match ev {
+                ServerToClient::Hello { your_id, protocol_version } => {
+                    if protocol_version != PROTOCOL_VERSION {
+                        // Anything after this may not decode; drop the connection
+                        let message = format!(
+                            "Please update your client: the server speaks protocol v{}, this client v{}.",
+                            protocol_version, PROTOCOL_VERSION
+                        );
+                        self.log(message.clone());
+                        self.version_error = Some(message);
+                        self.connected = false;
+                        self.connecting = false;
+                        self.tx_out = None;
+                        return Task::none();
+                    }
+                    self.version_error = None;
+                    self.pings_in_flight.clear();
+                    self.latency_ms = None;
                     self.your_id = Some(your_id);
                     self.log(format!("hello: {}", your_id));
                 }
                 ServerToClient::Joined { snapshot, your_seat, your_hand } => {
+                    self.removed_reason = None;
                     println!("🎰 Joined as player in seat {}", your_seat);
+                    self.app_state = crate::states::app_state_for_phase(&snapshot.phase);
                     self.snapshot = Some(snapshot);
                     self.your_seat = Some(your_seat);
                     self.your_hand = your_hand;
+                    self.turn_options = None;
+                    self.spectating = false;
                     self.auto_started = false;
-                    self.app_state = AppState::Game; // Now transition to Game state
                     self.log(format!("🎮 Joined communal game as player: seat {}", your_seat));
                     self.send(ClientToServer::SitReady);
                 }
                 ServerToClient::UpdateState { snapshot } => {
+                    // The server follows up with YourTurn if it's still our move
+                    self.turn_options = None;
+                    self.pending_bet = None;
+                    self.action_warning = None;
                     let names: Vec<String> = snapshot.players.iter()
                         .map(|p| format!("{}({})", p.name, &p.id.to_string()[..8]))
                         .collect();
@@ -419,6 +924,28 @@ impl App {
                         snapshot.to_act_seat, snapshot.players.len(), names.join(", ")
                     ));
 
+                    // Auto-ready for the next hand unless we're sitting out
+                    let auto_ready = self.auto_ready && !self.sitting_out && !self.spectating;
+                    if auto_ready
+                        && snapshot.phase == Phase::Lobby
+                        && self.my_player(&snapshot).is_some_and(|me| !me.ready)
+                    {
+                        self.log("auto-ready");
+                        self.send(ClientToServer::SitReady);
+                    }
+
+                    if self.wants_check_fold(&snapshot) {
+                        self.send(ClientToServer::SetPreAction { pre_action: Some(cctmog_protocol::PreAction::CheckFold) });
+                    }
+
+                    if self.should_auto_muck(&snapshot) {
+                        self.muck_sent = true;
+                        self.log("auto-muck");
+                        self.send(ClientToServer::Muck);
+                    } else if snapshot.phase != Phase::Showdown {
+                        self.muck_sent = false;
+                    }
+
                     if snapshot.phase == Phase::Lobby {
                         let all_ready = snapshot.players.iter().all(|p| p.ready) && snapshot.players.len() >= 2;
                         if all_ready && !self.auto_started {
@@ -430,26 +957,68 @@ impl App {
                         self.auto_started = false;
                     }
 
-                    // Handle phase transitions
-                    if snapshot.phase == Phase::Comments && self.app_state == AppState::Game {
-                        self.app_state = AppState::Comments;
-                        self.game_comments.clear(); // Clear previous comments
-                        self.ready_to_continue = false;
-                    } else if snapshot.phase == Phase::Lobby && self.app_state == AppState::Comments {
-                        self.app_state = AppState::Game;
+                    // Follow the server: when the phase moves on (or after a
+                    // resync) the phase decides the screen. Within a phase the
+                    // local navigation (e.g. the host picking a dealer) stands.
+                    let phase_changed = self.snapshot.as_ref().map(|s| &s.phase) != Some(&snapshot.phase);
+                    if self.app_state.is_table_screen() && (phase_changed || self.resync_pending) {
+                        let target = crate::states::app_state_for_phase(&snapshot.phase);
+                        if target == AppState::Comments && self.app_state != AppState::Comments {
+                            // First snapshot after the showdown: payouts are in
+                            if let Some(chips) = self.my_player(&snapshot).map(|me| me.chips) {
+                                self.chip_history.push(chips);
+                            }
+                            self.game_comments.clear(); // Clear previous comments
+                            self.comment_about = None;
+                            self.ready_to_continue = auto_ready;
+                            if auto_ready {
+                                self.log("auto-continue to next game");
+                                self.send(ClientToServer::ContinueToNextGame);
+                            }
+                        }
+                        // The dealer splash hands over to game selection by itself
+                        if !(self.app_state == AppState::DealerSplash && target == AppState::GameSelection) {
+                            self.app_state = target;
+                        }
                     }
+                    self.resync_pending = false;
 
                     self.snapshot = Some(snapshot);
                 }
+                ServerToClient::YourTurn { options } => {
+                    self.turn_options = Some(options);
+                }
                 ServerToClient::YourHand { hand } => {
                     self.log(format!("received your hand: {} down", hand.down_cards.len()));
                     self.your_hand = hand;
                 }
-                ServerToClient::Showdown { .. } => self.log("showdown"),
-                ServerToClient::Error { message } => self.log(format!("server error: {message}")),
+                ServerToClient::Showdown { payouts, actions, .. } => {
+                    self.log("showdown");
+                    self.record_showdown(&payouts, &actions);
+                    self.hand_actions = actions;
+                    self.hand_variant = self.snapshot.as_ref().map(|s| s.game_variant);
+                }
+                ServerToClient::CardsShown { player_name, cards, .. } => {
+                    let shown: Vec<String> = cards.iter().map(|c| c.to_string()).collect();
+                    self.log(format!("{} shows: {}", player_name, shown.join(" ")));
+                }
+                ServerToClient::Error { message, .. } => self.log(format!("server error: {message}")),
                 ServerToClient::Info { message } => self.log(format!("info: {message}")),
-                ServerToClient::ChatMessage { player_name, message, scope, room: _, timestamp, recipient: _ } => {
-                    self.chat_messages.push((player_name, message, scope, timestamp));
+                ServerToClient::ChatMessage { player_name, message, scope, room: _, timestamp, recipient } => {
+                    self.push_chat(ChatEntry { player_name, message, scope, timestamp, recipient });
+                }
+                ServerToClient::ChatHistory { messages } => {
+                    self.recent_activity = messages
+                        .into_iter()
+                        .map(|m| ChatEntry {
+                            player_name: m.player_name,
+                            message: m.message,
+                            scope: m.scope,
+                            timestamp: m.timestamp,
+                            recipient: m.recipient,
+                        })
+                        .collect();
+                    self.recent_activity.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
                 }
                 ServerToClient::TableList { tables } => {
                     self.available_tables = tables;
@@ -457,9 +1026,16 @@ impl App {
                 ServerToClient::SpectatorJoined { snapshot } => {
                     println!("👁️ Joined as spectator - table is full");
                     self.log("🎮 Joined communal game as spectator (table full)");
+                    self.spectating = true;
+                    self.your_seat = None;
                     self.room = snapshot.room.clone();
                     self.snapshot = Some(snapshot);
                     self.app_state = AppState::Game;
+                    // We asked for a seat but the table filled first: get in line
+                    if !self.watch_only {
+                        self.log("table filled before we sat down; queued for the next seat");
+                        self.send(ClientToServer::RequestSeat);
+                    }
                 }
                 ServerToClient::DealerDelegated { dealer_name, .. } => {
                     self.log(format!("Dealer delegated to {}", dealer_name));
@@ -480,11 +1056,16 @@ impl App {
                     // This prevents duplicates from multiple reconnections
                     if self.app_state != AppState::Lounge {
                         self.chat_messages.clear();
+                        self.send(ClientToServer::FetchChatHistory { limit: RECENT_ACTIVITY_LIMIT });
                     }
 
                     self.lounge_players = players.clone();
+                    self.send(ClientToServer::ListLoungePlayers);
                     self.available_hosts = available_hosts;
                     self.player_selections = player_selections.clone();
+                    if !self.is_hosting {
+                        self.check_for_available_host();
+                    }
                     self.in_lounge = true;
                     self.connected = true;
                     self.connecting = false;
@@ -520,8 +1101,35 @@ impl App {
                     self.connected = false;
                     self.tx_out = None;  // Reset connection to reconnect to game server
                 }
+                ServerToClient::LoungePlayers { players } => {
+                    self.lounge_roster = players;
+                }
+                ServerToClient::Pong { nonce } => {
+                    if let Some(sent) = self.pings_in_flight.remove(&nonce) {
+                        self.latency_ms = Some(sent.elapsed().as_millis() as u64);
+                    }
+                }
+                ServerToClient::Removed { reason } => {
+                    self.log(format!("removed from {}: {}", self.room, reason));
+                    self.removed_reason = Some(reason);
+                    self.turn_options = None;
+                    self.pending_bet = None;
+                    return self.update(Msg::BackToHome);
+                }
             },
 
+            Msg::SendPing => {
+                // Sent directly rather than through `send` to keep the log quiet
+                if let Some(tx) = &self.tx_out {
+                    self.pings_in_flight.retain(|_, sent| sent.elapsed() < PING_GIVE_UP);
+                    let nonce = self.next_ping_nonce;
+                    self.next_ping_nonce = self.next_ping_nonce.wrapping_add(1);
+                    if tx.unbounded_send(ClientToServer::Ping { nonce }).is_ok() {
+                        self.pings_in_flight.insert(nonce, Instant::now());
+                    }
+                }
+            }
+
             Msg::WsError(e) => {
                 self.log(format!("[ws error] connecting to {} failed: {}", self.url, e));
                 self.connected = false;
@@ -529,7 +1137,22 @@ impl App {
             }
 
             Msg::SitReady => self.send(ClientToServer::SitReady),
+            Msg::SitUnready => {
+                // Otherwise auto-ready would put us straight back in
+                if self.auto_ready && !self.sitting_out {
+                    self.sitting_out = true;
+                    self.log("sitting out: you won't be readied for the next hand");
+                }
+                self.send(ClientToServer::SitUnready);
+            }
+            Msg::ShowCards => self.send(ClientToServer::ShowCards),
+            Msg::Muck => self.send(ClientToServer::Muck),
+            Msg::RabbitHunt => self.send(ClientToServer::RabbitHunt),
+            Msg::TakeSeat => self.send(ClientToServer::TakeSeat),
+            Msg::RequestSeat => self.send(ClientToServer::RequestSeat),
+            Msg::Rebuy => self.send(ClientToServer::Rebuy),
             Msg::StartHand => self.send(ClientToServer::StartHand),
+            Msg::ForceStart => self.send(ClientToServer::ForceStart),
             Msg::TakeCard => {
                 println!("🎯 TakeCard button clicked!");
                 self.send(ClientToServer::TakeCard)
@@ -539,10 +1162,25 @@ impl App {
                 self.send(ClientToServer::Stand)
             },
             Msg::Fold    => self.send(ClientToServer::Fold),
-            Msg::Check   => self.send(ClientToServer::Check),
-            Msg::Bet     => self.send(ClientToServer::Bet),
-            Msg::Call    => self.send(ClientToServer::Call),
-            Msg::Raise   => self.send(ClientToServer::Raise),
+            Msg::Check   => self.send_action(ClientToServer::Check),
+            Msg::Bet | Msg::Raise => {
+                let is_raise = matches!(msg, Msg::Raise);
+                let cmd = if is_raise { ClientToServer::Raise } else { ClientToServer::Bet };
+                if self.refuse_action(&cmd) {
+                    return Task::none();
+                }
+                match self.bet_needing_confirm(is_raise) {
+                    Some(amount) => self.pending_bet = Some((cmd, amount)),
+                    None => self.send(cmd),
+                }
+            }
+            Msg::ConfirmBet => {
+                if let Some((cmd, _)) = self.pending_bet.take() {
+                    self.send(cmd);
+                }
+            }
+            Msg::CancelBet => self.pending_bet = None,
+            Msg::Call    => self.send_action(ClientToServer::Call),
 
             // Chat messages - handled by new system below
 
@@ -569,24 +1207,14 @@ impl App {
 
             // Name confirmation
             Msg::ConfirmName => {
-                let trimmed_name = self.name.trim();
-                if trimmed_name.is_empty() {
-                    self.name_error = Some("Please enter a name".to_string());
-                } else if trimmed_name.len() < 2 {
-                    self.name_error = Some("Name must be at least 2 characters".to_string());
-                } else if trimmed_name.len() > 20 {
-                    self.name_error = Some("Name must be 20 characters or less".to_string());
-                } else if !trimmed_name.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-') {
-                    self.name_error = Some("Name can only contain letters, numbers, _ and -".to_string());
-                } else {
-                    self.name_error = None;
-                    // Auto-connect to the lounge after name confirmation
-                    self.url = "ws://127.0.0.1:9001/ws".to_string();
-                    self.room = "lounge".to_string();
-                    self.in_lounge = true;
-                    self.app_state = AppState::ConnectOverlay;
-                    self.connecting = true;
-                    self.log("🚪 Joining lounge...".to_string());
+                match clean_name(&self.name, NAME_MAX) {
+                    Err(e) => self.name_error = Some(e),
+                    Ok(name) => {
+                        self.name = name;
+                        self.name_error = None;
+                        // Auto-connect to the lounge after name confirmation
+                        self.enter_lounge("🚪 Joining lounge...");
+                    }
                 }
             }
 
@@ -605,9 +1233,18 @@ impl App {
             Msg::CheckIn => {
                 self.send(ClientToServer::CheckIn);
             }
+            Msg::CancelSchedule => {
+                self.send(ClientToServer::CancelSchedule);
+            }
             Msg::SelectGameVariant(variant) => {
                 self.send(ClientToServer::SelectGameVariant { variant });
             }
+            Msg::SetDealersChoice(enabled) => {
+                self.send(ClientToServer::SetDealersChoice { enabled });
+            }
+            Msg::ChooseNextVariant(variant) => {
+                self.send(ClientToServer::ChooseGameVariant { variant });
+            }
 
             // Table creation form handlers
             Msg::TableNameChanged(name) => {
@@ -633,6 +1270,28 @@ impl App {
                 self.table_max_raises = raises;
                 self.table_creation_error = None;
             }
+            Msg::TableMinPlayersChanged(players) => {
+                self.table_min_players = players;
+                self.table_creation_error = None;
+            }
+            Msg::TableRakePercentChanged(percent) => {
+                self.table_rake_percent = percent;
+                self.table_creation_error = None;
+            }
+            Msg::TableRakeCapChanged(cap) => {
+                self.table_rake_cap = cap;
+                self.table_creation_error = None;
+            }
+            Msg::TableMuckWindowChanged(secs) => {
+                self.table_muck_window = secs;
+                self.table_creation_error = None;
+            }
+            Msg::TableAutoDealerToggled(enabled) => {
+                self.table_auto_dealer = enabled;
+            }
+            Msg::TableRabbitHuntToggled(enabled) => {
+                self.table_rabbit_hunt = enabled;
+            }
             Msg::SubmitTableCreation => {
                 // Start embedded server first for table creation
                 return Task::perform(
@@ -687,6 +1346,44 @@ impl App {
                     }
                 };
 
+                let min_players = match self.table_min_players.parse::<usize>() {
+                    Ok(val) if val >= 2 => val,
+                    _ => {
+                        self.table_creation_error = Some("Min players must be at least 2".to_string());
+                        return Task::none();
+                    }
+                };
+
+                let rake_percent = match self.table_rake_percent.parse::<u64>() {
+                    Ok(val) if val <= 10 => val,
+                    _ => {
+                        self.table_creation_error = Some("Rake must be between 0 and 10 percent".to_string());
+                        return Task::none();
+                    }
+                };
+
+                let rake_cap = match self.table_rake_cap.trim() {
+                    "" => None,
+                    cap => match cap.parse::<u64>() {
+                        Ok(val) => Some(val),
+                        Err(_) => {
+                            self.table_creation_error = Some("Rake cap must be a number, or empty for no cap".to_string());
+                            return Task::none();
+                        }
+                    },
+                };
+
+                let muck_window_secs = match self.table_muck_window.trim() {
+                    "" => None,
+                    secs => match secs.parse::<u64>() {
+                        Ok(val) if val <= 60 => Some(val),
+                        _ => {
+                            self.table_creation_error = Some("Muck window must be 0 to 60 seconds, or empty to reveal at once".to_string());
+                            return Task::none();
+                        }
+                    },
+                };
+
                 // Start embedded server if not already running
                 if self.embedded_server.is_none() {
                     self.log("🔧 Starting embedded server for table creation...".to_string());
@@ -721,6 +1418,16 @@ impl App {
                     limit_small,
                     limit_big,
                     max_raises,
+                    min_players: Some(min_players),
+                    rake_percent,
+                    rake_cap,
+                    max_buyin: None,
+                    deck_count: None,
+                    spectator_chat_to_players: None,
+                    muck_window_secs,
+                    max_draw_rounds: None,
+                    auto_dealer: self.table_auto_dealer,
+                    rabbit_hunt: self.table_rabbit_hunt,
                 };
 
                 // Connect to embedded server instead of central server
@@ -770,11 +1477,9 @@ impl App {
                     self.host_name = Some(self.name.clone());
                     self.host_server_port = Some(port);
 
-                    // Announce hosting to other clients via temporary file
-                    let host_info = format!("{}:{}", self.name, port);
-                    if let Err(e) = std::fs::write("/tmp/cctmog_host", host_info) {
-                        self.log(format!("Warning: Could not announce hosting: {}", e));
-                    } else {
+                    // Announce hosting through the server; lounge members see it in LoungeUpdate
+                    if let Some(ref tx) = self.tx_out {
+                        let _ = tx.unbounded_send(cctmog_protocol::ClientToServer::VolunteerToHost { port });
                         self.log(format!("📡 Announced hosting on port {}", port));
                     }
 
@@ -805,16 +1510,89 @@ impl App {
             }
             Msg::PostComment => {
                 if !self.comment_input.trim().is_empty() {
+                    let about = self.comment_about.take();
                     self.send(ClientToServer::PostComment {
                         message: self.comment_input.clone(),
+                        about,
                     });
                     self.comment_input.clear();
                 }
             }
+            Msg::CommentAboutChanged(about) => self.comment_about = about,
             Msg::ContinueToNextGame => {
                 self.ready_to_continue = true;
                 self.send(ClientToServer::ContinueToNextGame);
             }
+            Msg::Rematch => self.send(ClientToServer::Rematch),
+            Msg::CloseTable => self.send(ClientToServer::CloseTable),
+            Msg::ToggleSpectators => self.show_spectators = !self.show_spectators,
+            Msg::ToggleLeaderboardSort => self.leaderboard_by_name = !self.leaderboard_by_name,
+            Msg::ToggleSortHand => self.sort_hand = !self.sort_hand,
+            Msg::MessageSpectator(id, name) => {
+                // Clicking the same spectator again goes back to table chat
+                if self.dm_recipient.as_ref().is_some_and(|(cur, _)| *cur == id) {
+                    self.dm_recipient = None;
+                } else {
+                    self.dm_recipient = Some((id, name));
+                }
+            }
+            Msg::SetFoldToAnyBet(on) => {
+                self.fold_to_any_bet = on;
+                if !on {
+                    self.send(ClientToServer::SetPreAction { pre_action: None });
+                } else if self.snapshot.as_ref().is_some_and(|s| self.wants_check_fold(s)) {
+                    self.send(ClientToServer::SetPreAction { pre_action: Some(cctmog_protocol::PreAction::CheckFold) });
+                }
+            }
+            Msg::SetAutoReady(on) => {
+                self.auto_ready = on;
+                self.sitting_out = false;
+                // Take the step the table is already waiting on us for
+                let phase = self.snapshot.as_ref().filter(|_| on && !self.spectating).map(|s| {
+                    (s.phase.clone(), self.my_player(s).is_some_and(|me| !me.ready))
+                });
+                match phase {
+                    Some((Phase::Lobby, true)) => self.send(ClientToServer::SitReady),
+                    Some((Phase::Comments, _)) if !self.ready_to_continue => {
+                        self.ready_to_continue = true;
+                        self.send(ClientToServer::ContinueToNextGame);
+                    }
+                    _ => {}
+                }
+            }
+            Msg::SetConfirmBets(on) => {
+                self.confirm_bet_pct = match confirm_bet_pct_from_env() {
+                    _ if !on => 0,
+                    0 => CONFIRM_BET_DEFAULT_PCT,
+                    pct => pct,
+                };
+            }
+            Msg::SetAutoMuck(on) => {
+                self.auto_muck = on;
+                // Turned on with the window already open: muck now
+                if self.snapshot.as_ref().is_some_and(|s| self.should_auto_muck(s)) {
+                    self.muck_sent = true;
+                    self.send(ClientToServer::Muck);
+                }
+            }
+
+            Msg::StatusInputChanged(text) => self.status_input = text,
+            Msg::SetStatus => match cctmog_protocol::names::clean_status(&self.status_input) {
+                Ok(text) => {
+                    self.status_input = text.clone().unwrap_or_default();
+                    self.send(ClientToServer::SetStatus { text });
+                }
+                Err(e) => self.log(format!("status not set: {e}")),
+            },
+
+            Msg::ToggleSitOut => {
+                self.sitting_out = !self.sitting_out;
+                self.log(if self.sitting_out {
+                    "sitting out: you won't be readied for the next hand"
+                } else {
+                    "back in: auto-ready resumes next hand"
+                });
+            }
 
             // Lounge menu handlers
             Msg::ViewStats => {
@@ -826,8 +1604,13 @@ impl App {
                 self.log("⚙️ Settings view not yet implemented".to_string());
             }
             Msg::OpenTutorial => {
-                // TODO: Implement tutorial view
-                self.log("📖 Tutorial view not yet implemented".to_string());
+                self.tutorial_step = 0;
+                self.app_state = AppState::Tutorial;
+            }
+
+            // Tutorial navigation
+            Msg::TutorialNext | Msg::TutorialPrev | Msg::CloseTutorial => {
+                return self.handle_tutorial_msg(&msg);
             }
 
             // Window events
@@ -836,7 +1619,16 @@ impl App {
             }
 
             // Dealer selection messages
+            Msg::ElectToStart => self.send(ClientToServer::ElectToStart),
             Msg::DealerSelected(dealer_name) => {
+                // During the table's dealer selection the choice goes to the server
+                let delegate = self.snapshot.as_ref()
+                    .filter(|s| s.phase == Phase::DealerSelection)
+                    .and_then(|s| s.players.iter().find(|p| p.name == dealer_name))
+                    .map(|p| p.id);
+                if let Some(player_id) = delegate {
+                    self.send(ClientToServer::DelegateDealer { player_id });
+                }
                 self.selected_dealer = Some(dealer_name);
                 self.app_state = AppState::DealerSplash;
                 self.dealer_splash_start_time = Some(std::time::Instant::now());
@@ -860,10 +1652,18 @@ impl App {
                 }
             }
 
-            // Host discovery
+            // Host discovery: pick a volunteered host and tell the lounge
             Msg::CheckForHost => {
                 if !self.is_hosting {
                     self.check_for_available_host();
+                    if let (Some(name), Some(port)) = (self.host_name.clone(), self.host_server_port) {
+                        let already_selected = self.my_selected_host.as_ref() == Some(&(name.clone(), port));
+                        if self.in_lounge && !already_selected {
+                            return self.handle_lounge_msg(&Msg::SelectHost(name, port));
+                        }
+                    } else {
+                        self.log("🔍 No one has volunteered to host yet".to_string());
+                    }
                 }
             }
 
@@ -916,10 +1716,16 @@ impl App {
                 if self.app_state == AppState::Lounge && self.in_lounge {
                     return self.handle_lounge_msg(&msg);
                 } else if !self.chat_input.trim().is_empty() {
-                    self.send(ClientToServer::Chat {
-                        message: self.chat_input.clone(),
-                        scope: self.chat_scope
-                    });
+                    match &self.dm_recipient {
+                        Some((recipient, _)) => self.send(ClientToServer::PrivateMessage {
+                            recipient: *recipient,
+                            message: self.chat_input.clone(),
+                        }),
+                        None => self.send(ClientToServer::Chat {
+                            message: self.chat_input.clone(),
+                            scope: self.chat_scope
+                        }),
+                    }
                     self.chat_input.clear();
                 }
             }
@@ -932,8 +1738,29 @@ impl App {
         Task::none()
     }
 
+    /// Note a warning instead of sending when the action is plainly illegal.
+    fn refuse_action(&mut self, cmd: &ClientToServer) -> bool {
+        let reason = self.snapshot.as_ref().and_then(|s| illegal_action(s, cmd));
+        if let Some(reason) = reason {
+            self.log(format!("not sent: {reason}"));
+            self.action_warning = Some(reason.to_string());
+        }
+        reason.is_some()
+    }
+
+    fn send_action(&mut self, cmd: ClientToServer) {
+        if !self.refuse_action(&cmd) {
+            self.send(cmd);
+        }
+    }
+
     fn send(&mut self, cmd: ClientToServer) {
         println!("📤 Attempting to send: {:?}", cmd);
+        // Watching only: the server would reject these anyway
+        if is_gameplay_command(&cmd) && self.snapshot.as_ref().is_some_and(|s| self.is_spectator(s)) {
+            self.log(format!("not sent while spectating: {:?}", cmd));
+            return;
+        }
         if let Some(tx) = &self.tx_out {
             let json = serde_json::to_string(&cmd).ok();
             match tx.unbounded_send(cmd) {
@@ -956,13 +1783,18 @@ impl App {
 
     pub fn subscription(&self) -> Subscription<Msg> {
         let tick = iced::time::every(Duration::from_millis(400)).map(|_| Msg::Tick);
-        let ws_sub = if (self.app_state == AppState::ConnectOverlay || self.app_state == AppState::Lounge || self.app_state == AppState::Game || self.app_state == AppState::Comments) && (self.connecting || self.connected) && !self.name.trim().is_empty() {
+        let ws_sub = if self.app_state.uses_socket() && (self.connecting || self.connected) && !self.name.trim().is_empty() {
             subscription(self.url.clone(), self.room.clone(), self.name.clone())
         } else {
             Subscription::none()
         };
         let window_sub = iced::window::resize_events().map(|(_, size)| Msg::WindowResized(size));
-        Subscription::batch(vec![tick, ws_sub, window_sub])
+        let ping = if self.connected {
+            iced::time::every(PING_EVERY).map(|_| Msg::SendPing)
+        } else {
+            Subscription::none()
+        };
+        Subscription::batch(vec![tick, ws_sub, window_sub, ping])
     }
 
     pub(crate) fn view(&self) -> Element<Msg> {
@@ -987,6 +1819,21 @@ impl App {
             AppState::DealerSelection => self.dealer_selection_view(),
             AppState::DealerSplash => self.dealer_splash_view(),
             AppState::GameSelection => self.game_selection_view(),
+            AppState::Tutorial => self.tutorial_view(),
+        };
+
+        // Incompatible server: say so above whatever screen we're on
+        let version_banner: Element<Msg> = match &self.version_error {
+            Some(message) => iced_widget::container(text(message.clone()).size(16))
+                .padding(10)
+                .width(Length::Fill)
+                .style(|_theme: &iced::Theme| iced_widget::container::Style {
+                    background: Some(iced::Background::Color(iced::Color::from_rgb(0.55, 0.12, 0.12))),
+                    text_color: Some(iced::Color::WHITE),
+                    ..Default::default()
+                })
+                .into(),
+            None => iced_widget::Space::with_height(0.0).into(),
         };
 
         // Only show footer if not in splash screens
@@ -994,6 +1841,7 @@ impl App {
             main_content
         } else {
             column![
+                version_banner,
                 iced_widget::container(main_content).height(Length::Fill),
                 footer(self, self.window_size)
             ]
@@ -1061,11 +1909,7 @@ impl App {
                         Space::with_height(Length::Fixed(20.0)),
                         // Continue button - conditionally enabled
                         {
-                            let trimmed = self.name.trim();
-                            let is_valid = !trimmed.is_empty()
-                                && trimmed.len() >= 2
-                                && trimmed.len() <= 20
-                                && trimmed.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-');
+                            let is_valid = clean_name(&self.name, NAME_MAX).is_ok();
 
                             let mut btn = button(
                                 text::<iced::Theme, iced::Renderer>("Continue")
@@ -1351,8 +2195,13 @@ impl App {
         // Handle connecting/loading state
         if self.connecting {
             return container(
-                text::<iced::Theme, iced::Renderer>("Connecting…")
-                    .size(24),
+                column![
+                    text::<iced::Theme, iced::Renderer>("Connecting…")
+                        .size(24),
+                    button("Cancel").on_press(Msg::CancelConnect),
+                ]
+                .spacing(12)
+                .align_x(Center),
             )
             .center_x(Fill)
             .center_y(Fill)
@@ -1371,6 +2220,15 @@ impl App {
 
         let s = self.snapshot.as_ref().unwrap();
 
+        // Watching only: read-only layout without hand, actions or player panels
+        let spectator = self.is_spectator(s);
+
+        // Responsive breakpoint: narrow windows get a single stacked column
+        let compact = self.window_size
+            .map(|size| size.width < COMPACT_LAYOUT_WIDTH)
+            .unwrap_or(false);
+        let hole_card_size = if compact { CardSize::Small } else { CardSize::Large };
+
         let header = row![
             brand_logo(),
             Space::with_width(12.0),
@@ -1383,19 +2241,25 @@ impl App {
                     if s.in_betting { " · Betting" } else { " · Draw" }
                 )),
                 Space::with_width(8.0),
-                crate::ui::pill(format!("Round {}", s.round)),
+                crate::ui::pill(if s.round_label.is_empty() {
+                    format!("Round {}", s.round)
+                } else {
+                    s.round_label.clone()
+                }),
             ]
             .spacing(8.0)
             .align_y(Center),
             Space::with_width(Fill),
-            crate::ui::pill(format!("Pot {}", s.pot)),
+            crate::ui::pill(crate::ui::header::pot_label(s)),
         ]
             .align_y(Center);
 
         let seats_ring = round_table_view(s, self.your_id, self.your_seat, &self.your_hand);
 
         // Your face-up cards (above hole cards)
-        let your_up: Element<Msg> = if let Some(me) = s.players.iter().find(|p| {
+        let your_up: Element<Msg> = if spectator {
+            Space::with_height(0.0).into()
+        } else if let Some(me) = s.players.iter().find(|p| {
             self.your_id.map(|id| p.id == id).unwrap_or(false)
                 || self.your_seat.map(|seat| p.seat == seat).unwrap_or(false)
         }) {
@@ -1404,7 +2268,7 @@ impl App {
                     row![
                         text::<iced::Theme, iced::Renderer>("Up:").size(14),
                         Space::with_width(6.0),
-                        cards_row_svg(&me.up_cards, CardSize::Small, 6.0),
+                        cards_row_svg(&self.display_cards(&me.up_cards), CardSize::Small, 6.0),
                     ]
                         .spacing(6.0)
                         .align_y(Alignment::Center),
@@ -1421,9 +2285,14 @@ impl App {
 
         // Your hole cards (below felt)
         let your_down: Element<Msg> =
-            if s.phase != Phase::Lobby && !self.your_hand.down_cards.is_empty() {
+            if !spectator && s.phase != Phase::Lobby && !self.your_hand.down_cards.is_empty() {
                 container(
-                    row![cards_row_svg(&self.your_hand.down_cards, CardSize::Large, 10.0)]
+                    row![
+                        cards_row_svg(&self.display_cards(&self.your_hand.down_cards), hole_card_size, 10.0),
+                        button(text::<iced::Theme, iced::Renderer>(if self.sort_hand { "Deal order" } else { "Sort" }).size(11))
+                            .on_press(Msg::ToggleSortHand)
+                            .padding([4_u16, 8_u16]),
+                    ]
                         .spacing(10.0)
                         .align_y(Alignment::Center),
                 )
@@ -1435,10 +2304,80 @@ impl App {
                 Space::with_height(0.0).into()
             };
 
-        let actions = render_action_bar(s, self.your_seat, self.in_turn(s));
+        let mut your_cards = self.your_hand.down_cards.clone();
+        if let Some(me) = self.my_player(s) {
+            your_cards.extend(me.up_cards.iter().copied());
+        }
+        let actions: Element<Msg> = if spectator {
+            text::<iced::Theme, iced::Renderer>("Spectating — you'll see every action as it happens.")
+                .size(14)
+                .into()
+        } else {
+            let bar = render_action_bar(s, self.your_seat, self.turn_options.as_ref(), &your_cards);
+            match self.hand_strength(s).filter(|_| self.my_player(s).is_some_and(|me| !me.folded)) {
+                Some(best) => column![
+                    text::<iced::Theme, iced::Renderer>(format!("Your hand: {}", best))
+                        .size(13)
+                        .style(|_theme: &iced::Theme| iced_widget::text::Style {
+                            color: Some(iced::Color::from_rgb(0.75, 0.9, 0.75)),
+                        }),
+                    bar,
+                ]
+                .spacing(6)
+                .align_x(Center)
+                .into(),
+                None => bar,
+            }
+        };
+
+        // After the comments phase at bigger tables: everyone elects to start,
+        // then the table picks a dealer
+        let election_panel: Element<Msg> = if s.phase == Phase::WaitingForDealer {
+            let elected = self.your_id.is_some_and(|id| s.elected_players.contains(&id));
+            container(
+                column![
+                    text::<iced::Theme, iced::Renderer>(election_status(s)).size(14),
+                    button(text::<iced::Theme, iced::Renderer>(if elected { "Elected ✓" } else { "Elect to start" }).size(13))
+                        .on_press_maybe((!elected && !spectator).then_some(Msg::ElectToStart))
+                        .padding([6_u16, 10_u16]),
+                ]
+                .spacing(6.0),
+            )
+            .padding([8_u16, 12_u16])
+            .style(|_theme: &iced::Theme| iced_widget::container::Style {
+                border: iced::Border {
+                    color: iced::Color::from_rgb(0.36, 0.62, 0.98),
+                    width: 1.0,
+                    radius: iced::border::Radius::from(6.0),
+                },
+                ..Default::default()
+            })
+            .width(Fill)
+            .into()
+        } else {
+            Space::with_height(0.0).into()
+        };
+
+        // Heads-up that our turn comes after the current player's
+        let on_deck: Element<Msg> = if self.next_to_act_is_me(s) {
+            container(text::<iced::Theme, iced::Renderer>("You're next to act").size(13))
+                .padding([4_u16, 10_u16])
+                .style(|_theme: &iced::Theme| iced_widget::container::Style {
+                    border: iced::Border {
+                        color: iced::Color::from_rgba(1.0, 0.8, 0.0, 0.6),
+                        width: 1.0,
+                        radius: iced::border::Radius::from(6.0),
+                    },
+                    text_color: Some(iced::Color::from_rgb(1.0, 0.85, 0.4)),
+                    ..Default::default()
+                })
+                .into()
+        } else {
+            Space::with_height(0.0).into()
+        };
 
         // Scheduling panel
-        let scheduling_panel: Element<Msg> = if s.phase == Phase::Lobby {
+        let scheduling_panel: Element<Msg> = if !spectator && s.phase == Phase::Lobby {
             container(
                 column![
                     text::<iced::Theme, iced::Renderer>("Scheduling").size(16)
@@ -1488,6 +2427,20 @@ impl App {
                                 }
                             } else {
                                 Element::from(Space::with_height(0.0))
+                            },
+                            // Dealer (or host before the first hand) can drop the schedule
+                            if self.your_id.is_some()
+                                && s.current_dealer_id
+                                    .or_else(|| s.players.get(s.dealer_seat).map(|p| p.id))
+                                    == self.your_id
+                            {
+                                Element::from(
+                                    button(text::<iced::Theme, iced::Renderer>("Cancel schedule").size(12))
+                                        .on_press(Msg::CancelSchedule)
+                                        .padding([6_u16, 10_u16]),
+                                )
+                            } else {
+                                Element::from(Space::with_height(0.0))
                             }
                         ]
                     } else {
@@ -1500,7 +2453,7 @@ impl App {
                                 }),
                             Space::with_height(4.0),
                             row![
-                                text_input("e.g. 2024-01-01T20:00", &self.schedule_time_input)
+                                text_input("e.g. 2030-01-01T20:00:00Z", &self.schedule_time_input)
                                     .on_input(Msg::ScheduleTimeChanged)
                                     .on_submit(Msg::ScheduleGame)
                                     .padding(4)
@@ -1545,7 +2498,7 @@ impl App {
         };
 
         // Dealer selection panel
-        let dealer_panel: Element<Msg> = if s.phase == Phase::Lobby {
+        let dealer_panel: Element<Msg> = if !spectator && s.phase == Phase::Lobby {
             // Check if the player is in the room to allow dealer selection
             let can_select_dealer = self.your_id.is_some() &&
                 s.players.iter().any(|p| self.your_id.map(|id| p.id == id).unwrap_or(false));
@@ -1598,6 +2551,15 @@ impl App {
                                     ..Default::default()
                                 })
                         )
+                    },
+                    if self.is_dealer_or_host(s) {
+                        Element::from(
+                            button(text::<iced::Theme, iced::Renderer>("Close table").size(12))
+                                .on_press(Msg::CloseTable)
+                                .padding([6_u16, 10_u16]),
+                        )
+                    } else {
+                        Element::from(Space::with_height(0.0))
                     }
                 ]
                 .spacing(4.0),
@@ -1634,19 +2596,15 @@ impl App {
             "No messages yet".to_string()
         } else {
             self.chat_messages.iter()
-                .map(|(name, msg, scope, _timestamp)| {
-                    let scope_prefix = match scope {
-                        MessageScope::Match => "[Match]",
-                        MessageScope::Group => "[Group]",
-                        MessageScope::Global => "[Global]",
-                        MessageScope::Private => "[Private]",
-                    };
-                    format!("{} {}: {}", scope_prefix, name, msg)
-                })
+                .map(ChatEntry::line)
                 .collect::<Vec<_>>()
                 .join("\n")
         };
 
+        let chat_placeholder = match &self.dm_recipient {
+            Some((_, name)) => format!("Message {} privately...", name),
+            None => "Type a message...".to_string(),
+        };
         let chat_panel: Element<Msg> = container(
             column![
                 text::<iced::Theme, iced::Renderer>("Chat").size(16)
@@ -1675,7 +2633,7 @@ impl App {
                     ..Default::default()
                 }),
                 row![
-                    text_input("Type a message...", &self.chat_input)
+                    text_input(&chat_placeholder, &self.chat_input)
                         .on_input(Msg::ChatInputChanged)
                         .on_submit(Msg::SendChat)
                         .padding(8)
@@ -1715,9 +2673,131 @@ impl App {
             .width(Length::Fill)
             .into();
 
-        let left = column![seats_ring, your_up, your_down]
+        let spectator_banner: Element<Msg> = if self.spectating {
+            container(
+                row![
+                    text::<iced::Theme, iced::Renderer>(
+                        "Table full — you're spectating. You'll be offered the next open seat."
+                    )
+                    .size(14),
+                    Space::with_width(Fill),
+                    button(text::<iced::Theme, iced::Renderer>("Queue for seat").size(12))
+                        .on_press(Msg::RequestSeat)
+                        .padding([6_u16, 10_u16]),
+                    button(text::<iced::Theme, iced::Renderer>("Take seat").size(12))
+                        .on_press(Msg::TakeSeat)
+                        .padding([6_u16, 10_u16]),
+                ]
+                .spacing(8.0)
+                .align_y(Center),
+            )
+            .padding([8_u16, 12_u16])
+            .style(|_theme: &iced::Theme| iced_widget::container::Style {
+                background: Some(iced::Background::Color(iced::Color::from_rgb(0.18, 0.22, 0.35))),
+                border: iced::Border {
+                    color: iced::Color::from_rgb(0.36, 0.62, 0.98),
+                    width: 1.0,
+                    radius: iced::border::Radius::from(6.0),
+                },
+                ..Default::default()
+            })
+            .width(Fill)
+            .into()
+        } else {
+            Space::with_height(0.0).into()
+        };
+
+        // Can't cover the ante plus a big bet: prompt for a rebuy
+        let low_chips = !spectator && self.my_player(s).is_some_and(|me| me.chips < s.ante + s.limit_big);
+        let rebuy_banner: Element<Msg> = if low_chips {
+            let between_hands = s.phase == Phase::Lobby || s.phase == Phase::Comments;
+            container(
+                row![
+                    text::<iced::Theme, iced::Renderer>(if between_hands {
+                        "Low chips — Rebuy?"
+                    } else {
+                        "Low chips — you can rebuy after this hand."
+                    })
+                    .size(14),
+                    Space::with_width(Fill),
+                    button(text::<iced::Theme, iced::Renderer>("Rebuy").size(12))
+                        .on_press_maybe(between_hands.then_some(Msg::Rebuy))
+                        .padding([6_u16, 10_u16]),
+                ]
+                .spacing(8.0)
+                .align_y(Center),
+            )
+            .padding([8_u16, 12_u16])
+            .style(|_theme: &iced::Theme| iced_widget::container::Style {
+                background: Some(iced::Background::Color(iced::Color::from_rgb(0.40, 0.22, 0.12))),
+                border: iced::Border {
+                    color: iced::Color::from_rgb(1.0, 0.65, 0.2),
+                    width: 1.0,
+                    radius: iced::border::Radius::from(6.0),
+                },
+                ..Default::default()
+            })
+            .width(Fill)
+            .into()
+        } else {
+            Space::with_height(0.0).into()
+        };
+
+        let confirm_bet_banner: Element<Msg> = match &self.pending_bet {
+            Some((cmd, amount)) => container(
+                row![
+                    text::<iced::Theme, iced::Renderer>(format!(
+                        "{} {} chips?",
+                        if matches!(cmd, ClientToServer::Raise) { "Raise with" } else { "Bet" },
+                        amount
+                    ))
+                    .size(14),
+                    Space::with_width(Fill),
+                    button(text::<iced::Theme, iced::Renderer>("Confirm").size(12))
+                        .on_press(Msg::ConfirmBet)
+                        .padding([6_u16, 10_u16]),
+                    button(text::<iced::Theme, iced::Renderer>("Cancel").size(12))
+                        .on_press(Msg::CancelBet)
+                        .padding([6_u16, 10_u16]),
+                ]
+                .spacing(8.0)
+                .align_y(Center),
+            )
+            .padding([8_u16, 12_u16])
+            .style(|_theme: &iced::Theme| iced_widget::container::Style {
+                background: Some(iced::Background::Color(iced::Color::from_rgb(0.40, 0.12, 0.12))),
+                border: iced::Border {
+                    color: iced::Color::from_rgb(1.0, 0.35, 0.3),
+                    width: 1.0,
+                    radius: iced::border::Radius::from(6.0),
+                },
+                ..Default::default()
+            })
+            .width(Fill)
+            .into(),
+            None => Space::with_height(0.0).into(),
+        };
+
+        let action_warning_banner: Element<Msg> = match &self.action_warning {
+            Some(reason) => container(text::<iced::Theme, iced::Renderer>(reason.as_str()).size(14))
+                .padding([8_u16, 12_u16])
+                .style(|_theme: &iced::Theme| iced_widget::container::Style {
+                    background: Some(iced::Background::Color(iced::Color::from_rgb(0.40, 0.12, 0.12))),
+                    border: iced::Border {
+                        color: iced::Color::from_rgb(1.0, 0.35, 0.3),
+                        width: 1.0,
+                        radius: iced::border::Radius::from(6.0),
+                    },
+                    ..Default::default()
+                })
+                .width(Fill)
+                .into(),
+            None => Space::with_height(0.0).into(),
+        };
+
+        let left = column![spectator_banner, rebuy_banner, confirm_bet_banner, action_warning_banner, seats_ring, your_up, your_down]
             .spacing(8.0)
-            .width(Length::FillPortion(3));
+            .width(if compact { Length::Fill } else { Length::FillPortion(3) });
 
         let back_home_btn = button(text::<iced::Theme, iced::Renderer>("Back to Home"))
             .on_press(Msg::BackToHome)
@@ -1733,12 +2813,141 @@ impl App {
                 ..Default::default()
             });
 
-        let right = column![actions, Space::with_height(6.0), scheduling_panel, Space::with_height(6.0), dealer_panel, Space::with_height(8.0), toggle_log, Space::with_height(6.0), back_home_btn, Space::with_height(6.0), log_panel, chat_panel]
+        let stack_panel: Element<Msg> = match (self.chip_history.first(), self.chip_history.last()) {
+            _ if spectator => Space::with_height(0.0).into(),
+            (Some(&first), Some(&last)) => column![
+                text::<iced::Theme, iced::Renderer>(format!(
+                    "Stack {} ({:+} this session)",
+                    last,
+                    last as i64 - first as i64
+                ))
+                .size(12),
+                crate::ui::canvas::chip_sparkline(&self.chip_history),
+            ]
+            .spacing(2.0)
+            .into(),
+            _ => Space::with_height(0.0).into(),
+        };
+
+        let leaderboard: Element<Msg> = if s.phase == Phase::Lobby {
+            crate::ui::views::leaderboard_panel(self)
+        } else {
+            Space::with_height(0.0).into()
+        };
+
+        let sit_out_btn: Element<Msg> = if self.auto_ready && !spectator {
+            button(text::<iced::Theme, iced::Renderer>(if self.sitting_out { "I'm back" } else { "Sit out" }))
+                .on_press(Msg::ToggleSitOut)
+                .padding([6_u16, 10_u16])
+                .into()
+        } else {
+            Space::with_height(0.0).into()
+        };
+
+        let fold_any_toggle: Element<Msg> = if !spectator {
+            iced_widget::checkbox("Check/fold to any bet", self.fold_to_any_bet)
+                .on_toggle(Msg::SetFoldToAnyBet)
+                .size(14)
+                .text_size(13)
+                .into()
+        } else {
+            Space::with_height(0.0).into()
+        };
+
+        let auto_ready_toggle: Element<Msg> = if !spectator {
+            iced_widget::checkbox("Ready up automatically", self.auto_ready)
+                .on_toggle(Msg::SetAutoReady)
+                .size(14)
+                .text_size(13)
+                .into()
+        } else {
+            Space::with_height(0.0).into()
+        };
+
+        let confirm_bets_toggle: Element<Msg> = if !spectator {
+            iced_widget::checkbox("Confirm big bets", self.confirm_bet_pct > 0)
+                .on_toggle(Msg::SetConfirmBets)
+                .size(14)
+                .text_size(13)
+                .into()
+        } else {
+            Space::with_height(0.0).into()
+        };
+
+        let auto_muck_toggle: Element<Msg> = if !spectator {
+            iced_widget::checkbox("Muck losing hands", self.auto_muck)
+                .on_toggle(Msg::SetAutoMuck)
+                .size(14)
+                .text_size(13)
+                .into()
+        } else {
+            Space::with_height(0.0).into()
+        };
+
+        let status_box: Element<Msg> = if !spectator {
+            row![
+                text_input("Status, e.g. BRB", &self.status_input)
+                    .on_input(Msg::StatusInputChanged)
+                    .on_submit(Msg::SetStatus)
+                    .padding(4)
+                    .size(12),
+                button(text::<iced::Theme, iced::Renderer>("Set").size(12))
+                    .on_press(Msg::SetStatus)
+                    .padding([4_u16, 8_u16]),
+            ]
+            .spacing(6.0)
+            .align_y(Center)
+            .into()
+        } else {
+            Space::with_height(0.0).into()
+        };
+
+        // Who's watching, collapsible; click a name to message them
+        let spectators_panel: Element<Msg> = match watching_summary(&s.spectators) {
+            Some(summary) => {
+                let header = button(text::<iced::Theme, iced::Renderer>(format!(
+                    "{} {}",
+                    if self.show_spectators { "▾" } else { "▸" },
+                    summary
+                )).size(12))
+                .on_press(Msg::ToggleSpectators)
+                .padding([4_u16, 8_u16])
+                .style(|_theme: &iced::Theme, _status| button::Style {
+                    text_color: iced::Color::from_rgb(0.8, 0.8, 0.8),
+                    ..Default::default()
+                });
+                if self.show_spectators {
+                    let names = s.spectators.iter().map(|sp| {
+                        let selected = self.dm_recipient.as_ref().is_some_and(|(id, _)| *id == sp.id);
+                        button(text::<iced::Theme, iced::Renderer>(format!(
+                            "{} {}",
+                            if selected { "✉" } else { "·" },
+                            sp.name
+                        )).size(12))
+                        .on_press(Msg::MessageSpectator(sp.id, sp.name.clone()))
+                        .padding([2_u16, 12_u16])
+                        .into()
+                    });
+                    column![header, column(names).spacing(2.0)].spacing(2.0).into()
+                } else {
+                    header.into()
+                }
+            }
+            None => Space::with_height(0.0).into(),
+        };
+
+        let right = column![election_panel, on_deck, actions, fold_any_toggle, auto_ready_toggle, confirm_bets_toggle, auto_muck_toggle, status_box, spectators_panel, Space::with_height(6.0), stack_panel, leaderboard, Space::with_height(6.0), scheduling_panel, Space::with_height(6.0), dealer_panel, Space::with_height(8.0), toggle_log, Space::with_height(6.0), sit_out_btn, back_home_btn, Space::with_height(6.0), log_panel, chat_panel]
             .spacing(8.0)
-            .width(Length::FillPortion(1));
+            .width(if compact { Length::Fill } else { Length::FillPortion(1) });
+
+        let body: Element<Msg> = if compact {
+            column![left, right].spacing(14.0).height(Length::Fill).into()
+        } else {
+            row![left, right].spacing(14.0).height(Length::Fill).into()
+        };
 
         container(
-            column![header, Space::with_height(6.0), row![left, right].spacing(14.0).height(Length::Fill)]
+            column![header, Space::with_height(6.0), body]
                 .spacing(8.0)
                 .padding(12.0),
         )
@@ -1747,21 +2956,702 @@ impl App {
             .into()
     }
 
-    pub(crate) fn in_turn(&self, s: &PublicRoom) -> bool {
-        let my_seat_opt = if let Some(id) = self.your_id {
-            s.players.iter().find(|p| p.id == id).map(|p| p.seat)
-        } else {
-            self.your_seat
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cctmog_protocol::{GameVariant, PublicPlayer, PublicRoom, TurnOptions};
+
+    fn app_with_stack(chips: u64) -> App {
+        let id = Uuid::new_v4();
+        let mut app = App { your_id: Some(id), your_seat: Some(0), confirm_bet_pct: 50, ..App::default() };
+        app.snapshot = Some(PublicRoom {
+            room: "confirm".to_string(),
+            game_variant: GameVariant::TexasHoldem,
+            dealer_seat: 0,
+            to_act_seat: 0,
+            pot: 0,
+            ante: 10,
+            phase: Phase::Acting,
+            players: vec![PublicPlayer {
+                id,
+                name: "alice".to_string(),
+                seat: 0,
+                chips,
+                folded: false,
+                standing: false,
+                up_cards: vec![],
+                cards_count: 2,
+                committed_round: 0,
+                ready: true,
+                color_seed: 1,
+                status: None,
+            }],
+            in_betting: true,
+            current_bet: 0,
+            raises_made: 0,
+            max_raises: 3,
+            round: 1,
+            round_label: String::new(),
+            street: None,
+            split_pot: false,
+            limit_small: 10,
+            limit_big: 20,
+            community_cards: vec![],
+            scheduled_start: None,
+            checked_in_players: vec![],
+            elected_players: vec![],
+            current_dealer_id: None,
+            current_dealer_name: None,
+            elected_count: 0,
+            rabbit_hunt_open: false,
+            raked_total: 0,
+            showdown_winners: vec![],
+            available_variants: vec![],
+            dealers_choice: false,
+            turn_started_at: None,
+            spectators: vec![],
+        });
+        app
+    }
+
+    #[test]
+    fn test_large_bet_needs_confirmation() {
+        // A 10 chip bet from a 1000 stack goes straight out
+        let mut app = app_with_stack(1000);
+        app.turn_options = Some(TurnOptions { can_bet: Some(10), can_check: true, can_fold: true, ..TurnOptions::default() });
+        let _ = app.update(Msg::Bet);
+        assert!(app.pending_bet.is_none());
+
+        // Call 10 plus raise 10 from a 30 stack is over half: held back
+        let mut app = app_with_stack(30);
+        app.turn_options = Some(TurnOptions { can_call: Some(10), can_raise: Some(10), can_fold: true, ..TurnOptions::default() });
+        let _ = app.update(Msg::Raise);
+        assert!(matches!(app.pending_bet, Some((ClientToServer::Raise, 20))));
+        let _ = app.update(Msg::CancelBet);
+        assert!(app.pending_bet.is_none());
+
+        // Off by default
+        app.confirm_bet_pct = 0;
+        assert_eq!(app.bet_needing_confirm(true), None);
+
+        // The table toggle turns it back on at the default threshold
+        let _ = app.update(Msg::SetConfirmBets(true));
+        assert_eq!(app.confirm_bet_pct, CONFIRM_BET_DEFAULT_PCT);
+        let _ = app.update(Msg::Raise);
+        assert!(app.pending_bet.is_some());
+        let _ = app.update(Msg::CancelBet);
+        let _ = app.update(Msg::SetConfirmBets(false));
+        assert_eq!(app.bet_needing_confirm(true), None);
+    }
+
+    #[test]
+    fn test_chip_history_after_showdown() {
+        let mut app = app_with_stack(1000);
+        app.app_state = AppState::Game;
+        let mut snapshot = app.snapshot.clone().unwrap();
+
+        // Mid-hand updates don't count
+        snapshot.players[0].chips = 990;
+        let _ = app.update(Msg::WsEvent(ServerToClient::UpdateState { snapshot: snapshot.clone() }));
+        assert!(app.chip_history.is_empty());
+
+        // The post-showdown snapshot records the stack with the payout
+        snapshot.phase = Phase::Comments;
+        snapshot.players[0].chips = 1040;
+        let _ = app.update(Msg::WsEvent(ServerToClient::UpdateState { snapshot }));
+        assert_eq!(app.chip_history, vec![1040]);
+
+        let spark = crate::ui::canvas::ChipSparkline { history: vec![1000, 1040, 960] };
+        let pts = spark.points(100.0, 50.0);
+        assert_eq!(pts.len(), 3);
+        assert_eq!((pts[0].x, pts[2].x), (0.0, 100.0));
+        assert_eq!((pts[1].y, pts[2].y), (0.0, 50.0));
+    }
+
+    #[test]
+    fn test_next_to_act_is_me() {
+        let mut app = app_with_stack(1000);
+        let mut s = app.snapshot.clone().unwrap();
+        for seat in 1..4 {
+            let mut p = s.players[0].clone();
+            p.id = Uuid::new_v4();
+            p.seat = seat;
+            s.players.push(p);
+        }
+
+        // Seat 3 acting, then it wraps round to us in seat 0
+        s.to_act_seat = 3;
+        assert!(app.next_to_act_is_me(&s));
+
+        // Seat 2 acting: seat 3 is next, unless they folded
+        s.to_act_seat = 2;
+        assert!(!app.next_to_act_is_me(&s));
+        s.players[3].folded = true;
+        assert!(app.next_to_act_is_me(&s));
+
+        // Not while it's already our turn, or outside a hand
+        s.to_act_seat = 0;
+        assert!(!app.next_to_act_is_me(&s));
+        s.to_act_seat = 2;
+        s.phase = Phase::Comments;
+        assert!(!app.next_to_act_is_me(&s));
+        app.your_id = None;
+        app.your_seat = None;
+        s.phase = Phase::Acting;
+        assert!(!app.next_to_act_is_me(&s));
+    }
+
+    #[test]
+    fn test_auto_ready_toggle_catches_up() {
+        let (tx, mut rx) = iced::futures::channel::mpsc::unbounded();
+        let mut app = app_with_stack(1000);
+        app.tx_out = Some(tx);
+
+        // Turned on in the lobby while unready: ready now, not next update
+        let s = app.snapshot.as_mut().unwrap();
+        s.phase = Phase::Lobby;
+        s.players[0].ready = false;
+        let _ = app.update(Msg::SetAutoReady(true));
+        assert!(app.auto_ready);
+        assert!(matches!(rx.try_next(), Ok(Some(ClientToServer::SitReady))));
+
+        // Turned on during comments: continue once
+        let _ = app.update(Msg::SetAutoReady(false));
+        app.snapshot.as_mut().unwrap().phase = Phase::Comments;
+        let _ = app.update(Msg::SetAutoReady(true));
+        assert!(matches!(rx.try_next(), Ok(Some(ClientToServer::ContinueToNextGame))));
+        let _ = app.update(Msg::SetAutoReady(true));
+        assert!(rx.try_next().is_err());
+
+        // Turning it off also ends sitting out
+        app.sitting_out = true;
+        let _ = app.update(Msg::SetAutoReady(false));
+        assert!(!app.auto_ready && !app.sitting_out);
+    }
+
+    #[test]
+    fn test_auto_ready_between_hands() {
+        let (tx, mut rx) = iced::futures::channel::mpsc::unbounded();
+        let mut app = app_with_stack(1000);
+        app.tx_out = Some(tx);
+        app.auto_ready = true;
+        app.app_state = AppState::Game;
+        let mut snapshot = app.snapshot.clone().unwrap();
+
+        // Hand over: continue automatically
+        snapshot.phase = Phase::Comments;
+        let _ = app.update(Msg::WsEvent(ServerToClient::UpdateState { snapshot: snapshot.clone() }));
+        assert!(matches!(rx.try_next(), Ok(Some(ClientToServer::ContinueToNextGame))));
+
+        // Back in the lobby and not ready yet: ready up
+        snapshot.phase = Phase::Lobby;
+        snapshot.players[0].ready = false;
+        let _ = app.update(Msg::WsEvent(ServerToClient::UpdateState { snapshot: snapshot.clone() }));
+        assert!(matches!(rx.try_next(), Ok(Some(ClientToServer::SitReady))));
+
+        // Sitting out: nothing is sent for us
+        let _ = app.update(Msg::ToggleSitOut);
+        let _ = app.update(Msg::WsEvent(ServerToClient::UpdateState { snapshot }));
+        assert!(rx.try_next().is_err(), "nothing queued while sitting out");
+    }
+
+    #[test]
+    fn test_watching_summary() {
+        let watcher = |name: &str| cctmog_protocol::PublicSpectator { id: Uuid::new_v4(), name: name.to_string() };
+        assert_eq!(watching_summary(&[]), None);
+        assert_eq!(watching_summary(&[watcher("Alice")]).unwrap(), "Watching: Alice");
+
+        let many: Vec<_> = ["Alice", "Bob", "Cy", "Di", "Ed"].into_iter().map(watcher).collect();
+        assert_eq!(watching_summary(&many).unwrap(), "Watching: Alice, Bob (+3)");
+
+        // Picking a spectator sends chat to them privately
+        let (tx, mut rx) = iced::futures::channel::mpsc::unbounded();
+        let mut app = app_with_stack(1000);
+        app.tx_out = Some(tx);
+        let _ = app.update(Msg::MessageSpectator(many[1].id, many[1].name.clone()));
+        app.chat_input = "hi".to_string();
+        let _ = app.update(Msg::SendChat);
+        assert!(matches!(
+            rx.try_next(),
+            Ok(Some(ClientToServer::PrivateMessage { recipient, .. })) if recipient == many[1].id
+        ));
+    }
+
+    #[test]
+    fn test_sorted_hand_is_display_only() {
+        use cctmog_protocol::{Card, Rank, Suit};
+        let c = |rank, suit| Card { rank, suit, face_up: false };
+        let mut app = App::default();
+        app.your_hand.down_cards = vec![c(Rank::King, Suit::Clubs), c(Rank::Two, Suit::Hearts), c(Rank::Nine, Suit::Spades)];
+
+        assert_eq!(app.display_cards(&app.your_hand.down_cards), app.your_hand.down_cards);
+
+        let _ = app.update(Msg::ToggleSortHand);
+        let shown = app.display_cards(&app.your_hand.down_cards);
+        assert_eq!(shown, vec![c(Rank::Two, Suit::Hearts), c(Rank::Nine, Suit::Spades), c(Rank::King, Suit::Clubs)]);
+        assert_eq!(app.your_hand.down_cards[0].rank, Rank::King, "hand keeps deal order");
+    }
+
+    #[test]
+    fn test_spectator_detected_from_snapshot() {
+        let mut app = app_with_stack(1000);
+        let snapshot = app.snapshot.clone().unwrap();
+        assert!(!app.is_spectator(&snapshot));
+
+        // A stale seat number doesn't make a watcher a player
+        app.your_id = Some(Uuid::new_v4());
+        assert!(app.is_spectator(&snapshot));
+        app.your_id = None;
+        assert!(app.is_spectator(&snapshot));
+    }
+
+    #[test]
+    fn test_spectator_commands_suppressed() {
+        let (tx, mut rx) = iced::futures::channel::mpsc::unbounded();
+        let mut app = App { connected: true, tx_out: Some(tx), ..app_with_stack(1000) };
+        app.your_id = Some(Uuid::new_v4()); // not among the snapshot's players
+
+        let _ = app.update(Msg::Fold);
+        assert!(rx.try_next().is_err(), "fold isn't sent while spectating");
+
+        // Asking for a seat still goes out
+        let _ = app.update(Msg::TakeSeat);
+        assert!(matches!(rx.try_next(), Ok(Some(ClientToServer::TakeSeat))));
+    }
+
+    #[test]
+    fn test_protocol_version_mismatch() {
+        let (tx, _rx) = iced::futures::channel::mpsc::unbounded();
+        let mut app = App { connected: true, tx_out: Some(tx), ..App::default() };
+        let id = Uuid::new_v4();
+
+        let _ = app.update(Msg::WsEvent(ServerToClient::Hello { your_id: id, protocol_version: PROTOCOL_VERSION + 1 }));
+        assert!(app.version_error.as_deref().is_some_and(|m| m.contains("Please update your client")));
+        assert!(!app.connected && app.tx_out.is_none());
+        assert_eq!(app.your_id, None);
+
+        let _ = app.update(Msg::WsEvent(ServerToClient::Hello { your_id: id, protocol_version: PROTOCOL_VERSION }));
+        assert_eq!(app.version_error, None);
+        assert_eq!(app.your_id, Some(id));
+    }
+
+    #[test]
+    fn test_ping_round_trip() {
+        let (tx, mut rx) = iced::futures::channel::mpsc::unbounded();
+        let mut app = App { connected: true, tx_out: Some(tx), ..App::default() };
+
+        let _ = app.update(Msg::SendPing);
+        let nonce = match rx.try_next() {
+            Ok(Some(ClientToServer::Ping { nonce })) => nonce,
+            other => panic!("expected a ping, got {:?}", other),
         };
+        assert!(app.pings_in_flight.contains_key(&nonce));
+        assert_eq!(app.latency_ms, None);
 
-        if let Some(my_seat) = my_seat_opt {
-            let me = s.players.iter().find(|p| p.seat == my_seat);
-            s.phase == Phase::Acting
-                && s.to_act_seat == my_seat
-                && me.map(|p| !p.folded && !p.standing).unwrap_or(false)
-        } else {
-            false
+        // A pong we never asked for is ignored
+        let _ = app.update(Msg::WsEvent(ServerToClient::Pong { nonce: nonce + 100 }));
+        assert_eq!(app.latency_ms, None);
+
+        let _ = app.update(Msg::WsEvent(ServerToClient::Pong { nonce }));
+        assert!(app.latency_ms.is_some());
+        assert!(app.pings_in_flight.is_empty());
+    }
+
+    #[test]
+    fn test_comments_grouped_by_target() {
+        use cctmog_protocol::{ActionEvent, ActionKind, CommentTarget, GameComment};
+
+        let bet = ActionEvent {
+            round: 3,
+            player_id: Uuid::new_v4(),
+            player_name: "John".to_string(),
+            kind: ActionKind::Bet,
+            amount: 20,
+        };
+        let comment = |message: &str, about: Option<CommentTarget>| GameComment {
+            player_id: Uuid::new_v4(),
+            player_name: "Ann".to_string(),
+            message: message.to_string(),
+            timestamp: String::new(),
+            about,
+        };
+
+        let app = App {
+            game_comments: vec![
+                comment("bold bet", Some(CommentTarget::Action(bet.clone()))),
+                comment("gg all", None),
+                comment("what a flop", Some(CommentTarget::Round(1))),
+                comment("way too big", Some(CommentTarget::Action(bet.clone()))),
+                comment("see you next hand", None),
+            ],
+            ..App::default()
+        };
+
+        let groups: Vec<(Option<&CommentTarget>, Vec<&str>)> = app
+            .comment_groups()
+            .into_iter()
+            .map(|(about, cs)| (about, cs.iter().map(|c| c.message.as_str()).collect()))
+            .collect();
+        assert_eq!(
+            groups,
+            vec![
+                (None, vec!["gg all", "see you next hand"]),
+                (Some(&CommentTarget::Action(bet)), vec!["bold bet", "way too big"]),
+                (Some(&CommentTarget::Round(1)), vec!["what a flop"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_continue_progress_counts_ready_players() {
+        let mut app = app_with_stack(100);
+        assert_eq!(app.continue_progress(), None, "only shown in the comments phase");
+
+        let s = app.snapshot.as_mut().unwrap();
+        s.phase = Phase::Comments;
+        let mut bob = s.players[0].clone();
+        bob.id = Uuid::new_v4();
+        bob.ready = false;
+        s.players.push(bob);
+        assert_eq!(app.continue_progress(), Some((1, 2)));
+    }
+
+    #[test]
+    fn test_leaderboard_nets_across_showdowns() {
+        use cctmog_protocol::{ActionEvent, ActionKind};
+
+        let mut app = app_with_stack(1000); // ante 10
+        let me = app.your_id.unwrap();
+        let bob = Uuid::new_v4();
+        let act = |player_id, name: &str, kind, amount| ActionEvent {
+            round: 1,
+            player_id,
+            player_name: name.to_string(),
+            kind,
+            amount,
+        };
+
+        // alice bets 20, bob calls and takes the 60 chip pot
+        app.record_showdown(
+            &[(bob, 60)],
+            &[act(me, "alice", ActionKind::Bet, 20), act(bob, "bob", ActionKind::Call, 20)],
+        );
+        assert_eq!(app.leaderboard_rows(), vec![("bob", 30), ("alice", -30)]);
+
+        // Next hand bob folds and alice takes the two antes
+        app.record_showdown(
+            &[(me, 20)],
+            &[act(me, "alice", ActionKind::Check, 0), act(bob, "bob", ActionKind::Fold, 0)],
+        );
+        assert_eq!(app.leaderboard_rows(), vec![("bob", 20), ("alice", -20)]);
+
+        let _ = app.update(Msg::ToggleLeaderboardSort);
+        assert_eq!(app.leaderboard_rows(), vec![("alice", -20), ("bob", 20)]);
+    }
+
+    #[test]
+    fn test_removed_returns_to_lounge() {
+        let mut app = app_with_stack(500);
+        app.app_state = AppState::Game;
+        let _ = app.update(Msg::WsEvent(ServerToClient::Removed { reason: "Host closed the table".to_string() }));
+        assert_eq!(app.app_state, AppState::Lounge);
+        assert!(app.snapshot.is_none());
+        assert!(app.your_seat.is_none());
+        assert_eq!(app.removed_reason.as_deref(), Some("Host closed the table"));
+    }
+
+    #[test]
+    fn test_connect_times_out_or_cancels() {
+        let mut app = App { app_state: AppState::Game, ..App::default() };
+        let _ = app.update(Msg::ConnectToggle);
+        let _ = app.update(Msg::Tick);
+        assert!(app.connecting && app.connect_started.is_some());
+
+        // Still waiting past the timeout: give up with a reason
+        app.connect_started = Some(Instant::now() - CONNECT_TIMEOUT);
+        let _ = app.update(Msg::Tick);
+        assert_eq!(app.app_state, AppState::Lounge);
+        assert!(!app.connecting && app.tx_out.is_none());
+        assert!(app.removed_reason.as_deref().is_some_and(|r| r.contains("Couldn't reach")));
+
+        // Cancelling by hand goes back the same way
+        app.app_state = AppState::Game;
+        let _ = app.update(Msg::ConnectToggle);
+        let _ = app.update(Msg::CancelConnect);
+        assert_eq!(app.app_state, AppState::Lounge);
+        assert!(!app.connecting && app.connect_started.is_none());
+    }
+
+    #[test]
+    fn test_chat_entries_keep_time_and_order() {
+        let mut app = App::default();
+        let chat = |name: &str, message: &str, timestamp: &str| ServerToClient::ChatMessage {
+            player_name: name.to_string(),
+            message: message.to_string(),
+            scope: MessageScope::Match,
+            room: None,
+            timestamp: timestamp.to_string(),
+            recipient: None,
+        };
+        let _ = app.update(Msg::WsEvent(chat("Alice", "second", "2025-09-30T21:05:00+00:00")));
+        // Older message arriving late (history) slots in ahead
+        let _ = app.update(Msg::WsEvent(chat("Bob", "first", "2025-09-30T21:01:00+00:00")));
+        let _ = app.update(Msg::WsEvent(chat("Cara", "third", "2025-09-30T21:05:00+00:00")));
+
+        let order: Vec<&str> = app.chat_messages.iter().map(|m| m.message.as_str()).collect();
+        assert_eq!(order, ["first", "second", "third"]);
+
+        let line = app.chat_messages[0].line();
+        let time = crate::ui::views::comment_time("2025-09-30T21:01:00+00:00");
+        assert_eq!(line, format!("[{}] [Match] Bob: first", time));
+        assert_eq!(time.len(), 5, "shown as HH:MM");
+    }
+
+    #[test]
+    fn test_full_table_join_queues_for_seat() {
+        let snapshot = app_with_stack(500).snapshot.unwrap();
+
+        let (tx, mut rx) = iced::futures::channel::mpsc::unbounded();
+        let mut app = App { connected: true, tx_out: Some(tx), ..App::default() };
+        let _ = app.update(Msg::WsEvent(ServerToClient::SpectatorJoined { snapshot: snapshot.clone() }));
+        assert!(app.spectating);
+        assert_eq!(app.app_state, AppState::Game);
+        assert!(matches!(rx.try_next(), Ok(Some(ClientToServer::RequestSeat))));
+
+        // Watching on purpose: stay a spectator
+        let (tx, mut rx) = iced::futures::channel::mpsc::unbounded();
+        let mut app = App { connected: true, tx_out: Some(tx), watch_only: true, ..App::default() };
+        let _ = app.update(Msg::WsEvent(ServerToClient::SpectatorJoined { snapshot }));
+        assert!(app.spectating);
+        assert!(rx.try_next().is_err());
+    }
+
+    #[test]
+    fn test_auto_muck_only_losing_hands() {
+        let mut app = app_with_stack(500);
+        let mut s = app.snapshot.clone().unwrap();
+        let me = app.your_id.unwrap();
+        let winner = Uuid::new_v4();
+        s.phase = Phase::Showdown;
+        s.showdown_winners = vec![winner];
+
+        // Off unless asked for
+        assert!(!app.should_auto_muck(&s));
+        app.auto_muck = true;
+        assert!(app.should_auto_muck(&s));
+
+        // Winners keep their hand; so does everyone outside the muck window
+        s.showdown_winners = vec![me];
+        assert!(!app.should_auto_muck(&s));
+        s.showdown_winners = vec![winner];
+        s.phase = Phase::Comments;
+        assert!(!app.should_auto_muck(&s));
+
+        // Once per showdown
+        s.phase = Phase::Showdown;
+        app.muck_sent = true;
+        assert!(!app.should_auto_muck(&s));
+    }
+
+    #[test]
+    fn test_auto_muck_toggle_mucks_open_window() {
+        let (tx, mut rx) = iced::futures::channel::mpsc::unbounded();
+        let mut app = app_with_stack(500);
+        app.connected = true;
+        app.tx_out = Some(tx);
+        let s = app.snapshot.as_mut().unwrap();
+        s.phase = Phase::Showdown;
+        s.showdown_winners = vec![Uuid::new_v4()];
+
+        // Switched on while our losing hand can still be mucked
+        let _ = app.update(Msg::SetAutoMuck(true));
+        assert!(app.auto_muck && app.muck_sent);
+        assert!(matches!(rx.try_next(), Ok(Some(ClientToServer::Muck))));
+
+        // Switching it off and on again doesn't muck twice
+        let _ = app.update(Msg::SetAutoMuck(false));
+        let _ = app.update(Msg::SetAutoMuck(true));
+        assert!(rx.try_next().is_err());
+    }
+
+    #[test]
+    fn test_hand_strength_from_board() {
+        use cctmog_protocol::{Card, Rank, Suit};
+        let card = |rank, suit| Card { rank, suit, face_up: true };
+        let mut app = app_with_stack(500);
+        let mut s = app.snapshot.clone().unwrap();
+        app.your_hand.down_cards = vec![
+            card(Rank::King, Suit::Hearts), card(Rank::King, Suit::Spades),
+            card(Rank::Nine, Suit::Hearts), card(Rank::Two, Suit::Clubs),
+        ];
+        s.community_cards = vec![
+            card(Rank::Ace, Suit::Hearts), card(Rank::Seven, Suit::Hearts), card(Rank::Four, Suit::Hearts),
+        ];
+
+        // 7/27 has no board to play with
+        s.game_variant = GameVariant::SevenTwentySeven;
+        assert_eq!(app.hand_strength(&s), None);
+
+        // Hold'em plays any five: three hearts on board plus two in hand
+        s.game_variant = GameVariant::TexasHoldem;
+        assert_eq!(app.hand_strength(&s).as_deref(), Some("Flush, Ace high"));
+
+        // Omaha needs exactly two from the hand and three from the board
+        s.game_variant = GameVariant::Omaha;
+        assert_eq!(app.hand_strength(&s).as_deref(), Some("Flush, Ace high"));
+        app.your_hand.down_cards[2] = card(Rank::Nine, Suit::Clubs);
+        assert_eq!(app.hand_strength(&s).as_deref(), Some("Pair of Kings"));
+
+        // Before the flop there's nothing to show
+        s.community_cards.clear();
+        assert_eq!(app.hand_strength(&s), None);
+    }
+
+    #[test]
+    fn test_illegal_action_is_not_sent() {
+        let mut app = app_with_stack(1000);
+        let (tx, mut rx) = iced::futures::channel::mpsc::unbounded();
+        app.tx_out = Some(tx);
+
+        // Facing a bet: Check stays local and leaves a warning
+        if let Some(s) = app.snapshot.as_mut() {
+            s.current_bet = 20;
+            s.raises_made = 3;
         }
+        let _ = app.update(Msg::Check);
+        assert!(rx.try_next().is_err(), "check facing a bet isn't sent");
+        assert_eq!(app.action_warning.as_deref(), Some("Can't check facing a bet"));
+
+        // Raise cap reached
+        let _ = app.update(Msg::Raise);
+        assert!(rx.try_next().is_err(), "raise over the cap isn't sent");
+        assert!(app.pending_bet.is_none());
+
+        // Calling is fine and goes out
+        let _ = app.update(Msg::Call);
+        assert!(matches!(rx.try_next(), Ok(Some(ClientToServer::Call))));
     }
-}
 
+    #[test]
+    fn test_footer_table_summary() {
+        let mut app = app_with_stack(1000);
+        let s = app.snapshot.as_mut().unwrap();
+        s.pot = 350;
+        assert_eq!(crate::ui::shared::table_summary(s), "1 player · pot 350");
+
+        s.spectators = vec![
+            cctmog_protocol::PublicSpectator { id: Uuid::new_v4(), name: "Bob".to_string() },
+            cctmog_protocol::PublicSpectator { id: Uuid::new_v4(), name: "Cy".to_string() },
+        ];
+        s.players.push(PublicPlayer { id: Uuid::new_v4(), name: "Dee".to_string(), seat: 1, ..s.players[0].clone() });
+        assert_eq!(crate::ui::shared::table_summary(s), "2 players · 2 watching · pot 350");
+    }
+
+    #[test]
+    fn test_socket_kept_through_setup_screens() {
+        for (state, uses_socket) in [
+            (AppState::Splash, false),
+            (AppState::NameInput, false),
+            (AppState::Lounge, true),
+            (AppState::TableChoice, false),
+            (AppState::TableCreation, false),
+            (AppState::TableBrowser, false),
+            (AppState::ConnectOverlay, true),
+            (AppState::Game, true),
+            (AppState::Comments, true),
+            (AppState::DealerSelection, true),
+            (AppState::DealerSplash, true),
+            (AppState::GameSelection, true),
+            (AppState::Tutorial, true),
+        ] {
+            assert_eq!(state.uses_socket(), uses_socket, "{:?}", state);
+        }
+    }
+
+    #[test]
+    fn test_app_state_for_phase() {
+        use crate::states::app_state_for_phase;
+        assert_eq!(app_state_for_phase(&Phase::Lobby), AppState::Game);
+        assert_eq!(app_state_for_phase(&Phase::WaitingForDealer), AppState::Game);
+        assert_eq!(app_state_for_phase(&Phase::DealerSelection), AppState::DealerSelection);
+        assert_eq!(app_state_for_phase(&Phase::GameSelection), AppState::GameSelection);
+        assert_eq!(app_state_for_phase(&Phase::Dealing), AppState::Game);
+        assert_eq!(app_state_for_phase(&Phase::Acting), AppState::Game);
+        assert_eq!(app_state_for_phase(&Phase::Showdown), AppState::Game);
+        assert_eq!(app_state_for_phase(&Phase::Comments), AppState::Comments);
+
+        // Reconnecting mid-setup asks for a resync and lands on the dealer screen
+        let mut app = app_with_stack(1000);
+        app.room = app.snapshot.as_ref().unwrap().room.clone();
+        app.app_state = AppState::GameSelection;
+        let (tx, mut rx) = iced::futures::channel::mpsc::unbounded();
+        let _ = app.update(Msg::WsConnected(tx));
+        assert!(matches!(rx.try_next(), Ok(Some(ClientToServer::Resync))));
+        let mut snapshot = app.snapshot.clone().unwrap();
+        snapshot.phase = Phase::DealerSelection;
+        let _ = app.update(Msg::WsEvent(ServerToClient::UpdateState { snapshot }));
+        assert_eq!(app.app_state, AppState::DealerSelection);
+    }
+
+    #[test]
+    fn test_screen_follows_server_phase() {
+        let mut app = app_with_stack(1000);
+        app.app_state = AppState::Game;
+        let mut snapshot = app.snapshot.clone().unwrap();
+        let mut step = |app: &mut App, phase: Phase| {
+            snapshot.phase = phase;
+            let _ = app.update(Msg::WsEvent(ServerToClient::UpdateState { snapshot: snapshot.clone() }));
+            app.app_state.clone()
+        };
+
+        assert_eq!(step(&mut app, Phase::Lobby), AppState::Game);
+        // Picking a dealer locally isn't undone by more lobby updates
+        let _ = app.update(Msg::GoToDealerSelection);
+        assert_eq!(step(&mut app, Phase::Lobby), AppState::DealerSelection);
+        // ...but the server moving on is followed
+        assert_eq!(step(&mut app, Phase::WaitingForDealer), AppState::Game);
+        assert_eq!(step(&mut app, Phase::DealerSelection), AppState::DealerSelection);
+        assert_eq!(step(&mut app, Phase::GameSelection), AppState::GameSelection);
+        assert_eq!(step(&mut app, Phase::Dealing), AppState::Game);
+        assert_eq!(step(&mut app, Phase::Acting), AppState::Game);
+        assert_eq!(step(&mut app, Phase::Showdown), AppState::Game);
+        assert_eq!(step(&mut app, Phase::Comments), AppState::Comments);
+        assert_eq!(step(&mut app, Phase::Lobby), AppState::Game);
+
+        // Away from the table, snapshots don't pull us back
+        app.app_state = AppState::Lounge;
+        assert_eq!(step(&mut app, Phase::Acting), AppState::Lounge);
+    }
+
+    #[test]
+    fn test_election_and_dealer_choice_sent() {
+        let mut app = app_with_stack(1000);
+        let me = app.snapshot.as_ref().unwrap().players[0].id;
+        app.your_id = Some(me);
+        app.app_state = AppState::Game;
+        let (tx, mut rx) = iced::futures::channel::mpsc::unbounded();
+        app.tx_out = Some(tx);
+
+        let mut snapshot = app.snapshot.clone().unwrap();
+        snapshot.phase = Phase::WaitingForDealer;
+        snapshot.elected_players = vec![Uuid::new_v4()];
+        snapshot.players.push(PublicPlayer { id: Uuid::new_v4(), name: "Bob".to_string(), seat: 1, ..snapshot.players[0].clone() });
+        assert_eq!(election_status(&snapshot), "Waiting for all players to elect to start (1/2)");
+        let _ = app.update(Msg::WsEvent(ServerToClient::UpdateState { snapshot: snapshot.clone() }));
+        while rx.try_next().is_ok() {}
+
+        let _ = app.update(Msg::ElectToStart);
+        assert!(matches!(rx.try_next(), Ok(Some(ClientToServer::ElectToStart))));
+
+        // Everyone elected: the dealer screen, whose pick goes to the server
+        snapshot.phase = Phase::DealerSelection;
+        let _ = app.update(Msg::WsEvent(ServerToClient::UpdateState { snapshot }));
+        assert_eq!(app.app_state, AppState::DealerSelection);
+        while rx.try_next().is_ok() {}
+        let _ = app.update(Msg::DealerSelected("alice".to_string()));
+        assert!(matches!(rx.try_next(), Ok(Some(ClientToServer::DelegateDealer { player_id })) if player_id == me));
+        assert_eq!(app.app_state, AppState::DealerSplash);
+    }
+}
@@ -15,7 +15,7 @@ use std::{
 use tokio::sync::mpsc;
 use uuid::Uuid;
 
-use cctmog_protocol::{ClientToServer, ServerToClient, Phase, PrivateHand, StoredMessage};
+use cctmog_protocol::{ClientToServer, ErrorCode, ServerToClient, Phase, PrivateHand, StoredMessage};
 
 // Re-use the game logic from the server
 use crate::game;
@@ -94,6 +94,24 @@ impl EmbeddedServerState {
             }
         }
     }
+
+    async fn unregister_table_with_central_server(&self, name: &str) {
+        let central_server_url = "ws://127.0.0.1:9001/ws";
+        match tokio_tungstenite::connect_async(central_server_url).await {
+            Ok((mut ws, _)) => {
+                let unregister_msg = cctmog_protocol::ClientToServer::UnregisterTable { name: name.to_string() };
+                let msg_json = serde_json::to_string(&unregister_msg).unwrap();
+                match ws.send(tokio_tungstenite::tungstenite::Message::Text(msg_json)).await {
+                    Ok(_) => println!("[EMBEDDED] Unregistered table '{}' from central server", name),
+                    Err(e) => println!("[EMBEDDED] Failed to send unregister message: {}", e),
+                }
+                let _ = ws.close(None).await;
+            }
+            Err(e) => {
+                println!("[EMBEDDED] Failed to connect to central server to unregister table: {}", e);
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -304,11 +322,12 @@ async fn handle_client_message(
             }
         }
 
-        ClientToServer::CreateTable { name, game_variant, ante, limit_small, limit_big, max_raises } => {
+        ClientToServer::CreateTable { name, game_variant, ante, limit_small, limit_big, max_raises, .. } => {
             let trimmed_name = name.trim();
             if trimmed_name.is_empty() {
                 let _ = tx_out.send(ServerToClient::Error {
                     message: "Table name cannot be empty".to_string(),
+                    code: ErrorCode::Other,
                 });
                 return;
             }
@@ -319,6 +338,7 @@ async fn handle_client_message(
                 if rooms.contains_key(trimmed_name) {
                     let _ = tx_out.send(ServerToClient::Error {
                         message: format!("Table '{}' already exists", trimmed_name),
+                        code: ErrorCode::Other,
                     });
                     return;
                 }
@@ -344,10 +364,53 @@ async fn handle_client_message(
                 message: format!("Table '{}' created successfully on your local server!", trimmed_name),
             });
         }
+        ClientToServer::CloseTable => {
+            let joined_room = {
+                let players = state.players.lock();
+                players.get(&player_id).and_then(|p| p.joined_room.clone())
+            };
+            let Some(room_name) = joined_room else {
+                return;
+            };
+
+            // Same rule as the main server: the dealer, or the dealer seat before one is set
+            let closed = {
+                let mut rooms = state.inner.lock();
+                let is_host = rooms.get(&room_name).is_some_and(|r| {
+                    r.current_dealer_id.or_else(|| r.players.get(r.dealer_seat).map(|p| p.id)) == Some(player_id)
+                });
+                if is_host {
+                    rooms.remove(&room_name)
+                } else {
+                    None
+                }
+            };
+            let Some(game_room) = closed else {
+                let _ = tx_out.send(ServerToClient::Error {
+                    message: "Only the dealer can close the table.".to_string(),
+                    code: ErrorCode::Other,
+                });
+                return;
+            };
+
+            let closed_msg = ServerToClient::Removed {
+                reason: "The host closed the table".to_string(),
+            };
+            for player in &game_room.players {
+                let _ = player.tx.send(closed_msg.clone());
+            }
+            for spectator in &game_room.spectators {
+                let _ = spectator.tx.send(closed_msg.clone());
+            }
+            println!("[EMBEDDED] Table '{}' closed by {}", room_name, &player_id.to_string()[..8]);
+
+            state.unregister_table_with_central_server(&room_name).await;
+        }
         _ => {
             // For other messages, we can implement them later or delegate to main server logic
             let _ = tx_out.send(ServerToClient::Error {
                 message: "Feature not yet implemented in embedded server".to_string(),
+                code: ErrorCode::Other,
             });
         }
     }
@@ -43,6 +43,7 @@ pub struct Room {
     // Dealer system tracking
     pub elected_players: Vec<Uuid>,
     pub current_dealer_id: Option<Uuid>,
+    pub dealers_choice: bool,
 
     // Game chat messages
     pub chat_messages: Vec<StoredMessage>,
@@ -99,6 +100,7 @@ impl Room {
             spectators: vec![],
             elected_players: vec![],
             current_dealer_id: None,
+            dealers_choice: false,
             chat_messages: vec![],
         }
     }
@@ -134,6 +136,9 @@ impl Room {
             raises_made: self.raises_made,
             max_raises: self.max_raises,
             round: self.round,
+            round_label: self.game_variant.round_label(self.round, self.in_betting),
+            street: Street::for_board(self.game_variant, self.community_cards.len()),
+            split_pot: self.game_variant.config().hi_lo,
             limit_small: self.limit_small,
             limit_big: self.limit_big,
             community_cards: self.community_cards.clone(),
@@ -141,7 +146,18 @@ impl Room {
             checked_in_players: self.checked_in_players.clone(),
             elected_players: self.elected_players.clone(),
             current_dealer_id: self.current_dealer_id,
+            current_dealer_name: self
+                .current_dealer_id
+                .and_then(|id| self.players.iter().find(|p| p.id == id))
+                .map(|p| p.name.clone()),
+            elected_count: self.elected_players.len(),
+            rabbit_hunt_open: false,
+            raked_total: 0,
+            showdown_winners: vec![],
             available_variants: vec![GameVariant::SevenTwentySeven, GameVariant::Omaha, GameVariant::TexasHoldem],
+            dealers_choice: self.dealers_choice,
+            turn_started_at: None,
+            spectators: self.spectators.iter().map(|s| PublicSpectator { id: s.id, name: s.name.clone() }).collect(),
             players: self
                 .players
                 .iter()
@@ -157,6 +173,8 @@ impl Room {
                     cards_count: p.up_cards.len() + p.down_cards.len(),
                     committed_round: p.committed_round,
                     ready: p.ready,
+                    color_seed: PublicPlayer::color_seed_for(p.id),
+                    status: None,
                 })
                 .collect(),
         }
@@ -225,6 +243,9 @@ pub fn public_room(r: &Room) -> PublicRoom {
         raises_made: r.raises_made,
         max_raises: r.max_raises,
         round: r.round,
+        round_label: r.game_variant.round_label(r.round, r.in_betting),
+        street: Street::for_board(r.game_variant, r.community_cards.len()),
+        split_pot: r.game_variant.config().hi_lo,
         limit_small: r.limit_small,
         limit_big: r.limit_big,
         community_cards: r.community_cards.clone(),
@@ -232,7 +253,18 @@ pub fn public_room(r: &Room) -> PublicRoom {
         checked_in_players: r.checked_in_players.clone(),
         elected_players: r.elected_players.clone(),
         current_dealer_id: r.current_dealer_id,
+        current_dealer_name: r
+            .current_dealer_id
+            .and_then(|id| r.players.iter().find(|p| p.id == id))
+            .map(|p| p.name.clone()),
+        elected_count: r.elected_players.len(),
+        rabbit_hunt_open: false,
+        raked_total: 0,
+        showdown_winners: vec![],
         available_variants: vec![GameVariant::SevenTwentySeven, GameVariant::Omaha, GameVariant::TexasHoldem],
+        dealers_choice: r.dealers_choice,
+        turn_started_at: None,
+        spectators: r.spectators.iter().map(|s| PublicSpectator { id: s.id, name: s.name.clone() }).collect(),
         players: r
             .players
             .iter()
@@ -248,6 +280,8 @@ pub fn public_room(r: &Room) -> PublicRoom {
                 cards_count: p.up_cards.len() + p.down_cards.len(),
                 committed_round: p.committed_round,
                 ready: p.ready,
+                color_seed: PublicPlayer::color_seed_for(p.id),
+                status: None,
             })
             .collect(),
     }
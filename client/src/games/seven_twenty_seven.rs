@@ -1,4 +1,4 @@
-use cctmog_protocol::{GameVariant, Card, PublicRoom, PrivateHand, Phase};
+use cctmog_protocol::{GameVariant, Card, PublicRoom, PrivateHand, Phase, Rank, Suit};
 use iced::{Element, Length, Alignment};
 use iced_widget::{button, column, container, row, text, Space};
 use crate::messages::Msg;
@@ -142,4 +142,118 @@ impl GameLogic for SevenTwentySevenGame {
             _ => false,
         }
     }
-}
\ No newline at end of file
+}
+/// What the next card could do to a 7/27 hand, by rank. Only ranks with a
+/// copy left unseen are listed; suits don't matter in 7/27.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DrawOuts {
+    /// Ranks that bring the hand closer to 7 or to 27 without busting
+    pub improve: Vec<Rank>,
+    /// Ranks that take the hand over 27
+    pub bust: Vec<Rank>,
+}
+
+impl DrawOuts {
+    /// One-line hint for the action bar, e.g. "Improves: A 2 3 · Busts: 9 10"
+    pub fn summary(&self) -> String {
+        let list = |ranks: &[Rank]| ranks.iter().map(|r| rank_label(*r)).collect::<Vec<_>>().join(" ");
+        let improve = if self.improve.is_empty() { "nothing".to_string() } else { list(&self.improve) };
+        let mut hint = format!("Improves: {}", improve);
+        if !self.bust.is_empty() {
+            hint.push_str(&format!(" · Busts: {}", list(&self.bust)));
+        }
+        hint
+    }
+}
+
+/// Try every rank as the next card for `hand` (your down cards plus up
+/// cards) and sort it into improving and busting ranks. `seen` are other
+/// cards showing on the table, so ranks with all four copies visible are
+/// skipped.
+pub fn draw_outs(hand: &[Card], seen: &[Card]) -> DrawOuts {
+    use cctmog_protocol::score_hand;
+
+    let closer = |new: Option<f32>, cur: Option<f32>| match (new, cur) {
+        (Some(n), Some(c)) => n < c,
+        (Some(_), None) => true,
+        _ => false,
+    };
+
+    let current = score_hand(hand);
+    let mut outs = DrawOuts::default();
+    for rank in ALL_RANKS {
+        let visible = hand.iter().chain(seen).filter(|c| c.rank == rank).count();
+        if visible >= 4 {
+            continue;
+        }
+        let mut next = hand.to_vec();
+        next.push(Card { rank, suit: Suit::Clubs, face_up: false });
+        let score = score_hand(&next);
+        if score.bust_27 {
+            outs.bust.push(rank);
+        } else if closer(score.dist_to_7, current.dist_to_7) || closer(score.dist_to_27, current.dist_to_27) {
+            outs.improve.push(rank);
+        }
+    }
+    outs
+}
+
+const ALL_RANKS: [Rank; 13] = [
+    Rank::Ace, Rank::Two, Rank::Three, Rank::Four, Rank::Five, Rank::Six, Rank::Seven,
+    Rank::Eight, Rank::Nine, Rank::Ten, Rank::Jack, Rank::Queen, Rank::King,
+];
+
+fn rank_label(rank: Rank) -> &'static str {
+    match rank {
+        Rank::Ace => "A",
+        Rank::Two => "2",
+        Rank::Three => "3",
+        Rank::Four => "4",
+        Rank::Five => "5",
+        Rank::Six => "6",
+        Rank::Seven => "7",
+        Rank::Eight => "8",
+        Rank::Nine => "9",
+        Rank::Ten => "10",
+        Rank::Jack => "J",
+        Rank::Queen => "Q",
+        Rank::King => "K",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(rank: Rank, suit: Suit) -> Card {
+        Card { rank, suit, face_up: false }
+    }
+
+    #[test]
+    fn test_draw_outs_for_sample_hand() {
+        // 9 + 10 = 19: anything from 2 to 8 (or a face card, or an ace)
+        // gets closer to 27, 9 and 10 bust
+        let hand = [card(Rank::Nine, Suit::Hearts), card(Rank::Ten, Suit::Spades)];
+        let outs = draw_outs(&hand, &[]);
+        assert_eq!(outs.bust, vec![Rank::Nine, Rank::Ten]);
+        assert_eq!(
+            outs.improve,
+            vec![Rank::Ace, Rank::Two, Rank::Three, Rank::Four, Rank::Five, Rank::Six,
+                 Rank::Seven, Rank::Eight, Rank::Jack, Rank::Queen, Rank::King]
+        );
+        assert_eq!(outs.summary(), "Improves: A 2 3 4 5 6 7 8 J Q K · Busts: 9 10");
+
+        // Every other ten is showing on the table: only nines bust
+        let seen = [card(Rank::Ten, Suit::Hearts), card(Rank::Ten, Suit::Clubs), card(Rank::Ten, Suit::Diamonds)];
+        assert_eq!(draw_outs(&hand, &seen).bust, vec![Rank::Nine]);
+
+        // Sitting on 7 and 27 at once (A + 6 + 10 + 10) nothing improves
+        let perfect = [
+            card(Rank::Ace, Suit::Hearts), card(Rank::Six, Suit::Clubs),
+            card(Rank::Ten, Suit::Hearts), card(Rank::Ten, Suit::Clubs),
+        ];
+        let outs = draw_outs(&perfect, &[]);
+        assert!(outs.improve.is_empty());
+        assert!(outs.summary().starts_with("Improves: nothing"));
+    }
+}
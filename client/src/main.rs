@@ -38,10 +38,18 @@ mod ws;
 
 use crate::messages::Msg;
 
+/// Smallest window the layout is designed for; below this the table and
+/// action panels start clipping even in the compact layout.
+const MIN_WINDOW_SIZE: iced::Size = iced::Size::new(900.0, 640.0);
+
 fn main() -> iced::Result {
     iced::application("cctmog (client)", App::update, App::view)
         .subscription(App::subscription)
         .theme(|_| iced::Theme::Dark)
+        .window(iced::window::Settings {
+            min_size: Some(MIN_WINDOW_SIZE),
+            ..Default::default()
+        })
         .run()
 }
 
@@ -351,6 +359,7 @@ pub fn seat_plate(
     is_you: bool,
     is_to_act: bool,
 ) -> iced::Element<'static, crate::messages::Msg> {
+    let accent = crate::ui::game_table::seat_accent(p);
     let name = if is_you {
         format!("{} (you)", p.name)
     } else {
@@ -360,7 +369,9 @@ pub fn seat_plate(
     let header = row![
         chip_stack_icon(),
         Space::with_width(Length::Fixed(8.0)),
-        text(name).size(16),
+        text(name)
+            .size(16)
+            .style(move |_| iced_widget::text::Style { color: Some(accent) }),
         Space::with_width(Length::Fill),
         text(format!("{}", p.chips)).size(16),
     ]
@@ -380,9 +391,30 @@ pub fn seat_plate(
         Space::with_width(Length::Fixed(0.0)).into()
     };
 
-    let plate = container(row![header, act_hint].spacing(8))
+    let standing: Element<Msg> = if p.standing && !p.folded {
+        text("STANDING").size(12).into()
+    } else {
+        Space::with_width(Length::Fixed(0.0)).into()
+    };
+
+    // Player-set note like "BRB", under the name
+    let status: Element<Msg> = match &p.status {
+        Some(note) => text(note.clone())
+            .size(12)
+            .style(|_| iced_widget::text::Style { color: Some(Color::from_rgb(0.7, 0.7, 0.7)) })
+            .into(),
+        None => Space::with_height(Length::Fixed(0.0)).into(),
+    };
+
+    let plate = container(column![row![header, standing, act_hint].spacing(8), status].spacing(2))
         .padding([8.0, 12.0]) // 2-value padding is valid
-        .style(|_| plate()); // use local plate() helper
+        .style(move |_| container::Style {
+            border: Border {
+                color: Color { a: 0.7, ..accent },
+                ..plate().border
+            },
+            ..plate() // use local plate() helper
+        });
 
     let up = row![
         text("Up:").size(14),
@@ -526,11 +558,20 @@ fn pill(label: String) -> Element<'static, Msg> {
     container(text(label).size(16)).padding([6.0, 10.0]).into()
 }
 
+/// Pot odds (pot : call) offered by calling `to_call`.
+fn pot_odds(pot: u64, to_call: u64) -> f32 {
+    pot as f32 / to_call as f32
+}
+
 // client/src/ui/actions.rs (or wherever you keep it)
+/// Buttons come straight from the server's `YourTurn` options, so the bar
+/// never offers an action the server would reject. `your_cards` (down and
+/// up) feed the 7/27 draw hint.
 pub fn render_action_bar(
     s: &PublicRoom,
-    _your_seat: Option<usize>,
-    your_turn: bool,
+    your_seat: Option<usize>,
+    turn: Option<&TurnOptions>,
+    your_cards: &[Card],
 ) -> Element<'static, Msg> {
     use iced::widget::{button, column, row, text, Space};
     use iced::Length;
@@ -541,77 +582,81 @@ pub fn render_action_bar(
         return bar.push(text("Waiting in lobby…").size(14)).into();
     }
 
-    if !your_turn {
+    let Some(opts) = turn else {
         return bar.push(text("Waiting for other players…").size(14)).into();
+    };
+
+    // Once you've stood there is nothing left to draw, whatever a stale
+    // option set says
+    let you_stand = your_seat
+        .and_then(|seat| s.players.iter().find(|p| p.seat == seat))
+        .is_some_and(|p| p.standing);
+    if you_stand && !s.in_betting {
+        return bar.push(text("Standing — waiting for other players…").size(14)).into();
     }
 
     if s.in_betting {
-        if s.current_bet == 0 {
-            bar = bar.push(
-                row![
-                    button(text("Check"))
-                        .on_press(Msg::Check)
-                        .padding([10_u16, 18_u16]),
-                    Space::with_width(Length::Fixed(8.0)),
-                    button(text(format!(
-                        "Bet {}",
-                        if s.round <= 2 {
-                            s.limit_small
-                        } else {
-                            s.limit_big
-                        }
-                    )))
-                    .on_press(Msg::Bet)
-                    .padding([10_u16, 18_u16]),
-                ]
-                .spacing(8),
-            );
-        } else {
-            let can_raise = s.raises_made < s.max_raises;
+        bar = bar.push(text(format!("Raises: {}/{}", s.raises_made, s.max_raises)).size(14));
+        if let Some(to_call) = opts.can_call.filter(|c| *c > 0) {
             bar = bar.push(
-                row![
-                    button(text("Call"))
-                        .on_press(Msg::Call)
-                        .padding([10_u16, 18_u16]),
-                    Space::with_width(Length::Fixed(8.0)),
-                    button(text(format!(
-                        "Raise +{}",
-                        if s.round <= 2 {
-                            s.limit_small
-                        } else {
-                            s.limit_big
-                        }
-                    )))
-                    .on_press_maybe(can_raise.then_some(Msg::Raise))
-                    .padding([10_u16, 18_u16]),
-                    Space::with_width(Length::Fixed(8.0)),
-                    button(text("Fold"))
-                        .on_press(Msg::Fold)
-                        .padding([10_u16, 18_u16]),
-                ]
-                .spacing(8),
+                text(format!("Call {} (pot odds {:.1}:1)", to_call, pot_odds(s.pot, to_call))).size(14),
             );
         }
-    } else {
-        bar = bar.push(
-            row![
-                button(text("Take card"))
-                    .on_press(Msg::TakeCard)
-                    .padding([10_u16, 18_u16]),
-                Space::with_width(Length::Fixed(8.0)),
-                button(text("Stand"))
-                    .on_press(Msg::Stand)
-                    .padding([10_u16, 18_u16]),
-                Space::with_width(Length::Fixed(8.0)),
-                button(text("Fold"))
-                    .on_press(Msg::Fold)
-                    .padding([10_u16, 18_u16]),
-            ]
-            .spacing(8),
+    }
+
+    let mut buttons = row![].spacing(8);
+    if opts.can_check {
+        buttons = buttons.push(button(text("Check")).on_press(Msg::Check).padding([10_u16, 18_u16]));
+    }
+    if let Some(to_call) = opts.can_call {
+        buttons = buttons.push(
+            button(text(format!("Call {}", to_call)))
+                .on_press(Msg::Call)
+                .padding([10_u16, 18_u16]),
         );
     }
+    if let Some(amount) = opts.can_bet {
+        buttons = buttons.push(
+            button(text(format!("Bet {}", amount)))
+                .on_press(Msg::Bet)
+                .padding([10_u16, 18_u16]),
+        );
+    }
+    if s.in_betting && opts.can_bet.is_none() {
+        // Show raise even when capped so the limit is visible
+        let size = if s.round <= 2 { s.limit_small } else { s.limit_big };
+        buttons = buttons.push(
+            button(text(format!("Raise +{}", opts.can_raise.unwrap_or(size))))
+                .on_press_maybe(opts.can_raise.map(|_| Msg::Raise))
+                .padding([10_u16, 18_u16]),
+        );
+        if opts.can_raise.is_none() && s.raises_made >= s.max_raises {
+            buttons = buttons.push(text("betting capped").size(12));
+        }
+    }
+    if opts.can_take_card && !you_stand {
+        buttons = buttons.push(button(text("Take card")).on_press(Msg::TakeCard).padding([10_u16, 18_u16]));
+        if s.game_variant == GameVariant::SevenTwentySeven {
+            let seen: Vec<Card> = s
+                .players
+                .iter()
+                .filter(|p| Some(p.seat) != your_seat)
+                .flat_map(|p| p.up_cards.iter().copied())
+                .collect();
+            let outs = crate::games::seven_twenty_seven::draw_outs(your_cards, &seen);
+            bar = bar.push(text(outs.summary()).size(13));
+        }
+    }
+    if opts.can_stand && !you_stand {
+        buttons = buttons.push(button(text("Stand")).on_press(Msg::Stand).padding([10_u16, 18_u16]));
+    }
+    if opts.can_fold {
+        buttons = buttons
+            .push(Space::with_width(Length::Fixed(8.0)))
+            .push(button(text("Fold")).on_press(Msg::Fold).padding([10_u16, 18_u16]));
+    }
 
-    bar.into()
+    bar.push(buttons).into()
 }
 
 /* ======================= WebSocket subscription ======================= */
@@ -6,20 +6,33 @@ pub enum Msg {
     ConnectToggle,
     WsConnected(iced::futures::channel::mpsc::UnboundedSender<cctmog_protocol::ClientToServer>),
     WsEvent(cctmog_protocol::ServerToClient),
+    DelayedWsEvent(cctmog_protocol::ServerToClient), // released after the stream delay
+    ReleaseDelayedEvents,
     WsError(String),
 
     SitReady,
+    SitUnready,
     StartHand,
+    ForceStart,
     TakeCard,
     Stand,
     Fold,
+    ShowCards,
+    Muck,
+    RabbitHunt,
+    TakeSeat,
+    RequestSeat, // queue for the next open seat
+    Rebuy,
     Check,
     Bet,
     Call,
     Raise,
+    ConfirmBet, // send the bet/raise held back for confirmation
+    CancelBet,
 
     ToggleAssetTest,
     Tick,
+    SendPing, // measure round-trip time to the server
 
     // New messages for splash and table choice
     SplashFinished,
@@ -28,6 +41,8 @@ pub enum Msg {
     BrowseTables,
     CreateNewGame,
     BackToHome,
+    CancelConnect, // give up on a connection attempt
+    FetchChatHistory, // refresh the lounge's recent activity panel
 
     // Chat messages
     ChatInputChanged(String),
@@ -43,9 +58,12 @@ pub enum Msg {
     ScheduleGame,
     ScheduleTimeChanged(String),
     CheckIn,
+    CancelSchedule,
 
     // Game variant selection
     SelectGameVariant(cctmog_protocol::GameVariant),
+    SetDealersChoice(bool),
+    ChooseNextVariant(cctmog_protocol::GameVariant),
 
     // Table creation form inputs
     TableNameChanged(String),
@@ -54,6 +72,12 @@ pub enum Msg {
     TableLimitSmallChanged(String),
     TableLimitBigChanged(String),
     TableMaxRaisesChanged(String),
+    TableMinPlayersChanged(String),
+    TableRakePercentChanged(String),
+    TableRakeCapChanged(String),
+    TableMuckWindowChanged(String),
+    TableAutoDealerToggled(bool),
+    TableRabbitHuntToggled(bool),
     SubmitTableCreation,
     StartEmbeddedServerForTable,
     EmbeddedServerStarted(u16),
@@ -62,17 +86,37 @@ pub enum Msg {
     // Comments phase messages
     CommentInputChanged(String),
     PostComment,
+    CommentAboutChanged(Option<cctmog_protocol::CommentTarget>),
     ContinueToNextGame,
+    ToggleSitOut, // stop/resume auto-ready between hands
+    SetFoldToAnyBet(bool), // standing check/fold for every street until turned off
+    SetAutoReady(bool), // ready up and continue between hands without clicking
+    SetConfirmBets(bool), // ask before bets that commit a large share of the stack
+    SetAutoMuck(bool), // muck a losing hand as soon as the muck window opens
+    StatusInputChanged(String),
+    SetStatus, // send the status box; empty clears it
+    ToggleSpectators,
+    ToggleLeaderboardSort,
+    ToggleSortHand,
+    MessageSpectator(uuid::Uuid, String),
+    Rematch,
+    CloseTable,
 
     // Lounge menu options
     ViewStats,
     OpenSettings,
     OpenTutorial,
 
+    // Tutorial navigation
+    TutorialNext,
+    TutorialPrev,
+    CloseTutorial,
+
     // Window events
     WindowResized(iced::Size),
 
     // Dealer selection messages
+    ElectToStart, // vote to start; once everyone has, the table picks a dealer
     DealerSelected(String), // player name
     DealerSplashFinished,
 
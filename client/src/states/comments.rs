@@ -13,6 +13,7 @@ impl App {
                 if !self.comment_input.trim().is_empty() {
                     self.send_message(cctmog_protocol::ClientToServer::PostComment {
                         message: self.comment_input.clone(),
+                        about: None,
                     });
                     self.comment_input.clear();
                 }
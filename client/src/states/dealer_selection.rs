@@ -5,7 +5,7 @@ use crate::App;
 
 impl App {
     pub fn dealer_selection_view(&self) -> Element<'_, Msg> {
-        let (players, game_debug_text) = if let Some(ref snapshot) = self.snapshot {
+        let (players, status, game_debug_text) = if let Some(ref snapshot) = self.snapshot {
             let debug_text = format!(
                 "GAME STATE (Room struct)\n\
                 ══════════════════════════════\n\
@@ -28,8 +28,8 @@ impl App {
                 Limits: ${} / ${}\n\
                 Community Cards: {}\n\
                 ──────────────────────────────\n\
-                Elected Players: {}\n\
-                Dealer ID: {:?}\n\
+                Elected: {}/{}\n\
+                Dealer: {}\n\
                 ══════════════════════════════",
                 snapshot.room,
                 snapshot.phase,
@@ -51,10 +51,17 @@ impl App {
                 snapshot.limit_small,
                 snapshot.limit_big,
                 snapshot.community_cards.len(),
-                snapshot.elected_players.len(),
-                snapshot.current_dealer_id,
+                snapshot.elected_count,
+                snapshot.players.len(),
+                snapshot.current_dealer_name.as_deref().unwrap_or("none yet"),
             );
-            (&snapshot.players, debug_text)
+            let status = format!(
+                "{}/{} elected · Dealer: {}",
+                snapshot.elected_count,
+                snapshot.players.len(),
+                snapshot.current_dealer_name.as_deref().unwrap_or("none yet"),
+            );
+            (&snapshot.players, status, debug_text)
         } else {
             // Fallback for when no game snapshot is available
             return container(
@@ -97,6 +104,17 @@ impl App {
             )
             .center_x(Length::Fill),
 
+            Space::with_height(Length::Fixed(8.0)),
+
+            container(
+                text(status)
+                    .size(14)
+                    .style(|_theme: &iced::Theme| iced_widget::text::Style {
+                        color: Some(iced::Color::from_rgb(0.6, 0.6, 0.6)),
+                    })
+            )
+            .center_x(Length::Fill),
+
             Space::with_height(Length::Fixed(40.0)),
 
             // Player buttons
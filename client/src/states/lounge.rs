@@ -3,18 +3,25 @@ use iced_widget::{button, column, container, row, text, Space, text_input, scrol
 use futures::SinkExt;
 use crate::messages::Msg;
 use crate::App;
+use crate::app::ChatEntry;
 
 impl App {
+    /// Connect to the central server's lounge. The websocket subscription
+    /// sends `JoinLounge { name }` as soon as it connects, and the first
+    /// `LoungeUpdate` moves us into `AppState::Lounge`.
+    pub fn enter_lounge(&mut self, status: &str) {
+        self.url = "ws://127.0.0.1:9001/ws".to_string();
+        self.room = "lounge".to_string();
+        self.in_lounge = true;
+        self.app_state = crate::states::AppState::ConnectOverlay;
+        self.connecting = true;
+        self.log(status.to_string());
+    }
+
     pub fn handle_lounge_msg(&mut self, msg: &Msg) -> Task<Msg> {
         match msg {
             Msg::JoinLounge => {
-                // Connect to the lounge
-                self.url = "ws://127.0.0.1:9001/ws".to_string();
-                self.room = "lounge".to_string();
-                self.in_lounge = true;
-                self.app_state = crate::states::AppState::ConnectOverlay;
-                self.connecting = true;
-                self.log("🚪 Joining lounge...".to_string());
+                self.enter_lounge("🚪 Joining lounge...");
                 Task::none()
             }
             Msg::LeaveLounge => {
@@ -51,12 +58,7 @@ impl App {
             }
             Msg::HostGame => {
                 // Legacy host game - redirect to lounge
-                self.url = "ws://127.0.0.1:9001/ws".to_string();
-                self.room = "lounge".to_string();
-                self.in_lounge = true;
-                self.app_state = crate::states::AppState::ConnectOverlay;
-                self.connecting = true;
-                self.log("🚪 Redirecting to lounge to host...".to_string());
+                self.enter_lounge("🚪 Redirecting to lounge to host...");
                 Task::none()
             }
             Msg::BrowseTables => {
@@ -65,12 +67,7 @@ impl App {
             }
             Msg::JoinTable => {
                 // Legacy join table - redirect to lounge
-                self.url = "ws://127.0.0.1:9001/ws".to_string();
-                self.room = "lounge".to_string();
-                self.in_lounge = true;
-                self.app_state = crate::states::AppState::ConnectOverlay;
-                self.connecting = true;
-                self.log("🚪 Redirecting to lounge to join...".to_string());
+                self.enter_lounge("🚪 Redirecting to lounge to join...");
                 Task::none()
             }
             Msg::BackToHome => {
@@ -333,6 +330,21 @@ impl App {
             )
             .center_x(Length::Fill),
 
+            // Why we're back here, if a table sent us
+            match &self.removed_reason {
+                Some(reason) => Element::from(
+                    container(
+                        text(reason.as_str())
+                            .size(14)
+                            .style(|_theme: &iced::Theme| iced_widget::text::Style {
+                                color: Some(iced::Color::from_rgb(1.0, 0.65, 0.2)),
+                            })
+                    )
+                    .center_x(Length::Fill)
+                ),
+                None => Element::from(Space::with_height(0.0)),
+            },
+
             Space::with_height(Length::Fixed(40.0)),
 
             // Players section
@@ -428,6 +440,98 @@ impl App {
                 ]
             )
             .center_x(Length::Fill),
+
+            Space::with_height(Length::Fixed(24.0)),
+
+            // Hosts volunteered in the lounge
+            container(
+                column![
+                    text("AVAILABLE HOSTS")
+                        .size(16)
+                        .style(|_theme: &iced::Theme| iced_widget::text::Style {
+                            color: Some(iced::Color::from_rgb(0.84, 0.95, 0.95)), // #d5f2f3
+                        }),
+                    Space::with_height(Length::Fixed(8.0)),
+                    column(
+                        self.hosts_summary()
+                            .into_iter()
+                            .map(|line| {
+                                text(line)
+                                    .size(14)
+                                    .style(|_theme: &iced::Theme| iced_widget::text::Style {
+                                        color: Some(iced::Color::from_rgb(0.8, 0.8, 0.8)),
+                                    })
+                                    .into()
+                            })
+                            .collect::<Vec<_>>()
+                    )
+                    .spacing(4),
+                ]
+                .align_x(Alignment::Center)
+            )
+            .center_x(Length::Fill),
+
+            Space::with_height(Length::Fixed(24.0)),
+
+            // Lounge and global chat from before we arrived
+            container(
+                column![
+                    row![
+                        text("RECENT ACTIVITY")
+                            .size(16)
+                            .style(|_theme: &iced::Theme| iced_widget::text::Style {
+                                color: Some(iced::Color::from_rgb(0.84, 0.95, 0.95)), // #d5f2f3
+                            }),
+                        button(text("↻").size(14))
+                            .on_press(Msg::FetchChatHistory)
+                            .padding([2, 8]),
+                    ]
+                    .spacing(8)
+                    .align_y(Alignment::Center),
+                    Space::with_height(Length::Fixed(8.0)),
+                    scrollable(
+                        column(
+                            self.recent_activity_lines()
+                                .into_iter()
+                                .map(|line| {
+                                    text(line)
+                                        .size(12)
+                                        .style(|_theme: &iced::Theme| iced_widget::text::Style {
+                                            color: Some(iced::Color::from_rgb(0.8, 0.8, 0.8)),
+                                        })
+                                        .into()
+                                })
+                                .collect::<Vec<_>>()
+                        )
+                        .spacing(4)
+                    )
+                    .height(Length::Fixed(140.0)),
+                ]
+                .align_x(Alignment::Center)
+                .width(Length::Fixed(360.0))
+            )
+            .center_x(Length::Fill),
+
+            Space::with_height(Length::Fixed(40.0)),
+
+            // Tutorial button
+            button(text("📖 How to Play 7/27").size(16))
+                .on_press(Msg::OpenTutorial)
+                .padding(12)
+                .width(Length::Fixed(240.0))
+                .style(|_theme: &iced::Theme, status| iced_widget::button::Style {
+                    background: Some(iced::Background::Color(match status {
+                        iced_widget::button::Status::Hovered => iced::Color::from_rgb(0.4, 0.5, 0.7),
+                        _ => iced::Color::from_rgb(0.3, 0.4, 0.6),
+                    })),
+                    text_color: iced::Color::WHITE,
+                    border: iced::Border {
+                        color: iced::Color::from_rgb(0.5, 0.6, 0.8),
+                        width: 2.0,
+                        radius: iced::border::Radius::from(8.0),
+                    },
+                    ..Default::default()
+                }),
         ]
         .align_x(Alignment::Center)
         .spacing(8)
@@ -458,6 +562,25 @@ impl App {
             .into()
     }
 
+    /// Fetched lounge and global chat for the recent activity panel.
+    pub fn recent_activity_lines(&self) -> Vec<String> {
+        if self.recent_activity.is_empty() {
+            return vec!["Nothing said yet".to_string()];
+        }
+        self.recent_activity.iter().map(ChatEntry::line).collect()
+    }
+
+    /// One line per host from the last `LoungeUpdate`.
+    pub fn hosts_summary(&self) -> Vec<String> {
+        if self.available_hosts.is_empty() {
+            return vec!["Nobody is hosting yet — click your name to host".to_string()];
+        }
+        self.available_hosts
+            .iter()
+            .map(|(name, port)| format!("🎯 {} on port {}", name, port))
+            .collect()
+    }
+
     pub fn lounge_chat_view(&self) -> Element<'_, Msg> {
         let total_messages = self.chat_messages.len();
 
@@ -465,7 +588,7 @@ impl App {
         let mut elements: Vec<Element<'_, Msg>> = vec![];
         let mut last_date: Option<String> = None;
 
-        for (idx, (player_name, message, _scope, timestamp)) in self.chat_messages.iter().enumerate() {
+        for (idx, ChatEntry { player_name, message, timestamp, .. }) in self.chat_messages.iter().enumerate() {
             // Parse timestamp to get date (format: "2025-09-30T23:38:56.256330+00:00")
             let current_date = timestamp.split('T').next().unwrap_or("");
 
@@ -673,4 +796,61 @@ impl App {
         })
         .into()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::AppState;
+    use cctmog_protocol::ServerToClient;
+
+    #[test]
+    fn test_confirm_name_joins_lounge() {
+        let mut app = App { name: "alice".to_string(), ..App::default() };
+        app.app_state = AppState::NameInput;
+
+        let _ = app.update(Msg::ConfirmName);
+        assert_eq!(app.app_state, AppState::ConnectOverlay);
+        assert!(app.connecting && app.in_lounge);
+        // The websocket subscription sends JoinLounge for this room
+        assert_eq!(app.room, "lounge");
+
+        let _ = app.update(Msg::WsEvent(ServerToClient::LoungeUpdate {
+            players: vec!["alice".to_string(), "bob".to_string()],
+            available_hosts: vec![("bob".to_string(), 9002)],
+            player_selections: vec![],
+        }));
+        assert_eq!(app.app_state, AppState::Lounge);
+        assert!(app.connected && !app.connecting);
+        assert_eq!(app.lounge_players, vec!["alice", "bob"]);
+        assert_eq!(app.hosts_summary(), vec!["🎯 bob on port 9002"]);
+    }
+
+    #[test]
+    fn test_fetched_history_fills_recent_activity() {
+        let mut app = App { app_state: AppState::Lounge, in_lounge: true, ..App::default() };
+        assert_eq!(app.recent_activity_lines(), vec!["Nothing said yet"]);
+
+        let stored = |message: &str, scope, timestamp: &str| cctmog_protocol::StoredMessage {
+            player_name: "bob".to_string(),
+            message: message.to_string(),
+            scope,
+            room: None,
+            timestamp: timestamp.to_string(),
+            recipient: None,
+        };
+        let _ = app.update(Msg::WsEvent(ServerToClient::ChatHistory {
+            messages: vec![
+                stored("anyone up for a game?", cctmog_protocol::MessageScope::Group, "2025-09-30T21:00:00+00:00"),
+                stored("server restart at noon", cctmog_protocol::MessageScope::Global, "2025-09-30T21:02:00+00:00"),
+            ],
+        }));
+
+        let lines = app.recent_activity_lines();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].ends_with("[Group] bob: anyone up for a game?"));
+        assert!(lines[1].ends_with("[Global] bob: server restart at noon"));
+        // History lives in its own panel, not the live chat
+        assert!(app.chat_messages.is_empty());
+    }
+}
@@ -10,6 +10,7 @@ pub mod comments;
 pub mod dealer_selection;
 pub mod dealer_splash;
 pub mod game_selection;
+pub mod tutorial;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum AppState {
@@ -25,4 +26,48 @@ pub enum AppState {
     DealerSelection,
     DealerSplash,
     GameSelection,
+    Tutorial,
+}
+
+/// The screen a table in `phase` belongs on, for re-deriving where we are
+/// after a reconnect instead of assuming the game screen.
+pub fn app_state_for_phase(phase: &cctmog_protocol::Phase) -> AppState {
+    use cctmog_protocol::Phase;
+    match phase {
+        Phase::DealerSelection => AppState::DealerSelection,
+        Phase::GameSelection => AppState::GameSelection,
+        Phase::Comments => AppState::Comments,
+        Phase::Lobby | Phase::WaitingForDealer | Phase::Dealing | Phase::Acting | Phase::Showdown => AppState::Game,
+    }
+}
+
+impl AppState {
+    /// Screens shown while at a table, which follow the table's phase.
+    pub fn is_table_screen(&self) -> bool {
+        matches!(
+            self,
+            AppState::Game
+                | AppState::Comments
+                | AppState::DealerSelection
+                | AppState::DealerSplash
+                | AppState::GameSelection
+        )
+    }
+
+    /// Screens that talk to the server, so the socket stays open while
+    /// they're up. Dealer and game selection send `DelegateDealer` and
+    /// `ChooseGameVariant` mid-setup.
+    pub fn uses_socket(&self) -> bool {
+        matches!(
+            self,
+            AppState::ConnectOverlay
+                | AppState::Lounge
+                | AppState::Game
+                | AppState::Comments
+                | AppState::Tutorial
+                | AppState::DealerSelection
+                | AppState::DealerSplash
+                | AppState::GameSelection
+        )
+    }
 }
\ No newline at end of file
@@ -58,6 +58,16 @@ impl App {
                     limit_small,
                     limit_big,
                     max_raises,
+                    min_players: self.table_min_players.parse().ok(),
+                    rake_percent: self.table_rake_percent.parse().unwrap_or(0),
+                    rake_cap: self.table_rake_cap.trim().parse().ok(),
+                    max_buyin: None,
+                    deck_count: None,
+                    spectator_chat_to_players: None,
+                    muck_window_secs: self.table_muck_window.trim().parse().ok(),
+                    max_draw_rounds: None,
+                    auto_dealer: self.table_auto_dealer,
+                    rabbit_hunt: self.table_rabbit_hunt,
                 };
 
                 self.pending_table_creation = Some(create_msg);
@@ -0,0 +1,254 @@
+use iced::{Element, Task, Length, Alignment};
+use iced_widget::{button, column, container, row, text, Space};
+use cctmog_protocol::{score_hand, Card, Rank, Suit};
+use crate::messages::Msg;
+use crate::ui::cards::{card_svg, CardSize};
+use crate::App;
+
+/// One page of the 7/27 walkthrough: a short explanation plus an example hand
+/// that is scored live with the same `score_hand` the server uses.
+struct TutorialStep {
+    title: &'static str,
+    body: &'static str,
+    hand: Vec<Card>,
+}
+
+fn card(rank: Rank, suit: Suit) -> Card {
+    Card { rank, suit, face_up: true }
+}
+
+fn tutorial_steps() -> Vec<TutorialStep> {
+    use Rank::*;
+    use Suit::*;
+    vec![
+        TutorialStep {
+            title: "Welcome to 7/27",
+            body: "Every player tries to finish as close as possible to 7 or to 27 without going over. \
+                   The pot is split: half goes to the best hand near 7, half to the best hand near 27.",
+            hand: vec![card(Three, Spades), card(Four, Hearts)],
+        },
+        TutorialStep {
+            title: "Number cards",
+            body: "Cards from 2 to 10 are worth their face value. This hand is worth 24 — \
+                   too much for the low side, but a solid high hand.",
+            hand: vec![card(Five, Diamonds), card(Nine, Clubs), card(Ten, Hearts)],
+        },
+        TutorialStep {
+            title: "Face cards count half a point",
+            body: "Jacks, Queens and Kings are each worth 0.5. They let you nudge a total \
+                   without jumping past your target.",
+            hand: vec![card(King, Spades), card(Queen, Hearts), card(Six, Diamonds)],
+        },
+        TutorialStep {
+            title: "Aces are 1 or 11",
+            body: "An Ace counts as 1 or 11, whichever helps. The same hand can be played \
+                   as 7 on the low side and 17 on the high side.",
+            hand: vec![card(Ace, Spades), card(Six, Hearts)],
+        },
+        TutorialStep {
+            title: "Two ways to win",
+            body: "With two Aces and a Five, count both Aces as 1 for a perfect 7, \
+                   or both as 11 for a perfect 27. Hands like this can scoop the whole pot.",
+            hand: vec![card(Ace, Spades), card(Ace, Hearts), card(Five, Clubs)],
+        },
+        TutorialStep {
+            title: "Going bust",
+            body: "Go over 27 and you are bust on the high side. Think twice before \
+                   taking another card when your total is already high.",
+            hand: vec![card(Ten, Spades), card(Nine, Hearts), card(Eight, Diamonds), card(King, Clubs)],
+        },
+        TutorialStep {
+            title: "Playing a hand",
+            body: "You start with one card down and one card up. On your turn, take a card or stand. \
+                   After each draw round there is a limit betting round: check, bet, call, raise or fold. \
+                   Once everyone stands, hands are revealed and the pot is split.",
+            hand: vec![card(Two, Clubs), card(Four, Diamonds), card(Jack, Spades)],
+        },
+    ]
+}
+
+impl App {
+    pub fn handle_tutorial_msg(&mut self, msg: &Msg) -> Task<Msg> {
+        let step_count = tutorial_steps().len();
+        match msg {
+            Msg::TutorialNext => {
+                if self.tutorial_step + 1 < step_count {
+                    self.tutorial_step += 1;
+                }
+                Task::none()
+            }
+            Msg::TutorialPrev => {
+                self.tutorial_step = self.tutorial_step.saturating_sub(1);
+                Task::none()
+            }
+            Msg::CloseTutorial => {
+                self.tutorial_step = 0;
+                self.app_state = crate::states::AppState::Lounge;
+                Task::none()
+            }
+            _ => Task::none(),
+        }
+    }
+
+    pub fn tutorial_view(&self) -> Element<'_, Msg> {
+        let steps = tutorial_steps();
+        let index = self.tutorial_step.min(steps.len() - 1);
+        let step = &steps[index];
+        let is_first = index == 0;
+        let is_last = index + 1 == steps.len();
+
+        let score = score_hand(&step.hand);
+
+        let mut hand_row = row![].spacing(10).align_y(Alignment::Center);
+        for c in &step.hand {
+            hand_row = hand_row.push(card_svg(c, CardSize::Large));
+        }
+
+        let low_text = match score.best_under_7 {
+            Some(v) => format!("Best under 7: {:.1}", v),
+            None => "Best under 7: none (over 7)".to_string(),
+        };
+        let high_text = match score.best_under_27 {
+            Some(v) => format!("Best under 27: {:.1}", v),
+            None => "Best under 27: BUST".to_string(),
+        };
+
+        let score_panel = container(
+            column![
+                text("Scored with score_hand")
+                    .size(12)
+                    .style(|_theme: &iced::Theme| iced_widget::text::Style {
+                        color: Some(iced::Color::from_rgb(0.6, 0.6, 0.6)),
+                    }),
+                text(low_text)
+                    .size(18)
+                    .style(|_theme: &iced::Theme| iced_widget::text::Style {
+                        color: Some(iced::Color::from_rgb(0.6, 0.9, 0.6)),
+                    }),
+                text(high_text)
+                    .size(18)
+                    .style(move |_theme: &iced::Theme| iced_widget::text::Style {
+                        color: Some(if score.bust_27 {
+                            iced::Color::from_rgb(0.9, 0.3, 0.3)
+                        } else {
+                            iced::Color::from_rgb(0.6, 0.8, 1.0)
+                        }),
+                    }),
+            ]
+            .spacing(6)
+            .align_x(Alignment::Center)
+        )
+        .padding(16)
+        .width(Length::Fixed(360.0))
+        .style(|_theme: &iced::Theme| iced_widget::container::Style {
+            background: Some(iced::Background::Color(iced::Color::from_rgba(0.1, 0.3, 0.1, 0.8))),
+            border: iced::Border {
+                color: iced::Color::from_rgb(0.3, 0.6, 0.3),
+                width: 1.0,
+                radius: iced::border::Radius::from(8.0),
+            },
+            ..Default::default()
+        });
+
+        let nav_button_style = |_theme: &iced::Theme, status: iced_widget::button::Status| iced_widget::button::Style {
+            background: Some(iced::Background::Color(match status {
+                iced_widget::button::Status::Hovered => iced::Color::from_rgb(0.46, 0.72, 1.0),
+                iced_widget::button::Status::Disabled => iced::Color::from_rgb(0.3, 0.3, 0.3),
+                _ => iced::Color::from_rgb(0.36, 0.62, 0.98),
+            })),
+            text_color: iced::Color::WHITE,
+            border: iced::Border {
+                color: iced::Color::from_rgb(0.30, 0.56, 0.92),
+                width: 1.0,
+                radius: iced::border::Radius::from(8.0),
+            },
+            ..Default::default()
+        };
+
+        let navigation = row![
+            button(text("← Previous").size(16))
+                .on_press_maybe((!is_first).then_some(Msg::TutorialPrev))
+                .padding(12)
+                .width(Length::Fixed(150.0))
+                .style(nav_button_style),
+            Space::with_width(Length::Fixed(20.0)),
+            text(format!("Step {} of {}", index + 1, steps.len()))
+                .size(14)
+                .style(|_theme: &iced::Theme| iced_widget::text::Style {
+                    color: Some(iced::Color::from_rgb(0.7, 0.7, 0.7)),
+                }),
+            Space::with_width(Length::Fixed(20.0)),
+            if is_last {
+                button(text("Done").size(16))
+                    .on_press(Msg::CloseTutorial)
+                    .padding(12)
+                    .width(Length::Fixed(150.0))
+                    .style(nav_button_style)
+            } else {
+                button(text("Next →").size(16))
+                    .on_press(Msg::TutorialNext)
+                    .padding(12)
+                    .width(Length::Fixed(150.0))
+                    .style(nav_button_style)
+            },
+        ]
+        .align_y(Alignment::Center);
+
+        let content = column![
+            Space::with_height(Length::Fixed(40.0)),
+            text("How to Play 7/27")
+                .size(32)
+                .style(|_theme: &iced::Theme| iced_widget::text::Style {
+                    color: Some(iced::Color::from_rgb(0.92, 0.92, 0.94)),
+                }),
+            Space::with_height(Length::Fixed(20.0)),
+            text(step.title)
+                .size(24)
+                .style(|_theme: &iced::Theme| iced_widget::text::Style {
+                    color: Some(iced::Color::from_rgb(0.98, 0.86, 0.22)),
+                }),
+            container(
+                text(step.body)
+                    .size(16)
+                    .style(|_theme: &iced::Theme| iced_widget::text::Style {
+                        color: Some(iced::Color::from_rgb(0.85, 0.85, 0.85)),
+                    })
+            )
+            .width(Length::Fixed(560.0)),
+            Space::with_height(Length::Fixed(20.0)),
+            hand_row,
+            Space::with_height(Length::Fixed(20.0)),
+            score_panel,
+            Space::with_height(Length::Fixed(30.0)),
+            navigation,
+            Space::with_height(Length::Fixed(20.0)),
+            button(text("Back to Lounge"))
+                .on_press(Msg::CloseTutorial)
+                .padding(10)
+                .width(Length::Fixed(150.0))
+                .style(|_theme: &iced::Theme, _status| iced_widget::button::Style {
+                    background: Some(iced::Background::Color(iced::Color::from_rgb(0.5, 0.5, 0.5))),
+                    text_color: iced::Color::WHITE,
+                    border: iced::Border {
+                        color: iced::Color::from_rgb(0.4, 0.4, 0.4),
+                        width: 1.0,
+                        radius: iced::border::Radius::from(8.0),
+                    },
+                    ..Default::default()
+                }),
+        ]
+        .align_x(Alignment::Center)
+        .spacing(10);
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x(Length::Fill)
+            .center_y(Length::Fill)
+            .style(|_theme: &iced::Theme| iced_widget::container::Style {
+                background: Some(iced::Background::Color(iced::Color::from_rgb(0.10, 0.10, 0.11))),
+                ..Default::default()
+            })
+            .into()
+    }
+}
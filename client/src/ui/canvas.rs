@@ -8,9 +8,10 @@ use cctmog_protocol::Card;
 #[derive(Debug, Clone)]
 pub struct PokerTableCanvas {
     pub pot: u64,
-    pub seats: usize,
-    pub to_act_seat: Option<usize>, // None in lobby
+    pub seats: usize, // slots around the ring, as laid out by the seat panels
+    pub to_act_seat: Option<usize>, // ring slot of the player to act; None in lobby
     pub community_cards: Vec<Card>, // Community cards for display
+    pub board_slots: usize, // full board size; undealt cards draw as empty outlines
 }
 
 impl<Message> canvas::Program<Message> for PokerTableCanvas {
@@ -40,25 +41,55 @@ impl<Message> canvas::Program<Message> for PokerTableCanvas {
         let lip = CanvasPath::circle(iced::Point::new(cx, cy), r + 7.0);
         frame.stroke(&lip, Stroke { width: 2.0, ..Default::default() });
 
-        // simple chip stack
+        // seat markers on the rail, the player to act glowing
+        for slot in 0..self.seats {
+            let angle = slot_angle(slot, self.seats);
+            let at = iced::Point::new(cx + (r + 3.0) * angle.cos(), cy + (r + 3.0) * angle.sin());
+            if self.to_act_seat == Some(slot) {
+                frame.fill(&CanvasPath::circle(at, 14.0), iced::Color::from_rgba(1.0, 0.8, 0.0, 0.35));
+                frame.fill(&CanvasPath::circle(at, 7.0), iced::Color::from_rgb(1.0, 0.8, 0.0));
+            } else {
+                frame.fill(&CanvasPath::circle(at, 4.0), iced::Color::from_rgba(1.0, 1.0, 1.0, 0.35));
+            }
+        }
+
+        // pot chips: stacks side by side, growing with the pot
         let chip_r = r * 0.085;
-        let gold   = iced::Color { r: 0.980, g: 0.860, b: 0.220, a: 1.0 };
-        let stack  = 3usize.min(((self.pot / 20).max(1)) as usize);
-        for i in 0..stack {
-            let p = iced::Point::new(cx + (i as f32) * (chip_r * 0.25), cy - (i as f32) * (chip_r * 0.15));
-            let c = CanvasPath::circle(p, chip_r);
-            frame.fill(&c, gold);
-            frame.stroke(&c, Stroke { width: 1.0, ..Default::default() });
+        let stacks = pot_chip_stacks(self.pot);
+        let stack_gap = chip_r * 2.2;
+        let first_x = cx - stack_gap * (stacks.len().saturating_sub(1)) as f32 / 2.0;
+        for (s, &height) in stacks.iter().enumerate() {
+            let color = STACK_COLORS[s % STACK_COLORS.len()];
+            for i in 0..height {
+                let p = iced::Point::new(first_x + s as f32 * stack_gap, cy - (i as f32) * (chip_r * 0.22));
+                let c = CanvasPath::circle(p, chip_r);
+                frame.fill(&c, color);
+                frame.stroke(&c, Stroke { width: 1.0, ..Default::default() });
+            }
         }
 
         // Draw community cards if any
-        if !self.community_cards.is_empty() {
+        let slots = self.board_slots.max(self.community_cards.len());
+        if slots > 0 {
             let card_width = r * 0.2;
             let card_height = card_width * 1.4;
             let card_spacing = card_width * 0.1;
-            let total_width = (card_width * self.community_cards.len() as f32) + (card_spacing * (self.community_cards.len() - 1) as f32);
+            let total_width = (card_width * slots as f32) + (card_spacing * (slots - 1) as f32);
             let start_x = cx - (total_width / 2.0);
 
+            // Streets still to come
+            for i in self.community_cards.len()..slots {
+                let slot_path = CanvasPath::rectangle(
+                    iced::Point::new(start_x + (i as f32) * (card_width + card_spacing), cy - (card_height / 2.0)),
+                    iced::Size::new(card_width, card_height)
+                );
+                frame.stroke(&slot_path, Stroke {
+                    width: 1.0,
+                    style: canvas::Style::Solid(iced::Color { a: 0.35, ..iced::Color::WHITE }),
+                    ..Default::default()
+                });
+            }
+
             for (i, card) in self.community_cards.iter().enumerate() {
                 let card_x = start_x + (i as f32) * (card_width + card_spacing);
                 let card_y = cy - (card_height / 2.0);
@@ -114,16 +145,26 @@ impl<Message> canvas::Program<Message> for PokerTableCanvas {
         }
 
         // Draw pot text below community cards or centered if no cards
-        let pot_y = if self.community_cards.is_empty() {
+        let pot_y = if slots == 0 {
             cy + chip_r * 1.8
         } else {
             cy + r * 0.3
         };
 
+        let label = format!("Pot: ${}", self.pot);
+        let label_size = chip_r * 1.2;
+        let plate_w = label.chars().count() as f32 * label_size * 0.62 + label_size;
+        let plate = CanvasPath::rounded_rectangle(
+            iced::Point::new(cx - plate_w / 2.0, pot_y - label_size * 0.8),
+            iced::Size::new(plate_w, label_size * 1.6),
+            iced::border::Radius::from(label_size * 0.8),
+        );
+        frame.fill(&plate, iced::Color { r: 0.0, g: 0.0, b: 0.0, a: 0.55 });
         frame.fill_text(canvas::Text {
-            content: format!("Pot: ${}", self.pot),
+            content: label,
             position: iced::Point::new(cx, pot_y),
-            size: iced::Pixels(chip_r * 0.8),
+            size: iced::Pixels(label_size),
+            color: STACK_COLORS[0],
             horizontal_alignment: iced::alignment::Horizontal::Center,
             vertical_alignment: iced::alignment::Vertical::Center,
             ..Default::default()
@@ -133,17 +174,147 @@ impl<Message> canvas::Program<Message> for PokerTableCanvas {
     }
 }
 
+/// Angle of ring slot `slot` out of `slots`, in canvas coordinates (y down):
+/// slot 0 is bottom centre and the rest follow clockwise.
+pub fn slot_angle(slot: usize, slots: usize) -> f32 {
+    std::f32::consts::FRAC_PI_2 + slot as f32 * std::f32::consts::TAU / slots.max(1) as f32
+}
+
+const CHIPS_PER_STACK: usize = 8;
+const MAX_STACKS: usize = 4;
+// gold, red, blue, green
+const STACK_COLORS: [iced::Color; MAX_STACKS] = [
+    iced::Color { r: 0.980, g: 0.860, b: 0.220, a: 1.0 },
+    iced::Color { r: 0.850, g: 0.200, b: 0.200, a: 1.0 },
+    iced::Color { r: 0.200, g: 0.450, b: 0.900, a: 1.0 },
+    iced::Color { r: 0.150, g: 0.700, b: 0.350, a: 1.0 },
+];
+
+/// Chip heights of the pot's stacks, left to right. The chip count grows
+/// with log2 of the pot so small pots still show movement and huge ones
+/// top out at `MAX_STACKS` full stacks instead of leaving the felt.
+pub fn pot_chip_stacks(pot: u64) -> Vec<usize> {
+    if pot == 0 {
+        return vec![];
+    }
+    let total = ((pot as f64).log2() * 1.5).round().clamp(1.0, (CHIPS_PER_STACK * MAX_STACKS) as f64) as usize;
+    let n = total.div_ceil(CHIPS_PER_STACK);
+    (0..n).map(|i| total / n + usize::from(i < total % n)).collect()
+}
+
 pub fn felt(pot: u64, seats: usize, to_act_seat: Option<usize>) -> Element<'static, Msg> {
-    Canvas::new(PokerTableCanvas { pot, seats, to_act_seat, community_cards: vec![] })
+    Canvas::new(PokerTableCanvas { pot, seats, to_act_seat, community_cards: vec![], board_slots: 0 })
         .width(iced::Length::Fill)
         .height(iced::Length::Fixed(380.0))
         .into()
 }
 
 /// Enhanced felt with community cards support
-pub fn felt_with_community(pot: u64, seats: usize, to_act_seat: Option<usize>, community_cards: Vec<Card>) -> Element<'static, Msg> {
-    Canvas::new(PokerTableCanvas { pot, seats, to_act_seat, community_cards })
+pub fn felt_with_community(pot: u64, seats: usize, to_act_seat: Option<usize>, community_cards: Vec<Card>, board_slots: usize) -> Element<'static, Msg> {
+    Canvas::new(PokerTableCanvas { pot, seats, to_act_seat, community_cards, board_slots })
         .width(iced::Length::Fill)
         .height(iced::Length::Fixed(380.0))
         .into()
-}
\ No newline at end of file
+}
+
+/// Line chart of the local player's stack after each showdown.
+#[derive(Debug, Clone)]
+pub struct ChipSparkline {
+    pub history: Vec<u64>,
+}
+
+impl ChipSparkline {
+    /// Points scaled into a `width` x `height` box, oldest on the left and
+    /// the session's high at the top. A flat history sits mid-height.
+    pub fn points(&self, width: f32, height: f32) -> Vec<iced::Point> {
+        let lo = self.history.iter().copied().min().unwrap_or(0);
+        let hi = self.history.iter().copied().max().unwrap_or(0);
+        let step = width / (self.history.len().max(2) - 1) as f32;
+        self.history
+            .iter()
+            .enumerate()
+            .map(|(i, &chips)| {
+                let y = if hi == lo {
+                    height * 0.5
+                } else {
+                    height * (1.0 - (chips - lo) as f32 / (hi - lo) as f32)
+                };
+                iced::Point::new(i as f32 * step, y)
+            })
+            .collect()
+    }
+}
+
+impl<Message> canvas::Program<Message> for ChipSparkline {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &iced::Renderer,
+        _theme: &iced::Theme,
+        bounds: iced::Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let pad = 4.0;
+        let pts: Vec<iced::Point> = self
+            .points(bounds.width - pad * 2.0, bounds.height - pad * 2.0)
+            .into_iter()
+            .map(|p| iced::Point::new(p.x + pad, p.y + pad))
+            .collect();
+
+        // Up on the session: green, down: red
+        let color = match (self.history.first(), self.history.last()) {
+            (Some(first), Some(last)) if last < first => iced::Color::from_rgb(1.0, 0.35, 0.3),
+            _ => iced::Color::from_rgb(0.3, 0.85, 0.45),
+        };
+
+        if pts.len() > 1 {
+            let line = CanvasPath::new(|b| {
+                b.move_to(pts[0]);
+                for p in &pts[1..] {
+                    b.line_to(*p);
+                }
+            });
+            frame.stroke(&line, Stroke::default().with_color(color).with_width(2.0));
+        }
+        if let Some(last) = pts.last() {
+            frame.fill(&CanvasPath::circle(*last, 3.0), color);
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+pub fn chip_sparkline(history: &[u64]) -> Element<'static, Msg> {
+    Canvas::new(ChipSparkline { history: history.to_vec() })
+        .width(iced::Length::Fill)
+        .height(iced::Length::Fixed(48.0))
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pot_chip_stacks_scale_with_pot() {
+        // Sample pots from empty to absurd
+        assert_eq!(pot_chip_stacks(0), Vec::<usize>::new());
+        assert_eq!(pot_chip_stacks(1), vec![1]);
+        assert_eq!(pot_chip_stacks(10), vec![5]);
+        assert_eq!(pot_chip_stacks(100), vec![5, 5]);
+        assert_eq!(pot_chip_stacks(1_000), vec![8, 7]);
+        assert_eq!(pot_chip_stacks(100_000), vec![7, 6, 6, 6]);
+        assert_eq!(pot_chip_stacks(u64::MAX), vec![8; 4]);
+
+        // Bigger pot never shows fewer chips
+        let mut last = 0;
+        for pot in [5, 20, 80, 400, 2_000, 50_000, 1_000_000] {
+            let chips: usize = pot_chip_stacks(pot).iter().sum();
+            assert!(chips >= last, "pot {} shows {} chips, fewer than {}", pot, chips, last);
+            last = chips;
+        }
+    }
+}
@@ -1,5 +1,5 @@
-use iced::{Alignment, Element, Length};
-use iced_widget::{column, container, row, text, Space};
+use iced::{Alignment, Element, Length, Padding};
+use iced_widget::{column, container, row, stack, text, Space};
 
 use uuid::Uuid;
 use cctmog_protocol::{PublicPlayer, PublicRoom};
@@ -7,16 +7,30 @@ use cctmog_protocol::{PublicPlayer, PublicRoom};
 use crate::messages::Msg;
 use crate::ui::cards::{face_down_cards_row, cards_row_svg, CardSize};
 
-fn player_avatar(name: &str, is_to_act: bool) -> Element<'static, Msg> {
-    let avatar_color = match name.chars().next().unwrap_or('A') {
-        'J' => iced::Color::from_rgb(0.2, 0.8, 0.4), // Green for Joe
-        'F' => iced::Color::from_rgb(0.8, 0.3, 0.2), // Red for Frank
-        'L' => iced::Color::from_rgb(0.2, 0.8, 0.8), // Cyan for Lor
-        'M' => iced::Color::from_rgb(0.9, 0.7, 0.2), // Yellow for Mass
-        'H' => iced::Color::from_rgb(0.8, 0.2, 0.8), // Purple for Huge
-        _ => iced::Color::from_rgb(0.6, 0.6, 0.6), // Default gray
+/// Seat accent colors, indexed by a player's `color_seed`
+const SEAT_COLORS: [iced::Color; 8] = [
+    iced::Color::from_rgb(0.2, 0.8, 0.4),  // green
+    iced::Color::from_rgb(0.8, 0.3, 0.2),  // red
+    iced::Color::from_rgb(0.2, 0.8, 0.8),  // cyan
+    iced::Color::from_rgb(0.9, 0.7, 0.2),  // yellow
+    iced::Color::from_rgb(0.8, 0.2, 0.8),  // purple
+    iced::Color::from_rgb(0.3, 0.5, 0.95), // blue
+    iced::Color::from_rgb(0.95, 0.5, 0.2), // orange
+    iced::Color::from_rgb(0.9, 0.45, 0.6), // pink
+];
+
+/// Stable accent color for a player. Servers that don't send a seed yet
+/// leave it at 0, so fall back to deriving one from the id.
+pub fn seat_accent(p: &PublicPlayer) -> iced::Color {
+    let seed = if p.color_seed == 0 {
+        PublicPlayer::color_seed_for(p.id)
+    } else {
+        p.color_seed
     };
+    SEAT_COLORS[seed as usize % SEAT_COLORS.len()]
+}
 
+fn player_avatar(name: &str, avatar_color: iced::Color, is_to_act: bool) -> Element<'static, Msg> {
     let border_color = if is_to_act {
         iced::Color::from_rgb(1.0, 0.8, 0.0) // Gold border for active player
     } else {
@@ -96,14 +110,28 @@ fn chip_stack(chips: u64) -> Element<'static, Msg> {
     .into()
 }
 
-fn seat_panel(p: &PublicPlayer, is_you: bool, is_to_act: bool) -> Element<'static, Msg> {
+/// Turns longer than this are highlighted as slow.
+const SLOW_TURN_SECS: i64 = 30;
+
+/// Seconds since the current turn began, from the server's `turn_started_at`.
+fn turn_elapsed_secs(s: &PublicRoom) -> Option<i64> {
+    let started = chrono::DateTime::parse_from_rfc3339(s.turn_started_at.as_deref()?).ok()?;
+    Some((chrono::Utc::now() - started.with_timezone(&chrono::Utc)).num_seconds().max(0))
+}
+
+fn seat_panel(p: &PublicPlayer, is_you: bool, is_to_act: bool, turn_secs: Option<i64>) -> Element<'static, Msg> {
+    let is_slow = turn_secs.is_some_and(|secs| secs >= SLOW_TURN_SECS);
+    let accent = seat_accent(p);
+
     let player_name = if is_you {
         format!("{} (You)", p.name)
     } else {
         p.name.clone()
     };
 
-    let mut name_style = text(player_name).size(12);
+    let mut name_style = text(player_name)
+        .size(12)
+        .style(move |_theme| iced_widget::text::Style { color: Some(accent) });
     if is_to_act {
         name_style = text(format!("● {}", if is_you { format!("{} (You)", p.name) } else { p.name.clone() }))
             .size(12)
@@ -113,6 +141,72 @@ fn seat_panel(p: &PublicPlayer, is_you: bool, is_to_act: bool) -> Element<'stati
             });
     }
 
+    let ready_badge: Element<'static, Msg> = if p.ready {
+        container(
+            text("READY")
+                .size(9)
+                .style(|_theme| iced_widget::text::Style {
+                    color: Some(iced::Color::WHITE),
+                })
+        )
+        .padding([1, 4])
+        .style(move |_theme| iced_widget::container::Style {
+            background: Some(iced::Background::Color(iced::Color::from_rgb(0.2, 0.7, 0.3))),
+            border: iced::Border {
+                color: if is_you {
+                    iced::Color::from_rgb(1.0, 0.8, 0.0)
+                } else {
+                    iced::Color::from_rgb(0.1, 0.5, 0.2)
+                },
+                width: 1.0,
+                radius: iced::border::Radius::from(3.0),
+            },
+            ..Default::default()
+        })
+        .into()
+    } else {
+        Space::with_width(Length::Fixed(0.0)).into()
+    };
+
+    let standing_badge: Element<'static, Msg> = if p.standing && !p.folded {
+        container(
+            text("STANDING")
+                .size(9)
+                .style(|_theme| iced_widget::text::Style {
+                    color: Some(iced::Color::WHITE),
+                })
+        )
+        .padding([1, 4])
+        .style(|_theme| iced_widget::container::Style {
+            background: Some(iced::Background::Color(iced::Color::from_rgb(0.25, 0.4, 0.75))),
+            border: iced::Border {
+                color: iced::Color::from_rgb(0.15, 0.25, 0.55),
+                width: 1.0,
+                radius: iced::border::Radius::from(3.0),
+            },
+            ..Default::default()
+        })
+        .into()
+    } else {
+        Space::with_width(Length::Fixed(0.0)).into()
+    };
+
+    let turn_timer: Element<'static, Msg> = match turn_secs {
+        Some(secs) => text(format!("⏱ {}s", secs))
+            .size(10)
+            .style(move |_theme| iced_widget::text::Style {
+                color: Some(if is_slow {
+                    iced::Color::from_rgb(1.0, 0.35, 0.3)
+                } else if secs >= SLOW_TURN_SECS / 2 {
+                    iced::Color::from_rgb(1.0, 0.65, 0.2)
+                } else {
+                    iced::Color::from_rgb(0.8, 0.8, 0.8)
+                }),
+            })
+            .into(),
+        None => Space::with_width(Length::Fixed(0.0)).into(),
+    };
+
     let chip_count = text(format!("${}", p.chips))
         .size(11)
         .style(|_theme| iced_widget::text::Style {
@@ -168,10 +262,10 @@ fn seat_panel(p: &PublicPlayer, is_you: bool, is_to_act: bool) -> Element<'stati
     container(
         column![
             row![
-                player_avatar(&p.name, is_to_act),
+                player_avatar(&p.name, accent, is_to_act),
                 Space::with_width(Length::Fixed(8.0)),
                 column![
-                    name_style,
+                    row![name_style, ready_badge, standing_badge, turn_timer].spacing(4).align_y(Alignment::Center),
                     chip_count,
                     if let Some(indicator) = card_count_indicator {
                         indicator
@@ -196,10 +290,12 @@ fn seat_panel(p: &PublicPlayer, is_you: bool, is_to_act: bool) -> Element<'stati
             Some(iced::Background::Color(iced::Color::from_rgba(0.0, 0.0, 0.0, 0.3)))
         },
         border: iced::Border {
-            color: if is_to_act {
+            color: if is_slow {
+                iced::Color::from_rgb(1.0, 0.35, 0.3)
+            } else if is_to_act {
                 iced::Color::from_rgb(1.0, 0.8, 0.0)
             } else {
-                iced::Color::from_rgb(0.3, 0.3, 0.3)
+                iced::Color { a: 0.6, ..accent }
             },
             width: if is_to_act { 2.0 } else { 1.0 },
             radius: iced::border::Radius::from(6.0),
@@ -210,100 +306,98 @@ fn seat_panel(p: &PublicPlayer, is_you: bool, is_to_act: bool) -> Element<'stati
     .into()
 }
 
-pub fn table_view(
+/// Table area the seats are laid out in
+const TABLE_W: f32 = 600.0;
+const TABLE_H: f32 = 400.0;
+/// Space reserved for one seat panel on the ring
+const SEAT_W: f32 = 120.0;
+const SEAT_H: f32 = 96.0;
+
+/// Top-left corners of `slots` seat panels spaced evenly around the table
+/// oval. Slot 0 is bottom centre (the local player's spot) and the rest
+/// follow clockwise. Every panel stays inside the table area.
+pub(crate) fn ring_positions(slots: usize) -> Vec<(f32, f32)> {
+    let (cx, cy) = (TABLE_W / 2.0, TABLE_H / 2.0);
+    let (rx, ry) = ((TABLE_W - SEAT_W) / 2.0, (TABLE_H - SEAT_H) / 2.0);
+    (0..slots)
+        .map(|i| {
+            let angle = crate::ui::canvas::slot_angle(i, slots);
+            (
+                (cx + rx * angle.cos() - SEAT_W / 2.0).round(),
+                (cy + ry * angle.sin() - SEAT_H / 2.0).round(),
+            )
+        })
+        .collect()
+}
+
+/// Ring size and the slot each other player sits in. Slots follow
+/// `PublicPlayer.seat` counted clockwise from the viewer's own seat, so a
+/// player stays put while others join or leave. Spectators are anchored at
+/// seat 0.
+pub(crate) fn seat_slots(
     s: &PublicRoom,
     your_id: Option<Uuid>,
     your_seat: Option<usize>,
-) -> Element<'static, Msg> {
-    let mut others: Vec<&PublicPlayer> = vec![];
-    for p in &s.players {
-        let is_you = your_id.map(|id| id == p.id).unwrap_or(false)
-            || your_seat.map(|seat| seat == p.seat).unwrap_or(false);
-        if !is_you {
-            others.push(p);
-        }
-    }
+) -> (usize, Vec<(usize, &PublicPlayer)>) {
+    let is_you = |p: &PublicPlayer| your_id == Some(p.id) || your_seat == Some(p.seat);
+    let anchor = s.players.iter().find(|p| is_you(p)).map_or(0, |p| p.seat);
+    let highest_seat = s.players.iter().map(|p| p.seat + 1).max().unwrap_or(0);
+    let slots = s.players.len().max(highest_seat).min(crate::MAX_SEATS);
 
-    let mut it = others.into_iter();
-    let tl = it.next();
-    let tc = it.next();
-    let tr = it.next();
-    let bl = it.next();
-    let br = it.next();
-    let ll = it.next();
-    let rr = it.next();
-
-    let seat_box = |pp: Option<&PublicPlayer>| -> Element<Msg> {
-        match pp {
-            Some(p) => {
-                let you = your_id == Some(p.id) || your_seat == Some(p.seat);
-                let to_act = s.to_act_seat == p.seat;
-                seat_panel(p, you, to_act)
-            }
-            None => Space::with_width(Length::Fixed(0.0)).into(),
-        }
-    };
+    let placed = s
+        .players
+        .iter()
+        .filter(|p| !is_you(p) && p.seat < crate::MAX_SEATS)
+        .map(|p| ((p.seat + slots - anchor % slots) % slots, p))
+        .collect();
+    (slots, placed)
+}
 
-    let top_band = row![
-        container(seat_box(tl)).width(Length::FillPortion(1)),
-        container(seat_box(tc))
-            .width(Length::FillPortion(1))
-            .center_x(Length::Fill),
-        container(seat_box(tr))
-            .width(Length::FillPortion(1))
-            .align_x(Alignment::End),
-    ]
-        .spacing(12)
-        .width(Length::Fill);
+/// Ring slot of the player to act, for the felt's turn marker. The viewer
+/// sits in slot 0. `None` unless a hand is being played.
+pub(crate) fn to_act_slot(s: &PublicRoom, your_id: Option<Uuid>, your_seat: Option<usize>) -> Option<usize> {
+    if s.phase != cctmog_protocol::Phase::Acting {
+        return None;
+    }
+    let actor = s.players.iter().find(|p| p.seat == s.to_act_seat)?;
+    if your_id == Some(actor.id) || your_seat == Some(actor.seat) {
+        return Some(0);
+    }
+    let (_, placed) = seat_slots(s, your_id, your_seat);
+    placed.into_iter().find(|(_, p)| p.id == actor.id).map(|(slot, _)| slot)
+}
 
+pub fn table_view(
+    s: &PublicRoom,
+    your_id: Option<Uuid>,
+    your_seat: Option<usize>,
+) -> Element<'static, Msg> {
+    let turn_secs = turn_elapsed_secs(s);
+
+    let (slots, placed) = seat_slots(s, your_id, your_seat);
     let felt_canvas = crate::ui::canvas::felt_with_community(
         s.pot,
-        s.players.len(),
-        if s.phase == cctmog_protocol::Phase::Lobby { None } else { Some(s.to_act_seat) },
+        slots,
+        to_act_slot(s, your_id, your_seat),
         s.community_cards.clone(),
+        if s.street.is_some() && s.phase != cctmog_protocol::Phase::Lobby { s.game_variant.community_cards() } else { 0 },
     );
 
-    let mid_band = row![
-        container(seat_box(ll))
-            .width(Length::FillPortion(1))
-            .align_x(Alignment::Start),
-        container(felt_canvas)
-            .width(Length::FillPortion(2))
-            .center_x(Length::Fill)
-            .center_y(Length::Fill),
-        container(seat_box(rr))
-            .width(Length::FillPortion(1))
-            .align_x(Alignment::End),
-    ]
-        .spacing(12)
-        .height(Length::FillPortion(2))
-        .width(Length::Fill);
-
-    let bottom_band = row![
-        container(seat_box(bl))
-            .width(Length::FillPortion(1))
-            .align_x(Alignment::Start),
-        Space::with_width(Length::FillPortion(1)),
-        container(seat_box(br))
-            .width(Length::FillPortion(1))
-            .align_x(Alignment::End),
-    ]
-        .spacing(12)
-        .width(Length::Fill);
+    let positions = ring_positions(slots);
 
-    container(
-        column![
-            top_band,
-            Space::with_height(Length::Fixed(6.0)),
-            mid_band,
-            Space::with_height(Length::Fixed(6.0)),
-            bottom_band,
-        ]
-        .spacing(4)
-        .width(Length::Fill)
-    )
-    .width(Length::Fixed(60.0 * 10.0)) // 60 units wide
-    .height(Length::Fixed(40.0 * 10.0)) // 40 units high
+    let mut ring = stack![container(felt_canvas).center(Length::Fill)];
+    for (slot, p) in placed {
+        let (x, y) = positions[slot];
+        let to_act = s.to_act_seat == p.seat;
+        ring = ring.push(
+            container(seat_panel(p, false, to_act, if to_act { turn_secs } else { None }))
+                .padding(Padding { top: y, left: x, right: 0.0, bottom: 0.0 }),
+        );
+    }
+
+    container(ring.width(Length::Fill).height(Length::Fill))
+    .width(Length::Fixed(TABLE_W)) // 60 units wide
+    .height(Length::Fixed(TABLE_H)) // 40 units high
     .style(|_theme| iced_widget::container::Style {
         background: Some(iced::Background::Color(iced::Color::from_rgba(0.0, 0.3, 0.0, 0.8))),
         border: iced::Border {
@@ -314,4 +408,151 @@ pub fn table_view(
         ..Default::default()
     })
     .into()
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cctmog_protocol::{GameVariant, Phase};
+
+    fn room_with_players(n: usize) -> PublicRoom {
+        PublicRoom {
+            room: "layout".to_string(),
+            game_variant: GameVariant::SevenTwentySeven,
+            dealer_seat: 0,
+            to_act_seat: 0,
+            pot: 0,
+            ante: 10,
+            phase: Phase::Lobby,
+            players: (0..n)
+                .map(|seat| PublicPlayer {
+                    id: Uuid::new_v4(),
+                    name: format!("Player {}", seat + 1),
+                    seat,
+                    chips: 1000,
+                    folded: false,
+                    standing: false,
+                    up_cards: vec![],
+                    cards_count: 0,
+                    committed_round: 0,
+                    ready: false,
+                    color_seed: seat as u32 + 1,
+                    status: None,
+                })
+                .collect(),
+            in_betting: false,
+            current_bet: 0,
+            raises_made: 0,
+            max_raises: 3,
+            round: 0,
+            round_label: String::new(),
+            street: None,
+            split_pot: false,
+            limit_small: 10,
+            limit_big: 20,
+            community_cards: vec![],
+            scheduled_start: None,
+            checked_in_players: vec![],
+            elected_players: vec![],
+            current_dealer_id: None,
+            current_dealer_name: None,
+            elected_count: 0,
+            rabbit_hunt_open: false,
+            raked_total: 0,
+            showdown_winners: vec![],
+            available_variants: vec![],
+            dealers_choice: false,
+            turn_started_at: None,
+            spectators: vec![],
+        }
+    }
+
+    #[test]
+    fn test_ring_layout_eight_players() {
+        let room = room_with_players(8);
+        let you = room.players[0].id;
+        let (slots, placed) = seat_slots(&room, Some(you), Some(0));
+        assert_eq!(slots, 8);
+        assert_eq!(placed.len(), 7);
+        assert!(placed.iter().all(|(slot, p)| *slot != 0 && p.id != you));
+
+        let positions = ring_positions(slots);
+        // Local player's slot is bottom centre
+        assert_eq!(positions[0], ((TABLE_W - SEAT_W) / 2.0, TABLE_H - SEAT_H));
+        for (i, &(x, y)) in positions.iter().enumerate() {
+            assert!((0.0..=TABLE_W - SEAT_W).contains(&x), "slot {} x={}", i, x);
+            assert!((0.0..=TABLE_H - SEAT_H).contains(&y), "slot {} y={}", i, y);
+            for &other in &positions[i + 1..] {
+                assert_ne!((x, y), other);
+            }
+        }
+
+        // Spectators see all eight on the ring
+        let (slots, placed) = seat_slots(&room, None, None);
+        assert_eq!(slots, 8);
+        assert_eq!(placed.len(), 8);
+    }
+
+    #[test]
+    fn test_seat_positions_follow_seat_index() {
+        let slot_of = |room: &PublicRoom, you: usize| -> Vec<(usize, usize)> {
+            let (_, placed) = seat_slots(room, Some(room.players[you].id), Some(room.players[you].seat));
+            let mut v: Vec<(usize, usize)> = placed.iter().map(|(slot, p)| (p.seat, *slot)).collect();
+            v.sort();
+            v
+        };
+
+        // Seat 2 looking at a five-handed table: seats clockwise from you
+        let mut room = room_with_players(5);
+        assert_eq!(slot_of(&room, 2), vec![(0, 3), (1, 4), (3, 1), (4, 2)]);
+
+        // Player order in the list doesn't matter, only seat numbers
+        room.players.reverse();
+        let you = room.players.iter().position(|p| p.seat == 2).unwrap();
+        assert_eq!(slot_of(&room, you), vec![(0, 3), (1, 4), (3, 1), (4, 2)]);
+
+        // Someone leaving seat 1 doesn't move the player in seat 0
+        room.players.retain(|p| p.seat != 1);
+        let you = room.players.iter().position(|p| p.seat == 2).unwrap();
+        assert_eq!(slot_of(&room, you), vec![(0, 3), (3, 1), (4, 2)]);
+    }
+
+    #[test]
+    fn test_to_act_slot_matches_ring() {
+        let mut s = room_with_players(4);
+        let you = s.players[2].id;
+        assert_eq!(to_act_slot(&s, Some(you), None), None, "no marker in the lobby");
+
+        s.phase = Phase::Acting;
+        s.to_act_seat = 2;
+        assert_eq!(to_act_slot(&s, Some(you), None), Some(0));
+
+        // Other players land on the same slot their seat panel uses
+        let (slots, placed) = seat_slots(&s, Some(you), None);
+        let placed: Vec<(usize, usize)> = placed.into_iter().map(|(slot, p)| (slot, p.seat)).collect();
+        for seat in [0, 1, 3] {
+            s.to_act_seat = seat;
+            let expected = placed.iter().find(|(_, p)| *p == seat).map(|(slot, _)| *slot);
+            assert_eq!(to_act_slot(&s, Some(you), None), expected);
+        }
+        s.to_act_seat = 3;
+        assert_eq!(to_act_slot(&s, Some(you), None), Some(1));
+        assert_eq!(slots, 4);
+    }
+
+    #[test]
+    fn test_seat_accent_colors() {
+        // Full table: every seat gets its own accent
+        let room = room_with_players(8);
+        let accents: Vec<iced::Color> = room.players.iter().map(seat_accent).collect();
+        for (i, a) in accents.iter().enumerate() {
+            assert!(accents[i + 1..].iter().all(|b| b != a), "seat {} shares a color", i);
+        }
+
+        // No seed from the server: same color as the id-derived seed
+        let mut p = room.players[0].clone();
+        p.color_seed = 0;
+        let derived = PublicPlayer { color_seed: PublicPlayer::color_seed_for(p.id), ..p.clone() };
+        assert_eq!(seat_accent(&p), seat_accent(&derived));
+        assert_eq!(PublicPlayer::color_seed_for(p.id), PublicPlayer::color_seed_for(p.id));
+    }
+}
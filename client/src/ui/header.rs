@@ -4,6 +4,23 @@ use iced_widget::{container, row, text, Space};
 use cctmog_protocol::{PublicRoom, Phase};
 use crate::messages::Msg;
 
+/// "Pot: $120", or for split-pot games "Pot: $120 (7-pot $60 · 27-pot $60)"
+/// so players see the stakes are shared. Raked tables add what the house
+/// has taken so far.
+pub fn pot_label(room: &PublicRoom) -> String {
+    let pot = match room.pot_halves() {
+        Some((seven, twenty_seven)) => {
+            format!("Pot: ${} (7-pot ${} · 27-pot ${})", room.pot, seven, twenty_seven)
+        }
+        None => format!("Pot: ${}", room.pot),
+    };
+    if room.raked_total > 0 {
+        format!("{} · raked ${}", pot, room.raked_total)
+    } else {
+        pot
+    }
+}
+
 pub fn header_view(room: &PublicRoom) -> Element<'static, Msg> {
     let room_info = text(format!("Room: {} | Phase: {}",
         room.room.chars().take(8).collect::<String>(),
@@ -24,7 +41,7 @@ pub fn header_view(room: &PublicRoom) -> Element<'static, Msg> {
         ..Default::default()
     });
 
-    let pot_info = text(format!("Pot: ${}", room.pot))
+    let pot_info = text(pot_label(room))
         .size(14)
         .style(|_theme| iced_widget::text::Style {
             color: Some(iced::Color::from_rgb(0.2, 0.8, 0.2)),
@@ -90,18 +90,62 @@ pub fn player_options_view(
                 }
                 action_row
             }
+        } else if room.phase == Phase::Lobby {
+            row![lobby_ready_status(room, player.ready, player.seat == room.dealer_seat)]
+        } else if room.phase == Phase::Acting && player.folded {
+            row![
+                text("You folded")
+                    .size(12)
+                    .style(|_theme| iced_widget::text::Style {
+                        color: Some(iced::Color::from_rgb(0.7, 0.7, 0.7)),
+                    }),
+                Space::with_width(Length::Fixed(12.0)),
+                button(text("Show cards").size(12))
+                    .on_press(Msg::ShowCards)
+                    .style(|_theme: &iced::Theme, _status| iced_widget::button::Style {
+                        background: Some(iced::Background::Color(iced::Color::from_rgb(0.6, 0.4, 0.8))),
+                        text_color: iced::Color::WHITE,
+                        border: iced::Border {
+                            color: iced::Color::from_rgb(0.5, 0.3, 0.7),
+                            width: 1.0,
+                            radius: iced::border::Radius::from(4.0),
+                        },
+                        ..Default::default()
+                    }),
+            ]
+            .align_y(Alignment::Center)
+        } else if room.phase == Phase::Showdown && !player.folded {
+            row![
+                text("Showdown")
+                    .size(12)
+                    .style(|_theme| iced_widget::text::Style {
+                        color: Some(iced::Color::from_rgb(0.7, 0.7, 0.7)),
+                    }),
+                Space::with_width(Length::Fixed(12.0)),
+                button(text("Muck").size(12))
+                    .on_press(Msg::Muck)
+                    .style(|_theme: &iced::Theme, _status| iced_widget::button::Style {
+                        background: Some(iced::Background::Color(iced::Color::from_rgb(0.35, 0.35, 0.38))),
+                        text_color: iced::Color::WHITE,
+                        border: iced::Border {
+                            color: iced::Color::from_rgb(0.25, 0.25, 0.28),
+                            width: 1.0,
+                            radius: iced::border::Radius::from(4.0),
+                        },
+                        ..Default::default()
+                    }),
+            ]
+            .align_y(Alignment::Center)
+        } else if room.phase == Phase::GameSelection && room.dealers_choice {
+            row![dealers_choice_picker(room, room.current_dealer_id == Some(player.id))]
         } else {
             row![
-                text(if room.phase == Phase::Lobby {
-                    "Waiting for game to start..."
-                } else {
-                    "Waiting for your turn..."
-                })
-                .size(12)
-                .style(|_theme| iced_widget::text::Style {
-                    color: Some(iced::Color::from_rgb(0.7, 0.7, 0.7)),
-                    ..Default::default()
-                })
+                text("Waiting for your turn...")
+                    .size(12)
+                    .style(|_theme| iced_widget::text::Style {
+                        color: Some(iced::Color::from_rgb(0.7, 0.7, 0.7)),
+                        ..Default::default()
+                    })
             ]
         }
     } else {
@@ -154,4 +198,147 @@ pub fn player_options_view(
         ..Default::default()
     })
     .into()
-}
\ No newline at end of file
+}
+
+/// Lobby readiness: "Ready: n/m" across the table, plus either a prominent
+/// "I'm Ready" button or a highlighted confirmation for the local player.
+/// The dealer also gets "Force start" once two or more are ready.
+fn lobby_ready_status(room: &PublicRoom, you_ready: bool, you_are_dealer: bool) -> Element<'static, Msg> {
+    let ready_count = room.players.iter().filter(|p| p.ready).count();
+    let all_ready = ready_count == room.players.len() && !room.players.is_empty();
+
+    let progress = text(format!("Ready: {}/{}", ready_count, room.players.len()))
+        .size(16)
+        .style(move |_theme| iced_widget::text::Style {
+            color: Some(if all_ready {
+                iced::Color::from_rgb(0.2, 0.8, 0.2)
+            } else {
+                iced::Color::from_rgb(0.9, 0.9, 0.9)
+            }),
+        });
+
+    let your_status: Element<'static, Msg> = if you_ready {
+        row![
+            container(
+                text("✓ You're ready")
+                    .size(14)
+                    .style(|_theme| iced_widget::text::Style {
+                        color: Some(iced::Color::from_rgb(0.2, 0.8, 0.2)),
+                    })
+            )
+            .padding([6, 12])
+            .style(|_theme| iced_widget::container::Style {
+                background: Some(iced::Background::Color(iced::Color::from_rgba(0.2, 0.8, 0.2, 0.15))),
+                border: iced::Border {
+                    color: iced::Color::from_rgb(0.2, 0.8, 0.2),
+                    width: 1.0,
+                    radius: iced::border::Radius::from(4.0),
+                },
+                ..Default::default()
+            }),
+            button(text("Not ready").size(12))
+                .on_press(Msg::SitUnready)
+                .padding([4, 10]),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center)
+        .into()
+    } else {
+        button(text("I'm Ready").size(16))
+            .on_press(Msg::SitReady)
+            .padding([8, 20])
+            .style(|_theme: &iced::Theme, _status| iced_widget::button::Style {
+                background: Some(iced::Background::Color(iced::Color::from_rgb(0.2, 0.7, 0.3))),
+                text_color: iced::Color::WHITE,
+                border: iced::Border {
+                    color: iced::Color::from_rgb(1.0, 0.8, 0.0),
+                    width: 2.0,
+                    radius: iced::border::Radius::from(6.0),
+                },
+                ..Default::default()
+            })
+            .into()
+    };
+
+    let force_start: Element<'static, Msg> = if you_are_dealer && ready_count >= 2 && !all_ready {
+        button(text("Force start").size(14))
+            .on_press(Msg::ForceStart)
+            .padding([6, 14])
+            .style(|_theme: &iced::Theme, _status| iced_widget::button::Style {
+                background: Some(iced::Background::Color(iced::Color::from_rgb(0.8, 0.5, 0.1))),
+                text_color: iced::Color::WHITE,
+                border: iced::Border {
+                    color: iced::Color::from_rgb(0.6, 0.35, 0.05),
+                    width: 1.0,
+                    radius: iced::border::Radius::from(6.0),
+                },
+                ..Default::default()
+            })
+            .into()
+    } else {
+        Space::with_width(Length::Fixed(0.0)).into()
+    };
+
+    row![
+        progress,
+        Space::with_width(Length::Fixed(16.0)),
+        your_status,
+        Space::with_width(Length::Fixed(12.0)),
+        force_start,
+    ]
+    .align_y(Alignment::Center)
+    .into()
+}
+
+/// Dealer's choice window: the dealer picks the next game, everyone else waits.
+fn dealers_choice_picker(room: &PublicRoom, you_are_dealer: bool) -> Element<'static, Msg> {
+    if !you_are_dealer {
+        let dealer_name = room
+            .players
+            .iter()
+            .find(|p| Some(p.id) == room.current_dealer_id)
+            .map(|p| p.name.clone())
+            .unwrap_or_else(|| "Dealer".to_string());
+        return text(format!("{} is choosing the next game...", dealer_name))
+            .size(14)
+            .style(|_theme| iced_widget::text::Style {
+                color: Some(iced::Color::from_rgb(0.98, 0.86, 0.22)),
+            })
+            .into();
+    }
+
+    let mut choices = row![
+        text("Your choice:")
+            .size(14)
+            .style(|_theme| iced_widget::text::Style {
+                color: Some(iced::Color::from_rgb(0.98, 0.86, 0.22)),
+            }),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+
+    for variant in room.available_variants.iter().copied() {
+        let is_current = variant == room.game_variant;
+        choices = choices.push(
+            button(text(variant.to_string()).size(14))
+                .on_press(Msg::ChooseNextVariant(variant))
+                .padding([6, 12])
+                .style(move |_theme: &iced::Theme, _status| iced_widget::button::Style {
+                    background: Some(iced::Background::Color(iced::Color::from_rgb(0.36, 0.62, 0.98))),
+                    text_color: iced::Color::WHITE,
+                    border: iced::Border {
+                        color: if is_current {
+                            iced::Color::from_rgb(1.0, 0.8, 0.0)
+                        } else {
+                            iced::Color::from_rgb(0.30, 0.56, 0.92)
+                        },
+                        width: if is_current { 2.0 } else { 1.0 },
+                        radius: iced::border::Radius::from(6.0),
+                    },
+                    ..Default::default()
+                }),
+        );
+    }
+
+    choices.into()
+}
@@ -14,11 +14,25 @@ pub fn brand_logo() -> Element<'static, Msg> {
     logo
 }
 
+/// Compact table line for the footer, e.g. "5 players · 2 watching · pot 350".
+pub fn table_summary(s: &cctmog_protocol::PublicRoom) -> String {
+    let players = s.players.len();
+    let mut parts = vec![format!("{} player{}", players, if players == 1 { "" } else { "s" })];
+    if !s.spectators.is_empty() {
+        parts.push(format!("{} watching", s.spectators.len()));
+    }
+    parts.push(format!("pot {}", s.pot));
+    parts.join(" · ")
+}
+
 pub fn footer(app: &App, window_size: Option<Size>) -> Element<'_, Msg> {
     let theme_name = "Dark"; // Since we're using Theme::Dark in main.rs
 
     let websocket_status = if app.connected {
-        format!("🟢 Connected to {}", app.url)
+        match app.latency_ms {
+            Some(ms) => format!("🟢 Connected to {} · ping: {}ms", app.url, ms),
+            None => format!("🟢 Connected to {}", app.url),
+        }
     } else if app.connecting {
         format!("🟡 Connecting to {}", app.url)
     } else {
@@ -49,6 +63,12 @@ pub fn footer(app: &App, window_size: Option<Size>) -> Element<'_, Msg> {
         "🏠 No host".to_string()
     };
 
+    // Only while at a table
+    let table_info = match &app.snapshot {
+        Some(s) if app.app_state == crate::states::AppState::Game => format!("🃏 {}", table_summary(s)),
+        _ => String::new(),
+    };
+
     let footer_content = row![
         text(user_info)
             .size(12)
@@ -84,6 +104,12 @@ pub fn footer(app: &App, window_size: Option<Size>) -> Element<'_, Msg> {
                 color: Some(Color::from_rgb(0.8, 0.8, 0.8)),
                 ..Default::default()
             }),
+        Space::with_width(Length::Fixed(20.0)),
+        text(table_info)
+            .size(12)
+            .style(|_theme: &iced::Theme| iced_widget::text::Style {
+                color: Some(Color::from_rgb(0.8, 0.8, 0.8)),
+            }),
     ]
     .spacing(10);
 
@@ -1,8 +1,8 @@
 // client/src/ui/views.rs
 use iced::{Element, Length, Alignment};
-use iced_widget::{button, column, container, row, text, text_input, Space, pick_list};
+use iced_widget::{button, checkbox, column, container, row, text, text_input, Space, pick_list};
 
-use cctmog_protocol::GameVariant;
+use cctmog_protocol::{CommentTarget, GameComment, GameVariant};
 use crate::messages::Msg;
 use crate::app::App;
 use crate::ui::shared::brand_logo;
@@ -206,6 +206,59 @@ pub fn table_creation_view(app: &App) -> Element<'_, Msg> {
         .spacing(10)
         .align_y(Alignment::Center),
 
+        Space::with_height(Length::Fixed(15.0)),
+
+        // Minimum players input
+        row![
+            text("Min Players:").width(Length::Fixed(120.0)),
+            text_input("2", &app.table_min_players)
+                .on_input(Msg::TableMinPlayersChanged)
+                .padding(8)
+                .width(Length::Fixed(200.0)),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center),
+
+        Space::with_height(Length::Fixed(15.0)),
+
+        // House rake: percent of each pot, optionally capped
+        row![
+            text("Rake %:").width(Length::Fixed(120.0)),
+            text_input("0", &app.table_rake_percent)
+                .on_input(Msg::TableRakePercentChanged)
+                .padding(8)
+                .width(Length::Fixed(90.0)),
+            text_input("no cap", &app.table_rake_cap)
+                .on_input(Msg::TableRakeCapChanged)
+                .padding(8)
+                .width(Length::Fixed(100.0)),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center),
+
+        Space::with_height(Length::Fixed(15.0)),
+
+        // Seconds losers get to muck before hands are turned over
+        row![
+            text("Muck Window:").width(Length::Fixed(120.0)),
+            text_input("off", &app.table_muck_window)
+                .on_input(Msg::TableMuckWindowChanged)
+                .padding(8)
+                .width(Length::Fixed(200.0)),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center),
+
+        Space::with_height(Length::Fixed(15.0)),
+
+        // Skip choosing a dealer by hand once everyone elects to start
+        checkbox("Pick the dealer automatically", app.table_auto_dealer)
+            .on_toggle(Msg::TableAutoDealerToggled),
+
+        // Hold'em and Omaha only: show the rest of the board on request
+        checkbox("Allow rabbit hunting", app.table_rabbit_hunt)
+            .on_toggle(Msg::TableRabbitHuntToggled),
+
         Space::with_height(Length::Fixed(30.0)),
 
         // Action buttons
@@ -416,9 +469,9 @@ pub fn game_view(app: &App) -> Element<'_, Msg> {
                         column(
                             app.chat_messages
                                 .iter()
-                                .map(|(player_name, message, _scope, _timestamp)| {
+                                .map(|entry| {
                                     container(
-                                        text(format!("{}: {}", player_name, message))
+                                        text(format!("{}: {}", entry.player_name, entry.message))
                                             .size(11)
                                             .style(|_theme: &iced::Theme| iced_widget::text::Style {
                                                 color: Some(iced::Color::from_rgb(0.9, 0.9, 0.9)),
@@ -625,6 +678,129 @@ pub fn game_view(app: &App) -> Element<'_, Msg> {
         .into()
 }
 
+/// One entry in the "About:" picker: nothing in particular, a round, or a
+/// single play from the hand timeline.
+#[derive(Debug, Clone, PartialEq)]
+struct CommentAbout {
+    label: String,
+    target: Option<CommentTarget>,
+}
+
+impl CommentAbout {
+    fn new(target: Option<CommentTarget>, variant: GameVariant) -> Self {
+        let label = match &target {
+            Some(t) => capitalize(&t.label(variant)),
+            None => "The game in general".to_string(),
+        };
+        Self { label, target }
+    }
+}
+
+impl std::fmt::Display for CommentAbout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.label)
+    }
+}
+
+fn comment_about_options(actions: &[cctmog_protocol::ActionEvent], variant: GameVariant) -> Vec<CommentAbout> {
+    let mut options = vec![CommentAbout::new(None, variant)];
+    for round_actions in actions.chunk_by(|a, b| a.round == b.round) {
+        options.push(CommentAbout::new(Some(CommentTarget::Round(round_actions[0].round)), variant));
+        options.extend(
+            round_actions
+                .iter()
+                .map(|a| CommentAbout::new(Some(CommentTarget::Action(a.clone())), variant)),
+        );
+    }
+    options
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// "HH:MM" in local time from the server's RFC 3339 stamp, or the raw
+/// stamp if it doesn't parse.
+pub(crate) fn comment_time(timestamp: &str) -> String {
+    chrono::DateTime::parse_from_rfc3339(timestamp)
+        .map(|t| t.with_timezone(&chrono::Local).format("%H:%M").to_string())
+        .unwrap_or_else(|_| timestamp.to_string())
+}
+
+fn comment_card(comment: &GameComment) -> Element<'_, Msg> {
+    container(
+        row![
+            text(format!("[{}]", comment_time(&comment.timestamp)))
+                .size(12)
+                .style(|_theme| iced_widget::text::Style {
+                    color: Some(iced::Color::from_rgb(0.6, 0.6, 0.6)),
+                }),
+            text(format!("{}: {}", comment.player_name, comment.message))
+                .size(14),
+        ]
+        .spacing(6)
+        .align_y(Alignment::Center)
+    )
+    .padding(10)
+    .style(|_theme| iced_widget::container::Style {
+        background: Some(iced::Background::Color(iced::Color::from_rgb(0.08, 0.08, 0.09))),
+        border: iced::Border {
+            color: iced::Color::from_rgb(0.25, 0.25, 0.25),
+            width: 1.0,
+            radius: iced::border::Radius::from(8.0),
+        },
+        ..Default::default()
+    })
+    .width(Length::Fixed(500.0))
+    .into()
+}
+
+/// Session leaderboard: net chips per player, click the header to switch
+/// between biggest winner first and alphabetical.
+pub fn leaderboard_panel(app: &App) -> Element<'_, Msg> {
+    if app.leaderboard.is_empty() {
+        return Space::with_height(0.0).into();
+    }
+
+    let header = button(
+        text(format!(
+            "Session leaderboard ({})",
+            if app.leaderboard_by_name { "by name" } else { "by net" }
+        ))
+        .size(14),
+    )
+    .on_press(Msg::ToggleLeaderboardSort)
+    .padding([4, 8])
+    .style(|_theme, _status| iced_widget::button::Style {
+        text_color: iced::Color::from_rgb(0.95, 0.82, 0.35),
+        ..Default::default()
+    });
+
+    let rows = app.leaderboard_rows().into_iter().map(|(name, net)| {
+        row![
+            text(name.to_string()).size(13).width(Length::Fill),
+            text(format!("{:+}", net))
+                .size(13)
+                .style(move |_theme| iced_widget::text::Style {
+                    color: Some(if net >= 0 {
+                        iced::Color::from_rgb(0.5, 0.9, 0.5)
+                    } else {
+                        iced::Color::from_rgb(0.95, 0.5, 0.45)
+                    }),
+                }),
+        ]
+        .into()
+    });
+
+    container(column![header, column(rows).spacing(2).padding([0, 8])].spacing(4))
+        .width(Length::Fixed(260.0))
+        .into()
+}
+
 pub fn comments_view(app: &App) -> Element<'_, Msg> {
     let mut content = column![
         Space::with_height(Length::Fixed(40.0)),
@@ -632,40 +808,75 @@ pub fn comments_view(app: &App) -> Element<'_, Msg> {
         Space::with_height(Length::Fixed(30.0)),
     ].align_x(Alignment::Center);
 
-    // Display existing comments
+    // What happened in the hand, one line per round
+    if !app.hand_actions.is_empty() {
+        content = content.push(text("Hand timeline:").size(18));
+        content = content.push(Space::with_height(Length::Fixed(10.0)));
+
+        let mut timeline = column![].spacing(4);
+        for round_actions in app.hand_actions.chunk_by(|a, b| a.round == b.round) {
+            let events: Vec<String> = round_actions.iter().map(|a| a.to_string()).collect();
+            timeline = timeline.push(
+                text(format!("Round {}: {}", round_actions[0].round, events.join(", ")))
+                    .size(14)
+                    .style(|_theme| iced_widget::text::Style {
+                        color: Some(iced::Color::from_rgb(0.85, 0.85, 0.85)),
+                    })
+            );
+        }
+        content = content.push(
+            container(timeline)
+                .padding(10)
+                .width(Length::Fixed(500.0))
+        );
+        content = content.push(Space::with_height(Length::Fixed(20.0)));
+    }
+
+    // Hand ended before the river: offer a look at the board that would have come
+    if app.snapshot.as_ref().is_some_and(|s| s.rabbit_hunt_open) {
+        content = content.push(
+            button(text("Rabbit hunt").size(14))
+                .on_press(Msg::RabbitHunt)
+                .padding([6, 12])
+        );
+        content = content.push(Space::with_height(Length::Fixed(20.0)));
+    }
+
+    content = content.push(leaderboard_panel(app));
+    content = content.push(Space::with_height(Length::Fixed(20.0)));
+
+    let hand_variant = app
+        .hand_variant
+        .or(app.snapshot.as_ref().map(|s| s.game_variant))
+        .unwrap_or(GameVariant::SevenTwentySeven);
+
+    // Display existing comments, grouped by the round or play they're about
     if !app.game_comments.is_empty() {
         content = content.push(text("Comments:").size(18));
         content = content.push(Space::with_height(Length::Fixed(15.0)));
 
-        for comment in &app.game_comments {
-            content = content.push(
-                container(
-                    column![
-                        text(format!("{}: {}", comment.player_name, comment.message))
-                            .size(14),
-                        text(&comment.timestamp)
-                            .size(12)
-                            .style(|_theme| iced_widget::text::Style {
-                                color: Some(iced::Color::from_rgb(0.6, 0.6, 0.6)),
-                                ..Default::default()
-                            }),
-                    ]
-                    .spacing(4)
-                )
-                .padding(10)
-                .style(|_theme| iced_widget::container::Style {
-                    background: Some(iced::Background::Color(iced::Color::from_rgb(0.08, 0.08, 0.09))),
-                    border: iced::Border {
-                        color: iced::Color::from_rgb(0.25, 0.25, 0.25),
-                        width: 1.0,
-                        radius: iced::border::Radius::from(8.0),
-                    },
-                    ..Default::default()
-                })
-                .width(Length::Fixed(500.0))
-            );
-            content = content.push(Space::with_height(Length::Fixed(8.0)));
+        let mut comments_panel = column![].align_x(Alignment::Center);
+        for (about, comments) in app.comment_groups() {
+            if let Some(about) = about {
+                comments_panel = comments_panel.push(
+                    text(capitalize(&about.label(hand_variant)))
+                        .size(15)
+                        .style(|_theme| iced_widget::text::Style {
+                            color: Some(iced::Color::from_rgb(0.95, 0.82, 0.35)),
+                        })
+                );
+                comments_panel = comments_panel.push(Space::with_height(Length::Fixed(6.0)));
+            }
+            for comment in comments {
+                comments_panel = comments_panel.push(comment_card(comment));
+                comments_panel = comments_panel.push(Space::with_height(Length::Fixed(8.0)));
+            }
         }
+        content = content.push(
+            container(iced_widget::scrollable(comments_panel))
+                .max_height(300.0)
+                .width(Length::Fixed(520.0))
+        );
     } else {
         content = content.push(
             text("No comments yet. Be the first to share your thoughts!")
@@ -679,11 +890,48 @@ pub fn comments_view(app: &App) -> Element<'_, Msg> {
 
     content = content.push(Space::with_height(Length::Fixed(30.0)));
 
+    // Next game picker - the variant can be changed between hands
+    let variant_options = vec![
+        GameVariant::SevenTwentySeven,
+        GameVariant::Omaha,
+        GameVariant::TexasHoldem,
+    ];
+    let current_variant = app.snapshot.as_ref().map(|s| s.game_variant);
+    let dealers_choice = app.snapshot.as_ref().is_some_and(|s| s.dealers_choice);
+    content = content.push(
+        row![
+            text("Next game:").size(16),
+            pick_list(
+                variant_options,
+                current_variant,
+                Msg::SelectGameVariant
+            )
+            .width(Length::Fixed(200.0)),
+            checkbox("Dealer's choice", dealers_choice)
+                .on_toggle(Msg::SetDealersChoice),
+        ]
+        .spacing(10)
+        .align_y(Alignment::Center)
+    );
+
+    content = content.push(Space::with_height(Length::Fixed(20.0)));
+
     // Comment input section
     content = content.push(
         column![
             text("Add your comment:").size(16),
             Space::with_height(Length::Fixed(10.0)),
+            row![
+                text("About:").size(14),
+                pick_list(
+                    comment_about_options(&app.hand_actions, hand_variant),
+                    Some(CommentAbout::new(app.comment_about.clone(), hand_variant)),
+                    |choice: CommentAbout| Msg::CommentAboutChanged(choice.target)
+                )
+                .width(Length::Fixed(300.0)),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
             text_input("Share your thoughts about this game...", &app.comment_input)
                 .on_input(Msg::CommentInputChanged)
                 .on_submit(Msg::PostComment)
@@ -706,10 +954,16 @@ pub fn comments_view(app: &App) -> Element<'_, Msg> {
                         ..Default::default()
                     }),
                 Space::with_width(Length::Fixed(20.0)),
-                button(text(if app.ready_to_continue { "Ready ✓" } else { "Continue to Next Game" }))
+                button(text({
+                    let label = if app.ready_to_continue { "Ready ✓" } else { "Continue to Next Game" };
+                    match app.continue_progress() {
+                        Some((ready, seated)) => format!("{} ({}/{} ready)", label, ready, seated),
+                        None => label.to_string(),
+                    }
+                }))
                     .on_press(Msg::ContinueToNextGame)
                     .padding(12)
-                    .width(Length::Fixed(200.0))
+                    .width(Length::Fixed(260.0))
                     .style(move |_theme, _status| iced_widget::button::Style {
                         background: Some(iced::Background::Color(if app.ready_to_continue {
                             iced::Color::from_rgb(0.2, 0.7, 0.2)
@@ -735,6 +989,28 @@ pub fn comments_view(app: &App) -> Element<'_, Msg> {
         .spacing(8)
     );
 
+    // The dealer can skip the continue round and re-deal right away
+    let you_are_dealer = app.your_id.is_some()
+        && app.snapshot.as_ref().and_then(|s| s.current_dealer_id) == app.your_id;
+    if you_are_dealer {
+        content = content.push(Space::with_height(Length::Fixed(10.0)));
+        content = content.push(
+            button(text("Rematch now"))
+                .on_press(Msg::Rematch)
+                .padding(12)
+                .width(Length::Fixed(200.0)),
+        );
+    }
+    if app.snapshot.as_ref().is_some_and(|s| app.is_dealer_or_host(s)) {
+        content = content.push(Space::with_height(Length::Fixed(10.0)));
+        content = content.push(
+            button(text("Close table"))
+                .on_press(Msg::CloseTable)
+                .padding(12)
+                .width(Length::Fixed(200.0)),
+        );
+    }
+
     content = content.push(Space::with_height(Length::Fixed(30.0)));
 
     // Back button
@@ -79,7 +79,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
 async fn handle_server_message(msg: ServerToClient, player_name: &str) {
     match msg {
-        ServerToClient::Hello { your_id } => {
+        ServerToClient::Hello { your_id, .. } => {
             println!("👋 [{}] Welcome! Your ID: {}", player_name, your_id);
         }
         ServerToClient::Joined { snapshot, your_seat, your_hand } => {
@@ -93,13 +93,13 @@ async fn handle_server_message(msg: ServerToClient, player_name: &str) {
         ServerToClient::YourHand { hand } => {
             println!("🃏 [{}] Your cards updated: {} down cards", player_name, hand.down_cards.len());
         }
-        ServerToClient::Error { message } => {
+        ServerToClient::Error { message, .. } => {
             println!("❌ [{}] Error: {}", player_name, message);
         }
         ServerToClient::Info { message } => {
             println!("ℹ️  [{}] {}", player_name, message);
         }
-        ServerToClient::Showdown { winners7, winners27, payouts, reveal } => {
+        ServerToClient::Showdown { winners7, winners27, payouts, reveal, actions } => {
             println!("\n🎭 [{}] SHOWDOWN!", player_name);
             println!("🏆 [{}] 7-or-under winners: {:?}", player_name, winners7);
             println!("🏆 [{}] 27-or-under winners: {:?}", player_name, winners27);
@@ -107,6 +107,9 @@ async fn handle_server_message(msg: ServerToClient, player_name: &str) {
             for (player_id, cards) in reveal {
                 println!("🃏 [{}] Player {}: {:?}", player_name, player_id, cards);
             }
+            for action in actions {
+                println!("📜 [{}] Round {}: {}", player_name, action.round, action);
+            }
         }
         ServerToClient::ChatMessage { player_name: sender, message, scope, .. } => {
             println!("💬 [{}] [{:?}] {}: {}", player_name, scope, sender, message);
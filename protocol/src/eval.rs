@@ -0,0 +1,295 @@
+//! Standard poker hand evaluation (Texas Hold'em / Omaha).
+//!
+//! `evaluate_five` ranks exactly five cards; `best_of_seven` picks the best
+//! five-card hand out of up to seven, and `best_community_hand` applies a
+//! variant's rules for combining hole and board cards. Hands are ordered
+//! with `compare` and named with `describe`.
+
+use std::cmp::Ordering;
+
+use crate::{Card, GameVariant, HandRank, Rank};
+
+/// Rank exactly five cards.
+pub fn evaluate_five(cards: &[Card; 5]) -> HandRank {
+    // Ranks sorted high to low
+    let mut ranks: Vec<Rank> = cards.iter().map(|c| c.rank).collect();
+    ranks.sort_by(|a, b| b.cmp(a));
+
+    let is_flush = cards.iter().all(|c| c.suit == cards[0].suit);
+    let straight_high = straight_high_card(&ranks);
+
+    // Group ranks by count, biggest group first, then by rank
+    let mut groups: Vec<(usize, Rank)> = vec![];
+    for &r in &ranks {
+        match groups.iter_mut().find(|(_, g)| *g == r) {
+            Some((n, _)) => *n += 1,
+            None => groups.push((1, r)),
+        }
+    }
+    groups.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.cmp(&a.1)));
+
+    match (straight_high, is_flush) {
+        (Some(Rank::Ace), true) => return HandRank::RoyalFlush,
+        (Some(high), true) => return HandRank::StraightFlush(high),
+        _ => {}
+    }
+
+    match groups.as_slice() {
+        [(4, quad), (1, kicker)] => HandRank::FourOfAKind(*quad, *kicker),
+        [(3, trips), (2, pair)] => HandRank::FullHouse(*trips, *pair),
+        _ if is_flush => HandRank::Flush(ranks),
+        _ if straight_high.is_some() => HandRank::Straight(straight_high.unwrap()),
+        [(3, trips), rest @ ..] => HandRank::ThreeOfAKind(*trips, rest.iter().map(|(_, r)| *r).collect()),
+        [(2, high), (2, low), (1, kicker)] => HandRank::TwoPair(*high, *low, *kicker),
+        [(2, pair), rest @ ..] => HandRank::OnePair(*pair, rest.iter().map(|(_, r)| *r).collect()),
+        _ => HandRank::HighCard(ranks),
+    }
+}
+
+/// Best five-card hand from five to seven cards (e.g. two hole cards plus
+/// the board). Returns `None` if fewer than five cards are given.
+pub fn best_of_seven(cards: &[Card]) -> Option<HandRank> {
+    let n = cards.len();
+    if n < 5 {
+        return None;
+    }
+
+    let mut best: Option<HandRank> = None;
+    for a in 0..n {
+        for b in a + 1..n {
+            for c in b + 1..n {
+                for d in c + 1..n {
+                    for e in d + 1..n {
+                        let hand = evaluate_five(&[cards[a], cards[b], cards[c], cards[d], cards[e]]);
+                        if best.as_ref().is_none_or(|cur| compare(&hand, cur) == Ordering::Greater) {
+                            best = Some(hand);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    best
+}
+
+/// Best poker hand a player makes with the board. Hold'em plays any five
+/// of hole plus board; Omaha must use exactly two hole and three board cards.
+pub fn best_community_hand(variant: GameVariant, hole: &[Card], board: &[Card]) -> Option<HandRank> {
+    match variant {
+        GameVariant::TexasHoldem => {
+            let mut cards = hole.to_vec();
+            cards.extend_from_slice(board);
+            best_of_seven(&cards)
+        }
+        GameVariant::Omaha => {
+            let mut best: Option<HandRank> = None;
+            for a in 0..hole.len() {
+                for b in a + 1..hole.len() {
+                    for c in 0..board.len() {
+                        for d in c + 1..board.len() {
+                            for e in d + 1..board.len() {
+                                let hand = evaluate_five(&[hole[a], hole[b], board[c], board[d], board[e]]);
+                                if best.as_ref().is_none_or(|cur| compare(&hand, cur) == Ordering::Greater) {
+                                    best = Some(hand);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            best
+        }
+        GameVariant::SevenTwentySeven => None,
+    }
+}
+
+/// Plain-English name for a hand, e.g. "Pair of Kings" or "Flush, Ace high".
+pub fn describe(hand: &HandRank) -> String {
+    match hand {
+        HandRank::HighCard(rs) => format!("{} high", rank_name(rs[0])),
+        HandRank::OnePair(p, _) => format!("Pair of {}", rank_plural(*p)),
+        HandRank::TwoPair(hi, lo, _) => format!("Two pair, {} and {}", rank_plural(*hi), rank_plural(*lo)),
+        HandRank::ThreeOfAKind(t, _) => format!("Three {}", rank_plural(*t)),
+        HandRank::Straight(r) => format!("Straight, {} high", rank_name(*r)),
+        HandRank::Flush(rs) => format!("Flush, {} high", rank_name(rs[0])),
+        HandRank::FullHouse(t, p) => format!("Full house, {} full of {}", rank_plural(*t), rank_plural(*p)),
+        HandRank::FourOfAKind(q, _) => format!("Four {}", rank_plural(*q)),
+        HandRank::StraightFlush(r) => format!("Straight flush, {} high", rank_name(*r)),
+        HandRank::RoyalFlush => "Royal flush".to_string(),
+        HandRank::SevenTwentySeven(_) => "7/27 hand".to_string(),
+    }
+}
+
+fn rank_name(r: Rank) -> &'static str {
+    match r {
+        Rank::Two => "Two",
+        Rank::Three => "Three",
+        Rank::Four => "Four",
+        Rank::Five => "Five",
+        Rank::Six => "Six",
+        Rank::Seven => "Seven",
+        Rank::Eight => "Eight",
+        Rank::Nine => "Nine",
+        Rank::Ten => "Ten",
+        Rank::Jack => "Jack",
+        Rank::Queen => "Queen",
+        Rank::King => "King",
+        Rank::Ace => "Ace",
+    }
+}
+
+fn rank_plural(r: Rank) -> String {
+    match r {
+        Rank::Six => "Sixes".to_string(),
+        _ => format!("{}s", rank_name(r)),
+    }
+}
+
+/// Order two hands: category first, then the ranks that break ties.
+pub fn compare(a: &HandRank, b: &HandRank) -> Ordering {
+    a.strength()
+        .cmp(&b.strength())
+        .then_with(|| tiebreak(a).cmp(&tiebreak(b)))
+}
+
+/// High card of a straight in ranks sorted high to low, counting A-2-3-4-5
+/// (the wheel) as five-high.
+fn straight_high_card(sorted_desc: &[Rank]) -> Option<Rank> {
+    let values: Vec<u8> = sorted_desc.iter().map(|r| *r as u8).collect();
+    if values.windows(2).all(|w| w[0] == w[1] + 1) {
+        return Some(sorted_desc[0]);
+    }
+    if values == [Rank::Ace as u8, 5, 4, 3, 2] {
+        return Some(Rank::Five);
+    }
+    None
+}
+
+fn tiebreak(h: &HandRank) -> Vec<Rank> {
+    match h {
+        HandRank::HighCard(rs) | HandRank::Flush(rs) => rs.clone(),
+        HandRank::OnePair(p, ks) | HandRank::ThreeOfAKind(p, ks) => {
+            let mut v = vec![*p];
+            v.extend(ks.iter().copied());
+            v
+        }
+        HandRank::TwoPair(hi, lo, k) => vec![*hi, *lo, *k],
+        HandRank::Straight(r) | HandRank::StraightFlush(r) => vec![*r],
+        HandRank::FullHouse(a, b) | HandRank::FourOfAKind(a, b) => vec![*a, *b],
+        HandRank::RoyalFlush | HandRank::SevenTwentySeven(_) => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Suit;
+    use Rank::*;
+    use Suit::*;
+
+    fn c(rank: Rank, suit: Suit) -> Card {
+        Card { rank, suit, face_up: true }
+    }
+
+    fn five(cards: [(Rank, Suit); 5]) -> HandRank {
+        evaluate_five(&cards.map(|(r, s)| c(r, s)))
+    }
+
+    #[test]
+    fn test_each_category() {
+        assert_eq!(five([(Ace, Spades), (King, Spades), (Queen, Spades), (Jack, Spades), (Ten, Spades)]), HandRank::RoyalFlush);
+        assert_eq!(five([(Nine, Hearts), (King, Hearts), (Queen, Hearts), (Jack, Hearts), (Ten, Hearts)]), HandRank::StraightFlush(King));
+        assert_eq!(five([(Seven, Spades), (Seven, Hearts), (Seven, Clubs), (Seven, Diamonds), (Two, Spades)]), HandRank::FourOfAKind(Seven, Two));
+        assert_eq!(five([(Three, Spades), (Three, Hearts), (Three, Clubs), (Nine, Diamonds), (Nine, Spades)]), HandRank::FullHouse(Three, Nine));
+        assert_eq!(five([(Two, Clubs), (Nine, Clubs), (Jack, Clubs), (Four, Clubs), (Ace, Clubs)]), HandRank::Flush(vec![Ace, Jack, Nine, Four, Two]));
+        assert_eq!(five([(Six, Clubs), (Seven, Hearts), (Eight, Spades), (Nine, Diamonds), (Ten, Clubs)]), HandRank::Straight(Ten));
+        assert_eq!(five([(Queen, Clubs), (Queen, Hearts), (Queen, Spades), (Two, Diamonds), (Nine, Clubs)]), HandRank::ThreeOfAKind(Queen, vec![Nine, Two]));
+        assert_eq!(five([(Jack, Clubs), (Jack, Hearts), (Four, Spades), (Four, Diamonds), (Ace, Clubs)]), HandRank::TwoPair(Jack, Four, Ace));
+        assert_eq!(five([(Ten, Clubs), (Ten, Hearts), (King, Spades), (Four, Diamonds), (Two, Clubs)]), HandRank::OnePair(Ten, vec![King, Four, Two]));
+        assert_eq!(five([(Ace, Clubs), (Ten, Hearts), (Eight, Spades), (Four, Diamonds), (Two, Clubs)]), HandRank::HighCard(vec![Ace, Ten, Eight, Four, Two]));
+    }
+
+    #[test]
+    fn test_wheel_and_ace_high_straights() {
+        let wheel = five([(Ace, Clubs), (Two, Hearts), (Three, Spades), (Four, Diamonds), (Five, Clubs)]);
+        assert_eq!(wheel, HandRank::Straight(Five));
+
+        let broadway = five([(Ace, Clubs), (King, Hearts), (Queen, Spades), (Jack, Diamonds), (Ten, Clubs)]);
+        assert_eq!(broadway, HandRank::Straight(Ace));
+        assert_eq!(compare(&broadway, &wheel), Ordering::Greater);
+
+        // The wheel is the lowest straight
+        let six_high = five([(Two, Clubs), (Three, Hearts), (Four, Spades), (Five, Diamonds), (Six, Clubs)]);
+        assert_eq!(compare(&six_high, &wheel), Ordering::Greater);
+
+        // Steel wheel is a five-high straight flush, not a royal
+        let steel = five([(Ace, Hearts), (Two, Hearts), (Three, Hearts), (Four, Hearts), (Five, Hearts)]);
+        assert_eq!(steel, HandRank::StraightFlush(Five));
+
+        // No wrap-around straights
+        let wrap = five([(Queen, Clubs), (King, Hearts), (Ace, Spades), (Two, Diamonds), (Three, Clubs)]);
+        assert_eq!(wrap, HandRank::HighCard(vec![Ace, King, Queen, Three, Two]));
+    }
+
+    #[test]
+    fn test_category_precedence() {
+        let flush = five([(Two, Clubs), (Nine, Clubs), (Jack, Clubs), (Four, Clubs), (Seven, Clubs)]);
+        let straight = five([(Ten, Clubs), (Jack, Hearts), (Queen, Spades), (King, Diamonds), (Ace, Clubs)]);
+        let full_house = five([(Two, Spades), (Two, Hearts), (Two, Clubs), (Three, Diamonds), (Three, Spades)]);
+        let quads = five([(Two, Spades), (Two, Hearts), (Two, Clubs), (Two, Diamonds), (Three, Spades)]);
+
+        assert_eq!(compare(&flush, &straight), Ordering::Greater);
+        assert_eq!(compare(&full_house, &flush), Ordering::Greater);
+        assert_eq!(compare(&quads, &full_house), Ordering::Greater);
+        assert_eq!(compare(&HandRank::RoyalFlush, &quads), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_tiebreaks_within_category() {
+        let aces_up = five([(Ace, Clubs), (Ace, Hearts), (Three, Spades), (Three, Diamonds), (Two, Clubs)]);
+        let kings_up = five([(King, Clubs), (King, Hearts), (Queen, Spades), (Queen, Diamonds), (Jack, Clubs)]);
+        assert_eq!(compare(&aces_up, &kings_up), Ordering::Greater);
+
+        let pair_ace_kicker = five([(Nine, Clubs), (Nine, Hearts), (Ace, Spades), (Four, Diamonds), (Two, Clubs)]);
+        let pair_king_kicker = five([(Nine, Spades), (Nine, Diamonds), (King, Spades), (Four, Clubs), (Two, Hearts)]);
+        assert_eq!(compare(&pair_ace_kicker, &pair_king_kicker), Ordering::Greater);
+
+        let same = five([(Nine, Spades), (Nine, Diamonds), (Ace, Hearts), (Four, Clubs), (Two, Hearts)]);
+        assert_eq!(compare(&pair_ace_kicker, &same), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_best_of_seven() {
+        // Board pairs plus a flush in hearts: flush wins over two pair
+        let cards = [
+            c(Ace, Hearts), c(Four, Hearts),
+            c(Nine, Hearts), c(Nine, Spades), c(Two, Hearts), c(Jack, Hearts), c(Jack, Clubs),
+        ];
+        assert_eq!(best_of_seven(&cards), Some(HandRank::Flush(vec![Ace, Jack, Nine, Four, Two])));
+
+        // Full house beats the flush also available
+        let cards = [
+            c(Nine, Clubs), c(Jack, Diamonds),
+            c(Nine, Hearts), c(Nine, Spades), c(Two, Hearts), c(Jack, Hearts), c(Five, Hearts),
+        ];
+        assert_eq!(best_of_seven(&cards), Some(HandRank::FullHouse(Nine, Jack)));
+
+        // Wheel straight hidden among seven cards
+        let cards = [
+            c(Ace, Clubs), c(King, Diamonds),
+            c(Two, Hearts), c(Three, Spades), c(Four, Hearts), c(Five, Clubs), c(Nine, Diamonds),
+        ];
+        assert_eq!(best_of_seven(&cards), Some(HandRank::Straight(Five)));
+
+        assert_eq!(best_of_seven(&cards[..4]), None);
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(describe(&HandRank::OnePair(King, vec![Nine, Four, Two])), "Pair of Kings");
+        assert_eq!(describe(&HandRank::TwoPair(Jack, Six, Ace)), "Two pair, Jacks and Sixes");
+        assert_eq!(describe(&HandRank::FullHouse(Three, Nine)), "Full house, Threes full of Nines");
+        assert_eq!(describe(&HandRank::Straight(Five)), "Straight, Five high");
+        assert_eq!(describe(&HandRank::HighCard(vec![Ace, Ten, Eight, Four, Two])), "Ace high");
+    }
+}
@@ -4,6 +4,9 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use uuid::Uuid;
 
+pub mod eval;
+pub mod names;
+
 /// ---- Message Scopes for Chat ----
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum MessageScope {
@@ -23,6 +26,11 @@ impl Default for MessageScope {
     }
 }
 
+/// Wire protocol version, sent in `ServerToClient::Hello`. Bump it whenever
+/// `ClientToServer` or `ServerToClient` change in a way an older peer can't
+/// decode (new variants, renamed or required fields).
+pub const PROTOCOL_VERSION: u32 = 1;
+
 /// ---- Game Variants ----
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum GameVariant {
@@ -47,47 +55,121 @@ impl Default for GameVariant {
     }
 }
 
+/// Betting street in a community-card game, named for the board it is
+/// bet on. The server deals the flop with the hole cards, so the first
+/// street seen in play is `Flop`; `Preflop` only shows before the deal.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Street {
+    Preflop,
+    Flop,
+    Turn,
+    River,
+}
+
+impl Street {
+    /// Street for a board of `board_len` cards, or `None` for variants
+    /// without community cards.
+    pub fn for_board(variant: GameVariant, board_len: usize) -> Option<Street> {
+        if !variant.uses_community_cards() {
+            return None;
+        }
+        Some(match board_len {
+            0..=2 => Street::Preflop,
+            3 => Street::Flop,
+            4 => Street::Turn,
+            _ => Street::River,
+        })
+    }
+}
+
+/// Everything that differs between variants, in one place. Adding a
+/// variant means adding one entry to `GameVariant::config`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VariantConfig {
+    /// Number of hole cards dealt to each player
+    pub hole_cards: usize,
+    /// Number of community cards (board cards)
+    pub community_cards: usize,
+    /// Maximum number of cards a player can have
+    pub max_cards_per_player: usize,
+    /// Betting rounds per hand; `None` keeps going until everyone stands
+    pub betting_rounds: Option<u32>,
+    /// Pot split between a high and a low hand (7 and 27)
+    pub hi_lo: bool,
+}
+
 /// Game configuration constants for different variants
 impl GameVariant {
-    /// Number of hole cards dealt to each player
-    pub fn hole_cards(&self) -> usize {
+    pub fn config(&self) -> VariantConfig {
         match self {
-            GameVariant::SevenTwentySeven => 2, // 2 down cards initially
-            GameVariant::Omaha => 4,
-            GameVariant::TexasHoldem => 2,
+            GameVariant::SevenTwentySeven => VariantConfig {
+                hole_cards: 2, // 2 down cards initially
+                community_cards: 0,
+                max_cards_per_player: 7, // Can draw up to 5 more cards
+                betting_rounds: None,
+                hi_lo: true,
+            },
+            GameVariant::Omaha => VariantConfig {
+                hole_cards: 4,
+                community_cards: 5,
+                max_cards_per_player: 4, // Only hole cards
+                betting_rounds: Some(3), // flop, turn, river
+                hi_lo: false,
+            },
+            GameVariant::TexasHoldem => VariantConfig {
+                hole_cards: 2,
+                community_cards: 5,
+                max_cards_per_player: 2, // Only hole cards
+                betting_rounds: Some(3), // flop, turn, river
+                hi_lo: false,
+            },
         }
     }
 
+    /// Number of hole cards dealt to each player
+    pub fn hole_cards(&self) -> usize {
+        self.config().hole_cards
+    }
+
     /// Number of community cards (board cards)
     pub fn community_cards(&self) -> usize {
-        match self {
-            GameVariant::SevenTwentySeven => 0, // No community cards
-            GameVariant::Omaha => 5,
-            GameVariant::TexasHoldem => 5,
-        }
+        self.config().community_cards
     }
 
     /// Maximum number of cards a player can have
     pub fn max_cards_per_player(&self) -> usize {
-        match self {
-            GameVariant::SevenTwentySeven => 7, // Can draw up to 5 more cards
-            GameVariant::Omaha => 4, // Only hole cards
-            GameVariant::TexasHoldem => 2, // Only hole cards
-        }
+        self.config().max_cards_per_player
     }
 
     /// Whether this variant uses community cards
     pub fn uses_community_cards(&self) -> bool {
-        match self {
-            GameVariant::SevenTwentySeven => false,
-            GameVariant::Omaha => true,
-            GameVariant::TexasHoldem => true,
+        self.config().community_cards > 0
+    }
+
+    /// Human name for a round, e.g. "Flop" or "Draw 2". Community games
+    /// deal the flop with the hole cards, so betting round 1 is the flop.
+    /// 7/27 rounds are a draw followed by a bet, told apart by `in_betting`.
+    pub fn round_label(&self, round: u32, in_betting: bool) -> String {
+        if self.uses_community_cards() {
+            return match round {
+                0 => "Preflop",
+                1 => "Flop",
+                2 => "Turn",
+                3 => "River",
+                _ => "Showdown",
+            }
+            .to_string();
+        }
+        match (round, in_betting) {
+            (0, _) => "Deal".to_string(),
+            (n, false) => format!("Draw {}", n),
+            (n, true) => format!("Bet {}", n),
         }
     }
 }
 
 /// ---- Cards ----
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Suit {
     Clubs,
     Diamonds,
@@ -119,6 +201,23 @@ pub struct Card {
     pub face_up: bool,
 }
 
+/// Cards order by rank, then suit (clubs low, spades high). `face_up` only
+/// breaks ties so the ordering agrees with `Eq`.
+impl Ord for Card {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.rank
+            .cmp(&other.rank)
+            .then(self.suit.cmp(&other.suit))
+            .then(self.face_up.cmp(&other.face_up))
+    }
+}
+
+impl PartialOrd for Card {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl fmt::Display for Card {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let r = match self.rank {
@@ -153,28 +252,39 @@ pub struct Deck {
 
 impl Deck {
     pub fn standard_shuffled() -> Self {
-        let mut cards = Vec::with_capacity(52);
-        for &s in &[Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades] {
-            for r in [
-                Rank::Two,
-                Rank::Three,
-                Rank::Four,
-                Rank::Five,
-                Rank::Six,
-                Rank::Seven,
-                Rank::Eight,
-                Rank::Nine,
-                Rank::Ten,
-                Rank::Jack,
-                Rank::Queen,
-                Rank::King,
-                Rank::Ace,
-            ] {
-                cards.push(Card {
-                    rank: r,
-                    suit: s,
-                    face_up: false,
-                });
+        Self::standard_shuffled_n(1)
+    }
+
+    /// A shoe of `decks` standard decks shuffled together (at least one).
+    /// Every card can turn up more than once, so hands built from a shoe may
+    /// hold exact duplicates; flush and straight logic doesn't expect that,
+    /// which is fine for 7/27 but not for the poker variants.
+    pub fn standard_shuffled_n(decks: usize) -> Self {
+        let decks = decks.max(1);
+        let mut cards = Vec::with_capacity(52 * decks);
+        for _ in 0..decks {
+            for &s in &[Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades] {
+                for r in [
+                    Rank::Two,
+                    Rank::Three,
+                    Rank::Four,
+                    Rank::Five,
+                    Rank::Six,
+                    Rank::Seven,
+                    Rank::Eight,
+                    Rank::Nine,
+                    Rank::Ten,
+                    Rank::Jack,
+                    Rank::Queen,
+                    Rank::King,
+                    Rank::Ace,
+                ] {
+                    cards.push(Card {
+                        rank: r,
+                        suit: s,
+                        face_up: false,
+                    });
+                }
             }
         }
         cards.shuffle(&mut thread_rng());
@@ -294,6 +404,20 @@ pub struct PublicPlayer {
     pub committed_round: u64,
     // NEW
     pub ready: bool,
+    #[serde(default)]
+    pub color_seed: u32, // Stable per-player accent color, see `PublicPlayer::color_seed_for`
+    #[serde(default)]
+    pub status: Option<String>, // Player-set note like "BRB", shown under the name
+}
+
+impl PublicPlayer {
+    /// Deterministic color seed for a player id, so the same player keeps
+    /// the same seat color across hands and clients.
+    pub fn color_seed_for(id: uuid::Uuid) -> u32 {
+        id.as_bytes()
+            .chunks(4)
+            .fold(0u32, |acc, c| acc ^ u32::from_be_bytes([c[0], c[1], c[2], c[3]]))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -328,7 +452,16 @@ pub struct PublicRoom {
     pub current_bet: u64,
     pub raises_made: u32,
     pub max_raises: u32,
+    /// Betting round within the hand, starting at 1 once cards are dealt
+    /// (0 in the lobby). In 7/27 each round is a draw then a bet; in
+    /// community games it counts streets, see `street`.
     pub round: u32,
+    #[serde(default)]
+    pub round_label: String, // "Flop", "Draw 2"… derived from variant and round
+    #[serde(default)]
+    pub street: Option<Street>, // community games only; follows the board size
+    #[serde(default)]
+    pub split_pot: bool, // 7/27: the pot is shared between the best 7 and best 27 hands
     pub limit_small: u64,
     pub limit_big: u64,
     pub community_cards: Vec<Card>,
@@ -337,7 +470,78 @@ pub struct PublicRoom {
     // Dealer system fields
     pub elected_players: Vec<Uuid>, // Players who have elected to start
     pub current_dealer_id: Option<Uuid>, // Current dealer (if any)
+    #[serde(default)]
+    pub current_dealer_name: Option<String>, // Name for current_dealer_id, if seated
+    #[serde(default)]
+    pub elected_count: usize, // elected_players.len(), for "3/5 elected"
+    #[serde(default)]
+    pub rabbit_hunt_open: bool, // a hand ended early and its undealt board can be revealed
+    #[serde(default)]
+    pub raked_total: u64, // house cut taken at this table so far
+    #[serde(default)]
+    pub showdown_winners: Vec<Uuid>, // set while losing hands may still muck
     pub available_variants: Vec<GameVariant>, // Available game variants for dealer to choose
+    #[serde(default)]
+    pub dealers_choice: bool, // Each new dealer picks the variant for the next hand
+    #[serde(default)]
+    pub turn_started_at: Option<String>, // ISO 8601 timestamp when the current to_act turn began
+    #[serde(default)]
+    pub spectators: Vec<PublicSpectator>, // Watching without a seat
+}
+
+impl PublicRoom {
+    /// The (7-pot, 27-pot) shares of the current pot in a split-pot game,
+    /// divided the way the server pays them: the odd chip goes to the 27.
+    pub fn pot_halves(&self) -> Option<(u64, u64)> {
+        self.split_pot.then(|| (self.pot / 2, self.pot - self.pot / 2))
+    }
+}
+
+/// Someone watching a table, as shown to everyone at it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicSpectator {
+    pub id: Uuid,
+    pub name: String,
+}
+
+/// Machine-readable reason for a rejected action, sent with `Error` so
+/// clients and tests don't have to match on the message text.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ErrorCode {
+    #[default]
+    Other,
+    NotInBetting,
+    NotYourTurn,
+    AlreadyFolded,
+    CheckFacingBet, // must call, raise or fold
+    BetAlreadyOpen, // must raise instead of bet
+    NoBetToRaise,
+    NoBetToCall,
+    RaiseCapReached,
+    MaxCardsReached, // 7/27: no more draws
+    MalformedCommand, // frame did not decode as a ClientToServer
+    FrameTooLarge,
+}
+
+/// What a player may do on their turn, as decided by the server. Amounts are
+/// the chips the action would put in (call) or add to the bet (bet/raise).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TurnOptions {
+    pub can_check: bool,
+    pub can_call: Option<u64>,
+    pub can_bet: Option<u64>,
+    pub can_raise: Option<u64>,
+    pub can_take_card: bool,
+    pub can_stand: bool,
+    pub can_fold: bool,
+}
+
+/// One entry in the `LoungePlayers` roster.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LoungePlayer {
+    pub name: String,
+    pub hosting: bool,        // volunteered to host a game
+    pub room: Option<String>, // table they're seated at or watching; None = in the lounge
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -354,23 +558,32 @@ pub enum ClientToServer {
     Join { room: String, name: String },
     Leave,
     SitReady,
+    SitUnready, // take back SitReady while still in the lobby
+    SetStatus { text: Option<String> }, // short note on your seat ("BRB"); None clears it
+    Resync, // resend me the current snapshot, my hand and my turn options
     StartHand,
+    ForceStart, // dealer only: start with the ready players, the rest sit out
     SelectGameVariant { variant: GameVariant },
 
     // Draw sub-phase
     TakeCard,
     Stand,
     Fold,
+    ShowCards, // voluntarily reveal your hand after folding
+    Muck,      // lost at showdown: don't turn the hand over
+    RabbitHunt, // after a community hand ends early, show the board that would have come
 
     // Betting sub-phase
     Check,
     Bet,
     Call,
     Raise,
+    SetPreAction { pre_action: Option<PreAction> }, // act automatically at your next turn this street; None clears
 
     // Scheduling
     ScheduleGame { start_time: String }, // ISO 8601 timestamp
     CheckIn,
+    CancelSchedule, // dealer/host drops the scheduled start
 
     // Chat with different scopes
     Chat { message: String, scope: MessageScope },
@@ -384,11 +597,26 @@ pub enum ClientToServer {
     // Spectator mode - join as observer only
     JoinAsSpectator { room: String, name: String },
     LeaveSpectator,
+    TakeSeat, // spectator asks for an open seat between hands
+    RequestSeat, // spectator queues for the next open seat, taken automatically
+    Rebuy,    // top chips back up to the starting stack between hands
 
     // Dealer system
     ElectToStart,
     DelegateDealer { player_id: Uuid },
     ChooseGameVariant { variant: GameVariant },
+    SetDealersChoice { enabled: bool },
+    // Dealer/host changes the stakes between hands
+    UpdateTableConfig {
+        ante: u64,
+        limit_small: u64,
+        limit_big: u64,
+        max_raises: u32,
+        #[serde(default)]
+        max_buyin: Option<u64>, // most a player can buy in or rebuy to; None = no ceiling
+        #[serde(default)]
+        max_draw_rounds: Option<u32>, // 7/27: showdown after this many rounds; None = until everyone stands
+    },
 
     // Table creation
     CreateTable {
@@ -398,6 +626,26 @@ pub enum ClientToServer {
         limit_small: u64,
         limit_big: u64,
         max_raises: u32,
+        #[serde(default)]
+        min_players: Option<usize>, // players needed to deal; None = server default
+        #[serde(default)]
+        rake_percent: u64, // house cut taken from each pot, 0..=10
+        #[serde(default)]
+        rake_cap: Option<u64>, // most a single pot is raked; None = uncapped
+        #[serde(default)]
+        max_buyin: Option<u64>, // most a player can buy in or rebuy to; None = no ceiling
+        #[serde(default)]
+        deck_count: Option<usize>, // decks shuffled into the shoe; None = one
+        #[serde(default)]
+        spectator_chat_to_players: Option<bool>, // Some(false) keeps spectators' table chat among themselves
+        #[serde(default)]
+        muck_window_secs: Option<u64>, // seconds losers get to muck before hands are shown; None or 0 = reveal at once
+        #[serde(default)]
+        max_draw_rounds: Option<u32>, // 7/27: showdown after this many rounds; None = until everyone stands
+        #[serde(default)]
+        auto_dealer: bool, // once everyone elects, the next dealer is picked without a DelegateDealer step
+        #[serde(default)]
+        rabbit_hunt: bool, // community games: players may ask to see the undealt board after an early finish
     },
     // Register a distributed table with the central server
     RegisterTable {
@@ -410,22 +658,38 @@ pub enum ClientToServer {
         server_port: u16,
         player_count: usize,
     },
+    // A distributed table was closed by its host
+    UnregisterTable { name: String },
 
     // Comments phase
-    PostComment { message: String },
+    PostComment {
+        message: String,
+        #[serde(default)]
+        about: Option<CommentTarget>, // a round or play from the hand just finished
+    },
     ContinueToNextGame,
+    Rematch, // dealer re-deals immediately with the same players
+    CloseTable, // host ends the table between hands; everyone is sent back to the lounge
+    KickPlayer { player_id: Uuid }, // host removes a seated player between hands
 
     // Lounge system
     JoinLounge { name: String },
     LeaveLounge,
     VolunteerToHost { port: u16 },
     SelectHost { host_name: String, port: u16 },
+    FetchChatHistory { limit: usize }, // most recent Group and Global messages
+    ListLoungePlayers, // answered with `LoungePlayers`
+
+    // Latency probe; the server echoes the nonce back in `Pong`
+    Ping { nonce: u64 },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ServerToClient {
     Hello {
         your_id: Uuid,
+        #[serde(default)]
+        protocol_version: u32, // 0 = server from before the handshake
     },
     Joined {
         snapshot: PublicRoom,
@@ -438,8 +702,14 @@ pub enum ServerToClient {
     YourHand {
         hand: PrivateHand,
     },
+    // Sent to the player whose turn it is, after each state update
+    YourTurn {
+        options: TurnOptions,
+    },
     Error {
         message: String,
+        #[serde(default)]
+        code: ErrorCode,
     },
     Info {
         message: String,
@@ -449,6 +719,13 @@ pub enum ServerToClient {
         winners27: Vec<Uuid>,
         payouts: Vec<(Uuid, u64)>,
         reveal: Vec<(Uuid, Vec<Card>)>,
+        #[serde(default)]
+        actions: Vec<ActionEvent>, // everything that happened in the hand, in order
+    },
+    CardsShown {
+        player_id: Uuid,
+        player_name: String,
+        cards: Vec<Card>,
     },
     ChatMessage {
         player_name: String,
@@ -458,6 +735,9 @@ pub enum ServerToClient {
         timestamp: String,
         recipient: Option<Uuid>, // For private messages
     },
+    ChatHistory {
+        messages: Vec<StoredMessage>, // oldest first
+    },
     TableList {
         tables: Vec<TableInfo>,
     },
@@ -482,12 +762,71 @@ pub enum ServerToClient {
         available_hosts: Vec<(String, u16)>, // (player_name, port)
         player_selections: Vec<(String, Option<String>)>, // (player_name, selected_host_name)
     },
+    // Everyone on this server, with where they are
+    LoungePlayers {
+        players: Vec<LoungePlayer>,
+    },
 
     // Game start signal
     StartGame {
         host_name: String,
         port: u16,
     },
+
+    // Answer to `ClientToServer::Ping`
+    Pong {
+        nonce: u64,
+    },
+    // Sent to a player or spectator just before they're taken out of a
+    // room (left, kicked, table closed), so they can head back to the lounge
+    Removed {
+        reason: String,
+    },
+}
+
+/// A move queued ahead of your turn. The server plays it once, on your
+/// next turn in the current betting round, and drops it when the round ends.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum PreAction {
+    CheckFold, // check if nobody has bet, otherwise fold
+}
+
+/// ---- Hand history ----
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ActionKind {
+    TakeCard,
+    Stand,
+    Fold,
+    Check,
+    Bet,
+    Call,
+    Raise,
+}
+
+/// One player action within a hand. `amount` is the chips that action put
+/// into the pot (0 for draws, checks and folds).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ActionEvent {
+    pub round: u32,
+    pub player_id: Uuid,
+    pub player_name: String,
+    pub kind: ActionKind,
+    pub amount: u64,
+}
+
+impl fmt::Display for ActionEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = &self.player_name;
+        match self.kind {
+            ActionKind::TakeCard => write!(f, "{} takes a card", name),
+            ActionKind::Stand => write!(f, "{} stands", name),
+            ActionKind::Fold => write!(f, "{} folds", name),
+            ActionKind::Check => write!(f, "{} checks", name),
+            ActionKind::Bet => write!(f, "{} bets {}", name, self.amount),
+            ActionKind::Call => write!(f, "{} calls {}", name, self.amount),
+            ActionKind::Raise => write!(f, "{} raises {}", name, self.amount),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -506,4 +845,141 @@ pub struct GameComment {
     pub player_name: String,
     pub message: String,
     pub timestamp: String,
+    #[serde(default)]
+    pub about: Option<CommentTarget>, // None = about the game in general
+}
+
+/// What a post-hand comment refers to: a whole round, or one action from
+/// the hand's timeline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CommentTarget {
+    Round(u32),
+    Action(ActionEvent),
+}
+
+impl CommentTarget {
+    /// Short description for grouping comments, e.g. "about the River" or
+    /// "about John's River bet".
+    pub fn label(&self, variant: GameVariant) -> String {
+        let community = variant.uses_community_cards();
+        match self {
+            CommentTarget::Round(round) if community => format!("about the {}", variant.round_label(*round, true)),
+            CommentTarget::Round(round) => format!("about round {}", round),
+            CommentTarget::Action(a) => {
+                let play = match a.kind {
+                    ActionKind::TakeCard => "draw",
+                    ActionKind::Stand => "stand",
+                    ActionKind::Fold => "fold",
+                    ActionKind::Check => "check",
+                    ActionKind::Bet => "bet",
+                    ActionKind::Call => "call",
+                    ActionKind::Raise => "raise",
+                };
+                if community {
+                    format!("about {}'s {} {}", a.player_name, variant.round_label(a.round, true), play)
+                } else {
+                    format!("about {}'s {} in round {}", a.player_name, play, a.round)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variant_configs() {
+        let seven27 = GameVariant::SevenTwentySeven;
+        assert_eq!((seven27.hole_cards(), seven27.community_cards(), seven27.max_cards_per_player()), (2, 0, 7));
+        assert!(!seven27.uses_community_cards());
+        assert!(seven27.config().hi_lo);
+        assert_eq!(seven27.config().betting_rounds, None);
+
+        let omaha = GameVariant::Omaha;
+        assert_eq!((omaha.hole_cards(), omaha.community_cards(), omaha.max_cards_per_player()), (4, 5, 4));
+        assert!(omaha.uses_community_cards());
+        assert!(!omaha.config().hi_lo);
+
+        let holdem = GameVariant::TexasHoldem;
+        assert_eq!((holdem.hole_cards(), holdem.community_cards(), holdem.max_cards_per_player()), (2, 5, 2));
+        assert!(holdem.uses_community_cards());
+        assert_eq!(holdem.config().betting_rounds, Some(3));
+    }
+
+    #[test]
+    fn test_round_labels() {
+        let holdem = GameVariant::TexasHoldem;
+        let labels: Vec<String> = (0..=4).map(|r| holdem.round_label(r, true)).collect();
+        assert_eq!(labels, ["Preflop", "Flop", "Turn", "River", "Showdown"]);
+        assert_eq!(GameVariant::Omaha.round_label(2, true), "Turn");
+
+        let seven27 = GameVariant::SevenTwentySeven;
+        assert_eq!(seven27.round_label(0, false), "Deal");
+        assert_eq!(seven27.round_label(1, false), "Draw 1");
+        assert_eq!(seven27.round_label(1, true), "Bet 1");
+        assert_eq!(seven27.round_label(3, false), "Draw 3");
+    }
+
+    #[test]
+    fn test_comment_target_labels() {
+        let bet = ActionEvent {
+            round: 3,
+            player_id: Uuid::nil(),
+            player_name: "John".to_string(),
+            kind: ActionKind::Bet,
+            amount: 20,
+        };
+        let on_river = CommentTarget::Action(bet);
+        assert_eq!(on_river.label(GameVariant::TexasHoldem), "about John's River bet");
+        assert_eq!(on_river.label(GameVariant::SevenTwentySeven), "about John's bet in round 3");
+        assert_eq!(CommentTarget::Round(1).label(GameVariant::Omaha), "about the Flop");
+        assert_eq!(CommentTarget::Round(2).label(GameVariant::SevenTwentySeven), "about round 2");
+
+        // Comments from older peers have no target
+        let old: GameComment = serde_json::from_value(serde_json::json!({
+            "player_id": Uuid::nil(), "player_name": "Ann", "message": "gg", "timestamp": "t",
+        }))
+        .unwrap();
+        assert_eq!(old.about, None);
+    }
+
+    #[test]
+    fn test_multi_deck_shoe() {
+        assert_eq!(Deck::standard_shuffled().cards.len(), 52);
+        let shoe = Deck::standard_shuffled_n(2);
+        assert_eq!(shoe.cards.len(), 104);
+        let aces = shoe.cards.iter().filter(|c| c.rank == Rank::Ace && c.suit == Suit::Spades).count();
+        assert_eq!(aces, 2);
+    }
+
+    #[test]
+    fn test_pot_halves() {
+        let room: PublicRoom = serde_json::from_value(serde_json::json!({
+            "room": "r", "game_variant": "SevenTwentySeven", "dealer_seat": 0, "to_act_seat": 0,
+            "pot": 75, "ante": 10, "phase": "Acting", "in_betting": false, "current_bet": 0,
+            "raises_made": 0, "max_raises": 3, "round": 1, "limit_small": 10, "limit_big": 20,
+            "players": [], "split_pot": true, "community_cards": [], "scheduled_start": null,
+            "checked_in_players": [], "elected_players": [], "current_dealer_id": null,
+            "available_variants": []
+        }))
+        .unwrap();
+        assert_eq!(room.pot_halves(), Some((37, 38)));
+
+        let single = PublicRoom { split_pot: false, ..room };
+        assert_eq!(single.pot_halves(), None);
+    }
+
+    #[test]
+    fn test_card_ordering() {
+        let c = |rank, suit| Card { rank, suit, face_up: false };
+        assert!(c(Rank::Two, Suit::Spades) < c(Rank::Three, Suit::Clubs));
+        assert!(c(Rank::King, Suit::Hearts) < c(Rank::Ace, Suit::Clubs));
+        assert!(c(Rank::Nine, Suit::Clubs) < c(Rank::Nine, Suit::Spades));
+
+        let mut hand = vec![c(Rank::Ace, Suit::Hearts), c(Rank::Four, Suit::Spades), c(Rank::Four, Suit::Diamonds)];
+        hand.sort();
+        assert_eq!(hand, vec![c(Rank::Four, Suit::Diamonds), c(Rank::Four, Suit::Spades), c(Rank::Ace, Suit::Hearts)]);
+    }
 }
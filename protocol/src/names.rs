@@ -0,0 +1,171 @@
+//! Player and table name rules shared by the client and server, plus the
+//! looser rules for a seat's status note.
+//!
+//! Names may use any Unicode letters, numbers and emoji plus spaces, `_`,
+//! `-`, `'` and `.`. Runs of spaces collapse to one and length is counted
+//! in graphemes, so an emoji with a skin tone counts as one character.
+//! Invisible characters are refused, except the joiners and selectors that
+//! hold an emoji sequence together.
+
+use unicode_properties::{GeneralCategoryGroup, UnicodeEmoji, UnicodeGeneralCategory};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Shortest allowed name, in graphemes.
+pub const NAME_MIN: usize = 2;
+/// Longest allowed player name, in graphemes.
+pub const NAME_MAX: usize = 20;
+/// Longest allowed seat status ("BRB", "back in 5"), in graphemes.
+pub const STATUS_MAX: usize = 20;
+
+/// Trim and collapse whitespace, then check the name is between
+/// [`NAME_MIN`] and `max` graphemes of allowed characters. Returns the
+/// cleaned name or a message to show the user.
+pub fn clean_name(raw: &str, max: usize) -> Result<String, String> {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return Err("Please enter a name".to_string());
+    }
+    // Checked before collapsing so tabs and newlines aren't silently eaten
+    if trimmed.chars().any(|c| c.is_control() || is_bidi_control(c)) {
+        return Err("Name can't contain control characters".to_string());
+    }
+    if !trimmed.graphemes(true).all(is_name_grapheme) {
+        return Err("Name can only contain letters, numbers, emoji, spaces, _ - ' and .".to_string());
+    }
+
+    let name = trimmed.split_whitespace().collect::<Vec<_>>().join(" ");
+    let length = name.graphemes(true).count();
+    if length < NAME_MIN {
+        return Err(format!("Name must be at least {} characters", NAME_MIN));
+    }
+    if length > max {
+        return Err(format!("Name must be {} characters or less", max));
+    }
+    Ok(name)
+}
+
+/// Trim and collapse whitespace in a seat status. Any printable text is
+/// fine; an empty status means clear it.
+pub fn clean_status(raw: &str) -> Result<Option<String>, String> {
+    if raw.chars().any(|c| c.is_control() || is_bidi_control(c)) {
+        return Err("Status can't contain control characters".to_string());
+    }
+    let status = raw.split_whitespace().collect::<Vec<_>>().join(" ");
+    if status.graphemes(true).count() > STATUS_MAX {
+        return Err(format!("Status must be {} characters or less", STATUS_MAX));
+    }
+    Ok((!status.is_empty()).then_some(status))
+}
+
+/// An emoji sequence (skin tones, ZWJ families, flags, keycaps) is taken
+/// whole; anything else is checked a character at a time.
+fn is_name_grapheme(g: &str) -> bool {
+    let first = g.chars().next().unwrap_or(' ');
+    let emoji = first.is_emoji_char() && (!first.is_ascii() || g.ends_with('\u{20E3}'));
+    if emoji {
+        // A joiner has to join something
+        g.chars().all(|c| c.is_emoji_char_or_emoji_component()) && !g.ends_with('\u{200D}')
+    } else {
+        g.chars().all(is_name_char)
+    }
+}
+
+/// ASCII is limited to letters, digits and a little punctuation. Beyond
+/// ASCII, letters and digits of any script are allowed along with the
+/// combining marks that accent them, but not invisible characters.
+fn is_name_char(c: char) -> bool {
+    if c.is_ascii() {
+        c.is_ascii_alphanumeric() || matches!(c, ' ' | '_' | '-' | '\'' | '.')
+    } else if is_invisible(c) {
+        false
+    } else {
+        c.is_alphanumeric() || c.general_category_group() == GeneralCategoryGroup::Mark
+    }
+}
+
+/// Marks and letters that draw nothing: variation selectors (only meaningful
+/// inside an emoji), the combining grapheme joiner and the Hangul fillers.
+fn is_invisible(c: char) -> bool {
+    matches!(c, '\u{FE00}'..='\u{FE0F}' | '\u{E0100}'..='\u{E01EF}' | '\u{034F}'
+        | '\u{115F}' | '\u{1160}' | '\u{3164}' | '\u{FFA0}')
+}
+
+/// Invisible characters that reorder the text around them.
+fn is_bidi_control(c: char) -> bool {
+    matches!(c, '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}' | '\u{200E}' | '\u{200F}' | '\u{061C}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_emoji_name_counts_graphemes() {
+        // 20 emoji, several made of multiple code points
+        let name = "👍🏽👨‍👩‍👧😀🎲🃏♠️♥️♦️♣️🍀🎉🔥💎🌟🚀🐉🦊🐼🐙🎯";
+        assert_eq!(name.graphemes(true).count(), 20);
+        assert!(name.len() > 20);
+        assert_eq!(clean_name(name, NAME_MAX).as_deref(), Ok(name));
+
+        let too_long = format!("{}🎯", name);
+        assert_eq!(clean_name(&too_long, NAME_MAX), Err("Name must be 20 characters or less".to_string()));
+    }
+
+    #[test]
+    fn test_control_characters_rejected() {
+        assert!(clean_name("Bob\u{7}", NAME_MAX).is_err());
+        assert!(clean_name("Al\tice", NAME_MAX).is_err());
+        assert!(clean_name("Eve\u{202E}gnp", NAME_MAX).is_err());
+        assert!(clean_name("<script>", NAME_MAX).is_err());
+    }
+
+    #[test]
+    fn test_invisible_characters_rejected() {
+        for hidden in ['\u{200B}', '\u{200D}', '\u{2060}', '\u{FEFF}', '\u{00AD}', '\u{FE0F}', '\u{3164}'] {
+            assert!(clean_name(&format!("Bo{}b", hidden), NAME_MAX).is_err(), "{:?}", hidden);
+            assert!(clean_name(&format!("Bob{}", hidden), NAME_MAX).is_err(), "{:?}", hidden);
+        }
+        // Two names that would look identical on screen
+        assert!(clean_name("Alice\u{200B}", NAME_MAX).is_err());
+        // A joiner dangling off an emoji joins nothing
+        assert!(clean_name("Al😀\u{200D}", NAME_MAX).is_err());
+        assert!(clean_name("😀\u{200D}x", NAME_MAX).is_err());
+    }
+
+    #[test]
+    fn test_emoji_sequences_allowed() {
+        // ZWJ family, skin tone, presentation selector, flag, subdivision flag, keycap
+        for name in ["Dad 👨‍👩‍👧", "Ann 👍🏽", "Hearts ♥️", "Eh 🇨🇦", "Gaz 🏴󠁧󠁢󠁥󠁮󠁧󠁿", "Lucky 7️⃣"] {
+            assert_eq!(clean_name(name, NAME_MAX).as_deref(), Ok(name));
+        }
+        // Letters in other scripts keep their marks
+        assert!(clean_name("नमस्ते", NAME_MAX).is_ok());
+        assert!(clean_name("Ünal", NAME_MAX).is_ok());
+    }
+
+    #[test]
+    fn test_names_are_trimmed_and_collapsed() {
+        assert_eq!(clean_name("  José   María ", NAME_MAX).as_deref(), Ok("José María"));
+        assert_eq!(clean_name("Zoë", NAME_MAX).as_deref(), Ok("Zoë"));
+        assert_eq!(clean_name("李", NAME_MAX), Err("Name must be at least 2 characters".to_string()));
+        assert_eq!(clean_name("   ", NAME_MAX), Err("Please enter a name".to_string()));
+    }
+
+    #[test]
+    fn test_length_counts_characters_not_bytes() {
+        // 20 accented letters: 40 bytes, still within the limit
+        let accented = "é".repeat(20);
+        assert_eq!(accented.len(), 40);
+        assert!(clean_name(&accented, NAME_MAX).is_ok());
+        assert!(clean_name(&"é".repeat(21), NAME_MAX).is_err());
+
+        // Decomposed e + combining acute counts once, like the precomposed form
+        assert!(clean_name(&"e\u{301}".repeat(20), NAME_MAX).is_ok());
+
+        // CJK: three bytes each
+        assert!(clean_name(&"漢".repeat(20), NAME_MAX).is_ok());
+        assert!(clean_name(&"漢".repeat(21), NAME_MAX).is_err());
+        assert!(clean_name("王明", NAME_MAX).is_ok());
+        assert!(clean_name("José", NAME_MAX).is_ok());
+    }
+}
@@ -1,5 +1,6 @@
 use cctmog_protocol::*;
 use uuid::Uuid;
+use std::collections::HashMap;
 
 #[derive(Debug)]
 pub struct Room {
@@ -15,11 +16,19 @@ pub struct Room {
     pub dealer_seat: usize,
     pub to_act_seat: usize,
     pub pot: u64,
+    // Chips each player has put in this hand, antes included; splits the
+    // pot into side pots when someone is all-in
+    pub contributed: HashMap<Uuid, u64>,
     pub phase: Phase,
 
     // round & sub-phase
     pub round: u32,
     pub in_betting: bool,
+    // 7/27 house rule: showdown after this many betting rounds even if
+    // players are still drawing. None = play until everyone stands.
+    pub max_draw_rounds: Option<u32>,
+    // Players needed before a hand can be dealt
+    pub min_players: usize,
 
     // draw-phase tracking
     pub draw_started_seat: usize,
@@ -29,6 +38,8 @@ pub struct Room {
     pub betting_started_seat: usize,
     pub last_aggressor_seat: Option<usize>,
     pub current_bet: u64,
+    // raises on the current street, not counting the opening bet:
+    // each street allows one bet plus `max_raises` raises
     pub raises_made: u32,
     pub betting_acted: Vec<bool>,
 
@@ -36,13 +47,117 @@ pub struct Room {
     pub community_cards: Vec<Card>,
     pub scheduled_start: Option<String>,
     pub checked_in_players: Vec<Uuid>,
+    // Seated but not dealt in this hand (left out by a dealer's force-start)
+    pub sitting_out: Vec<Uuid>,
+    // Lost their connection mid-hand: folded and sat out, then unseated
+    // once the hand is over
+    pub departed: Vec<Uuid>,
+    // Folded players who chose to show their cards this hand
+    pub shown_cards: Vec<Uuid>,
 
     // Spectator tracking: list of spectators (non-playing observers)
     pub spectators: Vec<Spectator>,
+    // Spectators waiting for a seat, in request order
+    pub seat_queue: Vec<Uuid>,
 
     // Dealer system tracking
     pub elected_players: Vec<Uuid>,
     pub current_dealer_id: Option<Uuid>,
+
+    // Ordered log of player actions in the current hand
+    pub actions: Vec<ActionEvent>,
+    // When the current to_act turn began (ISO 8601), None outside of a hand
+    pub turn_started_at: Option<String>,
+
+    // Dealer's choice: each new dealer picks the next variant within a time window
+    pub dealers_choice: bool,
+    pub variant_choice_deadline: Option<std::time::Instant>,
+    // Skip the manual DelegateDealer step: once everyone elects to start the
+    // dealer rotates from the last one, or is the first player to elect
+    pub auto_dealer: bool,
+
+    // Comments phase: after this long the table moves on without players who
+    // haven't pressed continue. None waits for everyone.
+    pub comments_timeout: Option<std::time::Duration>,
+    pub comments_deadline: Option<std::time::Instant>,
+    pub comments_warned: bool,
+
+    // House rule: losers at showdown get this long to muck before hands are
+    // turned over. None reveals straight away.
+    pub muck_window: Option<std::time::Duration>,
+    pub pending_showdown: Option<PendingShowdown>,
+    pub mucked: Vec<Uuid>,
+
+    // Moves queued for a player's next turn in the current betting round
+    pub pre_actions: HashMap<Uuid, PreAction>,
+
+    // House rule: when a community hand ends before the river, players may
+    // ask to see the rest of the board. The cards that would have come are
+    // held until the deadline or the next deal.
+    pub rabbit_hunt: bool,
+    pub rabbit_board: Option<(Vec<Card>, std::time::Instant)>,
+
+    // House cut: rake_percent of each pot, at most rake_cap, taken before
+    // payouts. raked_total is shown to players.
+    pub rake_percent: u64,
+    pub rake_cap: Option<u64>,
+    pub raked_total: u64,
+
+    // Ceiling on voluntary buy-ins (joining, rebuys). Winnings can take a
+    // stack past it. None = no ceiling.
+    pub max_buyin: Option<u64>,
+
+    // Standard decks shuffled into each hand's shoe (see Deck::standard_shuffled_n)
+    pub deck_count: usize,
+
+    // Whether spectators' table chat reaches the seated players; when off
+    // it only goes to the other spectators
+    pub spectator_chat_to_players: bool,
+
+    // Running totals for /metrics; hand_started_at is set while a hand is on
+    pub stats: RoomStats,
+    pub hand_started_at: Option<std::time::Instant>,
+}
+
+/// Hands finished at a table, with their total time and pots, for the
+/// averages reported on `/metrics`.
+#[derive(Debug, Default, Clone)]
+pub struct RoomStats {
+    pub hands_played: u64,
+    pub total_duration: std::time::Duration,
+    pub total_pot: u64,
+}
+
+impl RoomStats {
+    pub fn record_hand(&mut self, duration: std::time::Duration, pot: u64) {
+        self.hands_played += 1;
+        self.total_duration += duration;
+        self.total_pot += pot;
+    }
+
+    pub fn avg_duration_secs(&self) -> f64 {
+        if self.hands_played == 0 {
+            return 0.0;
+        }
+        self.total_duration.as_secs_f64() / self.hands_played as f64
+    }
+
+    pub fn avg_pot(&self) -> f64 {
+        if self.hands_played == 0 {
+            return 0.0;
+        }
+        self.total_pot as f64 / self.hands_played as f64
+    }
+}
+
+/// Showdown result held back while losing players decide whether to muck.
+/// The pot is already paid; only the reveal waits.
+#[derive(Debug)]
+pub struct PendingShowdown {
+    pub winners7: Vec<Uuid>,
+    pub winners27: Vec<Uuid>,
+    pub payouts: Vec<(Uuid, u64)>, // chips awarded, sent with the reveal
+    pub deadline: std::time::Instant,
 }
 
 #[derive(Debug)]
@@ -63,6 +178,7 @@ pub struct PlayerSeat {
     pub down_cards: Vec<Card>,
     pub ready: bool,
     pub committed_round: u64,
+    pub status: Option<String>, // short note shown on the seat ("BRB"), see SetStatus
     pub tx: tokio::sync::mpsc::UnboundedSender<ServerToClient>,
 }
 
@@ -80,9 +196,12 @@ impl Room {
             dealer_seat: 0,
             to_act_seat: 0,
             pot: 0,
+            contributed: HashMap::new(),
             phase: Phase::Lobby,
             round: 0,
             in_betting: false,
+            max_draw_rounds: None,
+            min_players: 2,
             draw_started_seat: 0,
             draw_acted: vec![],
             betting_started_seat: 0,
@@ -93,9 +212,35 @@ impl Room {
             community_cards: vec![],
             scheduled_start: None,
             checked_in_players: vec![],
+            sitting_out: vec![],
+            departed: vec![],
+            shown_cards: vec![],
             spectators: vec![],
+            seat_queue: vec![],
             elected_players: vec![],
             current_dealer_id: None,
+            actions: vec![],
+            turn_started_at: None,
+            dealers_choice: false,
+            auto_dealer: false,
+            comments_timeout: Some(std::time::Duration::from_secs(90)),
+            comments_deadline: None,
+            comments_warned: false,
+            variant_choice_deadline: None,
+            muck_window: None,
+            pending_showdown: None,
+            mucked: vec![],
+            pre_actions: HashMap::new(),
+            stats: RoomStats::default(),
+            hand_started_at: None,
+            rabbit_hunt: false,
+            rabbit_board: None,
+            rake_percent: 0,
+            rake_cap: None,
+            raked_total: 0,
+            max_buyin: None,
+            deck_count: 1,
+            spectator_chat_to_players: true,
         }
     }
 }
@@ -111,6 +256,36 @@ pub fn all_cards(p: &PlayerSeat) -> Vec<Card> {
     v
 }
 
+/// The shared board players can see. Always empty for variants without
+/// community cards, whatever was left over from an earlier game.
+pub fn board(r: &Room) -> &[Card] {
+    if r.game_variant.uses_community_cards() {
+        &r.community_cards
+    } else {
+        &[]
+    }
+}
+
+/// Cards in play more often than the shoe holds them (once per deck), by
+/// rank and suit, among hands and the board. Empty when the deal is sound.
+pub fn duplicate_cards_in_play(r: &Room) -> Vec<(Rank, Suit)> {
+    let mut seen = std::collections::BTreeMap::new();
+    let mut dups = vec![];
+    let in_play = r
+        .players
+        .iter()
+        .flat_map(|p| p.up_cards.iter().chain(p.down_cards.iter()))
+        .chain(r.community_cards.iter());
+    for c in in_play {
+        let count = seen.entry((c.rank, c.suit)).or_insert(0);
+        *count += 1;
+        if *count > r.deck_count.max(1) && !dups.contains(&(c.rank, c.suit)) {
+            dups.push((c.rank, c.suit));
+        }
+    }
+    dups
+}
+
 pub fn alive_seats(r: &Room) -> Vec<(usize, &PlayerSeat)> {
     r.players
         .iter()
@@ -136,15 +311,18 @@ pub fn bet_size_for_round(r: &Room) -> u64 {
     }
 }
 
-pub fn commit(r: &mut Room, seat: usize, amount: u64) {
+/// Move up to `amount` chips from the seat into the pot; returns what was actually paid.
+pub fn commit(r: &mut Room, seat: usize, amount: u64) -> u64 {
     if amount == 0 {
-        return;
+        return 0;
     }
     let p = &mut r.players[seat];
     let pay = amount.min(p.chips);
     p.chips -= pay;
     p.committed_round += pay;
+    *r.contributed.entry(p.id).or_default() += pay;
     r.pot += pay;
+    pay
 }
 
 /// Convert internal Room to public PublicRoom for client messages
@@ -162,14 +340,32 @@ pub fn public_room(r: &Room) -> PublicRoom {
         raises_made: r.raises_made,
         max_raises: r.max_raises,
         round: r.round,
+        round_label: r.game_variant.round_label(r.round, r.in_betting),
+        street: Street::for_board(r.game_variant, board(r).len()),
+        split_pot: r.game_variant.config().hi_lo,
         limit_small: r.limit_small,
         limit_big: r.limit_big,
-        community_cards: r.community_cards.clone(),
+        community_cards: board(r).to_vec(),
         scheduled_start: r.scheduled_start.clone(),
         checked_in_players: r.checked_in_players.clone(),
         elected_players: r.elected_players.clone(),
         current_dealer_id: r.current_dealer_id,
+        current_dealer_name: r
+            .current_dealer_id
+            .and_then(|id| r.players.iter().find(|p| p.id == id))
+            .map(|p| p.name.clone()),
+        elected_count: r.elected_players.len(),
+        rabbit_hunt_open: r.rabbit_board.is_some(),
+        raked_total: r.raked_total,
+        showdown_winners: r
+            .pending_showdown
+            .as_ref()
+            .map(|p| p.winners7.iter().chain(&p.winners27).copied().collect())
+            .unwrap_or_default(),
         available_variants: vec![GameVariant::SevenTwentySeven, GameVariant::Omaha, GameVariant::TexasHoldem],
+        dealers_choice: r.dealers_choice,
+        turn_started_at: r.turn_started_at.clone(),
+        spectators: r.spectators.iter().map(|s| PublicSpectator { id: s.id, name: s.name.clone() }).collect(),
         players: r
             .players
             .iter()
@@ -185,6 +381,8 @@ pub fn public_room(r: &Room) -> PublicRoom {
                 cards_count: p.up_cards.len() + p.down_cards.len(),
                 committed_round: p.committed_round,
                 ready: p.ready,
+                color_seed: PublicPlayer::color_seed_for(p.id),
+                status: p.status.clone(),
             })
             .collect(),
     }
@@ -215,34 +413,90 @@ pub fn can_take_card(r: &Room, player_id: Uuid) -> Result<usize, String> {
     Ok(seat)
 }
 
-#[allow(dead_code)]
-pub fn can_bet_or_raise(r: &Room, player_id: Uuid, is_raise: bool) -> Result<usize, String> {
+/// Common checks for any betting action: we're betting, it's this player's
+/// turn and they're still in the hand. Standing isn't checked on purpose: in
+/// 7/27 it only stops you drawing, and the last round is bet by players who
+/// have all stood.
+pub fn betting_turn(r: &Room, player_id: Uuid) -> Result<usize, (ErrorCode, String)> {
     if !r.in_betting || r.phase != Phase::Acting {
-        return Err("Not in betting phase".to_string());
+        return Err((ErrorCode::NotInBetting, "Not in betting phase".to_string()));
     }
 
-    let seat = seat_of(r, player_id).ok_or("Player not found")?;
+    let seat = seat_of(r, player_id).ok_or((ErrorCode::Other, "Player not found".to_string()))?;
 
     if r.to_act_seat != seat {
-        return Err(format!("Not your turn (to_act={} you={})", r.to_act_seat, seat));
+        return Err((ErrorCode::NotYourTurn, format!("Not your turn (to_act={} you={})", r.to_act_seat, seat)));
     }
     if r.players[seat].folded {
-        return Err("Player folded".to_string());
+        return Err((ErrorCode::AlreadyFolded, "Player folded".to_string()));
     }
+    Ok(seat)
+}
+
+pub fn can_bet_or_raise(r: &Room, player_id: Uuid, is_raise: bool) -> Result<usize, (ErrorCode, String)> {
+    let seat = betting_turn(r, player_id)?;
 
     if r.current_bet == 0 && is_raise {
-        return Err("Cannot raise when no bet exists".to_string());
+        return Err((ErrorCode::NoBetToRaise, "Cannot raise when no bet exists".to_string()));
     }
     if r.current_bet > 0 && !is_raise {
-        return Err("Must raise when bet exists".to_string());
+        return Err((ErrorCode::BetAlreadyOpen, "Must raise when bet exists".to_string()));
     }
     if is_raise && r.raises_made >= r.max_raises {
-        return Err(format!("Maximum raises ({}) reached", r.max_raises));
+        return Err((ErrorCode::RaiseCapReached, format!("Maximum raises ({}) reached", r.max_raises)));
     }
 
     Ok(seat)
 }
 
+pub fn can_check(r: &Room, player_id: Uuid) -> Result<usize, (ErrorCode, String)> {
+    let seat = betting_turn(r, player_id)?;
+    if r.current_bet != 0 {
+        return Err((ErrorCode::CheckFacingBet, "Cannot check facing a bet".to_string()));
+    }
+    Ok(seat)
+}
+
+pub fn can_call(r: &Room, player_id: Uuid) -> Result<usize, (ErrorCode, String)> {
+    let seat = betting_turn(r, player_id)?;
+    if r.current_bet == 0 {
+        return Err((ErrorCode::NoBetToCall, "Nothing to call".to_string()));
+    }
+    Ok(seat)
+}
+
+/// Actions available to the player in `seat` right now. Everything is off
+/// unless it's that player's turn in the acting phase.
+pub fn turn_options(r: &Room, seat: usize) -> TurnOptions {
+    let mut opts = TurnOptions::default();
+    let p = match r.players.get(seat) {
+        Some(p) => p,
+        None => return opts,
+    };
+    if r.phase != Phase::Acting || r.to_act_seat != seat || p.folded {
+        return opts;
+    }
+
+    opts.can_fold = true;
+    if r.in_betting {
+        let sz = bet_size_for_round(r);
+        let to_call = r.current_bet.saturating_sub(p.committed_round);
+        if r.current_bet == 0 {
+            opts.can_check = true;
+            opts.can_bet = (p.chips > 0).then_some(sz);
+        } else {
+            opts.can_check = to_call == 0;
+            opts.can_call = (to_call > 0).then_some(to_call.min(p.chips));
+            opts.can_raise = (r.raises_made < r.max_raises && p.chips > 0).then_some(sz);
+        }
+    } else if !p.standing {
+        let cards = p.up_cards.len() + p.down_cards.len();
+        opts.can_take_card = cards < r.game_variant.max_cards_per_player();
+        opts.can_stand = true;
+    }
+    opts
+}
+
 /// Helper function to find the next dealer after the current one rotates
 pub fn next_dealer_left_of(r: &Room, current_dealer_seat: usize) -> Option<Uuid> {
     if r.players.is_empty() {
@@ -377,6 +631,7 @@ mod tests {
             down_cards: vec![],
             ready: false,
             committed_round: 0,
+            status: None,
             tx: tokio::sync::mpsc::unbounded_channel().0,
         });
 
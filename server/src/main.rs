@@ -1,11 +1,12 @@
 use axum::{
     extract::{
         ws::{Message, WebSocket, WebSocketUpgrade},
-        State,
+        Path, State,
     },
+    http::StatusCode,
     response::IntoResponse,
     routing::get,
-    Router,
+    Json, Router,
 };
 use cctmog_protocol::*;
 use futures::{SinkExt, StreamExt};
@@ -27,6 +28,23 @@ use messages::MessageStore;
 const AUTO_START_WHEN_ALL_READY: bool = true; // start as soon as all ready?
 const DEALER_MUST_START: bool = false; // only dealer can press "Start hand"
 const MAX_PLAYERS: usize = 7; // maximum players per table
+const SUFFIX_DUPLICATE_NAMES: bool = true; // a second "Alice" at a table becomes "Alice (2)"; false rejects the join
+const ELECTED_DEALER_MIN_PLAYERS: usize = 4; // tables this big elect a dealer before dealing
+const REBUY_CHIPS: u64 = 1000; // a rebuy tops the player back up to a fresh seat's stack
+const DEALERS_CHOICE_WINDOW: std::time::Duration = std::time::Duration::from_secs(20); // time a new dealer has to pick the next game
+const SCHEDULE_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1); // how often scheduled starts are checked
+const MAX_FRAME_BYTES: usize = 64 * 1024; // default limit; text frames larger than this are rejected unparsed
+const MAX_FRAME_BYTES_ENV: &str = "CCTMOG_MAX_FRAME_BYTES"; // overrides MAX_FRAME_BYTES
+const MAX_DECODE_ERROR_CHARS: usize = 200; // parse error detail echoed back to the client
+const PING_INTERVAL: std::time::Duration = std::time::Duration::from_secs(15); // websocket keep-alive ping
+const PONG_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(45); // silence after which a socket counts as dead
+const COMMENTS_WARNING: std::time::Duration = std::time::Duration::from_secs(15); // heads-up before a stalled comments phase moves on
+const MAX_RAKE_PERCENT: u64 = 10; // highest house cut a table can be created with
+const MAX_DECKS: usize = 4; // most decks a table's shoe can be built from
+const MAX_MUCK_WINDOW_SECS: u64 = 60; // longest a table can hold a showdown for losers to muck
+const TABLE_NAME_MAX: usize = 40; // graphemes; room for "<player name>'s Table"
+const CHAT_HISTORY_MAX: usize = 100; // most messages one history fetch returns
+const RABBIT_HUNT_WINDOW: std::time::Duration = std::time::Duration::from_secs(20); // time to ask for the undealt board after an early finish
 
 #[derive(Clone)]
 struct LoungeState {
@@ -49,6 +67,25 @@ struct AppState {
     message_store: Arc<MessageStore>,
     distributed_tables: Arc<Mutex<HashMap<String, cctmog_protocol::TableInfo>>>,
     lounge: Arc<Mutex<LoungeState>>,
+    socket: SocketConfig,
+}
+
+/// Per-connection limits, split out so tests can shrink them.
+#[derive(Clone, Copy)]
+struct SocketConfig {
+    max_frame_bytes: usize,
+    ping_interval: std::time::Duration,
+    pong_timeout: std::time::Duration,
+}
+
+impl Default for SocketConfig {
+    fn default() -> Self {
+        SocketConfig {
+            max_frame_bytes: MAX_FRAME_BYTES,
+            ping_interval: PING_INTERVAL,
+            pong_timeout: PONG_TIMEOUT,
+        }
+    }
 }
 type Rooms = HashMap<String, game::Room>;
 
@@ -71,10 +108,16 @@ async fn main() {
         lounge: Arc::new(Mutex::new(LoungeState {
             players: HashMap::new(),
         })),
+        socket: SocketConfig {
+            max_frame_bytes: std::env::var(MAX_FRAME_BYTES_ENV)
+                .ok()
+                .and_then(|v| v.trim().parse().ok())
+                .unwrap_or(MAX_FRAME_BYTES),
+            ..SocketConfig::default()
+        },
     };
-    let app = Router::new()
-        .route("/ws", get(ws_handler))
-        .with_state(state.clone());
+    let app = app_router(state.clone());
+    tokio::spawn(run_schedule_clock(state.clone()));
 
     let addr = "0.0.0.0:9001";
     let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
@@ -82,8 +125,92 @@ async fn main() {
     axum::serve(listener, app).await.unwrap();
 }
 
+fn app_router(state: AppState) -> Router {
+    Router::new()
+        .route("/ws", get(ws_handler))
+        .route("/room/:name", get(room_snapshot_handler))
+        .route("/metrics", get(metrics_handler))
+        .with_state(state)
+}
+
 async fn ws_handler(ws: WebSocketUpgrade, State(state): State<AppState>) -> impl IntoResponse {
-    ws.on_upgrade(move |socket| handle_socket(socket, state))
+    // Frames over our own limit get an Error reply in handle_socket; the
+    // transport cap is a backstop so nothing enormous is ever buffered.
+    let transport_cap = state.socket.max_frame_bytes.saturating_mul(2);
+    ws.max_message_size(transport_cap)
+        .max_frame_size(transport_cap)
+        .on_upgrade(move |socket| handle_socket(socket, state))
+}
+
+/// Read-only JSON snapshot of a room, for browser/OBS overlays that don't run
+/// the client. `PublicRoom` only carries face-up cards, so hole cards never leak.
+async fn room_snapshot_handler(
+    Path(name): Path<String>,
+    State(state): State<AppState>,
+) -> Result<Json<PublicRoom>, StatusCode> {
+    let rooms = state.inner.lock();
+    rooms
+        .get(&name)
+        .map(|r| Json(game::public_room(r)))
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+/// Per-room hand counts, average hand length and average pot, in the
+/// Prometheus text format.
+async fn metrics_handler(State(state): State<AppState>) -> String {
+    metrics_text(&state.inner.lock())
+}
+
+fn metrics_text(rooms: &HashMap<String, Room>) -> String {
+    let mut names: Vec<&String> = rooms.keys().collect();
+    names.sort();
+    let mut out = format!("cctmog_rooms {}\n", rooms.len());
+    for name in names {
+        let stats = &rooms[name].stats;
+        let label = name.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n");
+        out += &format!("cctmog_room_hands_played{{room=\"{}\"}} {}\n", label, stats.hands_played);
+        out += &format!("cctmog_room_avg_hand_seconds{{room=\"{}\"}} {:.3}\n", label, stats.avg_duration_secs());
+        out += &format!("cctmog_room_avg_pot{{room=\"{}\"}} {:.2}\n", label, stats.avg_pot());
+    }
+    out
+}
+
+/// Drop a departing socket from whichever room or lounge it had joined.
+async fn disconnect(state: &AppState, joined_room: &Option<String>, my_id: Uuid) {
+    if let Some(room) = joined_room {
+        if room == "lounge" {
+            // Handle lounge disconnect
+            handle_leave_lounge(state.clone(), my_id).await;
+        } else {
+            // Handle match room disconnect; mid-hand the seat is kept until
+            // the hand is over
+            let deferred = state.inner.lock().get_mut(room).is_some_and(|r| defer_departure(r, my_id));
+            if !deferred {
+                remove_player(state, room, my_id, "Disconnected");
+            }
+            remove_spectator(state, room, my_id);
+        }
+    }
+}
+
+/// Parse a text frame into a command. Oversized frames are refused before
+/// parsing; parse failures carry the serde error, truncated so a hostile
+/// frame can't make the reply arbitrarily large.
+fn decode_command(text: &str, max_bytes: usize) -> Result<ClientToServer, (ErrorCode, String)> {
+    if text.len() > max_bytes {
+        return Err((
+            ErrorCode::FrameTooLarge,
+            format!("frame too large: {} bytes (max {})", text.len(), max_bytes),
+        ));
+    }
+    serde_json::from_str::<ClientToServer>(text).map_err(|e| {
+        let full = e.to_string();
+        let mut detail: String = full.chars().take(MAX_DECODE_ERROR_CHARS).collect();
+        if detail.len() < full.len() {
+            detail.push('…');
+        }
+        (ErrorCode::MalformedCommand, format!("bad json: {}", detail))
+    })
 }
 
 async fn handle_socket(socket: WebSocket, state: AppState) {
@@ -91,42 +218,68 @@ async fn handle_socket(socket: WebSocket, state: AppState) {
 
     let (tx_out, mut rx_out) = tokio::sync::mpsc::unbounded_channel::<ServerToClient>();
 
+    let config = state.socket;
     tokio::spawn(async move {
-        while let Some(msg) = rx_out.recv().await {
-            let text = serde_json::to_string(&msg).unwrap();
-            if sender.send(Message::Text(text)).await.is_err() {
+        let start = tokio::time::Instant::now() + config.ping_interval;
+        let mut ping = tokio::time::interval_at(start, config.ping_interval);
+        loop {
+            let outgoing = tokio::select! {
+                msg = rx_out.recv() => match msg {
+                    Some(msg) => Message::Text(serde_json::to_string(&msg).unwrap()),
+                    None => break,
+                },
+                _ = ping.tick() => Message::Ping(vec![]),
+            };
+            if sender.send(outgoing).await.is_err() {
                 break;
             }
         }
     });
 
     let my_id = uuid::Uuid::new_v4();
-    let _ = tx_out.send(ServerToClient::Hello { your_id: my_id });
+    let _ = tx_out.send(ServerToClient::Hello { your_id: my_id, protocol_version: PROTOCOL_VERSION });
 
     let mut joined_room: Option<String> = None;
 
-    while let Some(Ok(msg)) = receiver.next().await {
+    // Any frame (pongs included) proves the peer is alive. A connection that
+    // drops without a close frame would otherwise leave a zombie seat.
+    let mut last_seen = tokio::time::Instant::now();
+    let mut liveness = tokio::time::interval(config.ping_interval);
+    loop {
+        let msg = tokio::select! {
+            msg = receiver.next() => msg,
+            _ = liveness.tick() => {
+                if last_seen.elapsed() > config.pong_timeout {
+                    eprintln!("[WS] {} silent for {:?}, dropping", my_id, last_seen.elapsed());
+                    disconnect(&state, &joined_room, my_id).await;
+                    break;
+                }
+                continue;
+            }
+        };
+        let Some(Ok(msg)) = msg else {
+            disconnect(&state, &joined_room, my_id).await;
+            break;
+        };
+        last_seen = tokio::time::Instant::now();
+
         match msg {
             Message::Text(t) => {
-                if let Ok(cmd) = serde_json::from_str::<ClientToServer>(&t) {
-                    route_cmd(cmd, &state, &mut joined_room, my_id, &tx_out).await;
-                } else {
-                    let _ = tx_out.send(ServerToClient::Error {
-                        message: "bad json".into(),
-                    });
+                match decode_command(&t, config.max_frame_bytes) {
+                    Ok(cmd) => route_cmd(cmd, &state, &mut joined_room, my_id, &tx_out).await,
+                    Err((code, message)) => {
+                        eprintln!("[WS] Rejected frame from {}: {}", my_id, message);
+                        let _ = tx_out.send(ServerToClient::Error { message, code });
+                        if code == ErrorCode::FrameTooLarge {
+                            // Don't keep talking to a client that floods us
+                            disconnect(&state, &joined_room, my_id).await;
+                            break;
+                        }
+                    }
                 }
             }
             Message::Close(_) => {
-                if let Some(room) = &joined_room {
-                    if room == "lounge" {
-                        // Handle lounge disconnect
-                        handle_leave_lounge(state.clone(), my_id).await;
-                    } else {
-                        // Handle match room disconnect
-                        remove_player(&state, room, my_id);
-                        remove_spectator(&state, room, my_id);
-                    }
-                }
+                disconnect(&state, &joined_room, my_id).await;
                 break;
             }
             _ => {}
@@ -141,6 +294,12 @@ async fn route_cmd(
     my_id: Uuid,
     tx_out: &mpsc::UnboundedSender<ServerToClient>,
 ) {
+    // Latency probes arrive every few seconds; answer them without logging.
+    if let ClientToServer::Ping { nonce } = cmd {
+        let _ = tx_out.send(ServerToClient::Pong { nonce });
+        return;
+    }
+
     // --- DEBUG PRINT ---
     eprintln!("[WS] from {} → {:?}", &my_id.to_string()[..8], cmd);
 
@@ -161,9 +320,17 @@ async fn route_cmd(
         }
 
         ClientToServer::Join { room, name } => {
+            let Some(name) = checked_player_name(&name, tx_out) else {
+                return;
+            };
+            leave_lounge_for_table(state, joined_room, my_id).await;
             let mut rooms = state.inner.lock();
             let r = rooms.entry(room.clone()).or_insert_with(|| game::Room::new(room.clone()));
 
+            let Some(name) = room_name_for(r, my_id, &name, tx_out) else {
+                return;
+            };
+
             // Check if table is at maximum capacity - if so, join as spectator
             if r.players.len() >= MAX_PLAYERS {
                 eprintln!("[SPECTATOR_AUTO] {} auto-joining as spectator (table full)", name);
@@ -202,8 +369,10 @@ async fn route_cmd(
                 down_cards: vec![],
                 ready: false,
                 committed_round: 0,
+                status: None,
                 tx: tx_out.clone(),
             });
+            clamp_to_max_buyin(r, seat);
             *joined_room = Some(room.clone());
             log_room("JOIN", r);
             broadcast_state(r);
@@ -217,7 +386,7 @@ async fn route_cmd(
         }
         ClientToServer::Leave => {
             if let Some(room) = joined_room {
-                remove_player(state, room, my_id);
+                remove_player(state, room, my_id, "You left the table");
             }
         }
         ClientToServer::SitReady => {
@@ -241,7 +410,7 @@ async fn route_cmd(
 
                     if AUTO_START_WHEN_ALL_READY
                         && r.phase == Phase::Lobby
-                        && r.players.len() >= 2
+                        && r.players.len() >= r.min_players
                         && r.players.iter().all(|p| p.ready)
                     {
                         eprintln!(
@@ -255,6 +424,21 @@ async fn route_cmd(
                 });
             }
         }
+        ClientToServer::SitUnready => {
+            if let Some(room) = joined_room {
+                with_room(state, room, |r| handle_sit_unready(r, my_id));
+            }
+        }
+        ClientToServer::Resync => {
+            if let Some(room) = joined_room {
+                with_room(state, room, |r| handle_resync(r, my_id));
+            }
+        }
+        ClientToServer::SetStatus { text } => {
+            if let Some(room) = joined_room {
+                with_room(state, room, |r| handle_set_status(r, my_id, text));
+            }
+        }
         ClientToServer::StartHand => {
             if let Some(room) = joined_room {
                 with_room(state, room, |r| {
@@ -280,9 +464,9 @@ async fn route_cmd(
                         send_err_to(r, my_id, format!("Cannot start: phase is {:?}.", r.phase));
                         return;
                     }
-                    if r.players.len() < 2 {
-                        eprintln!("[START] rejected: players={}", r.players.len());
-                        send_err_to(r, my_id, "Need at least 2 players to start.");
+                    if r.players.len() < r.min_players {
+                        eprintln!("[START] rejected: players={} min={}", r.players.len(), r.min_players);
+                        send_err_to(r, my_id, format!("Need at least {} players to start.", r.min_players));
                         return;
                     }
                     if let Some(not_ready) = r.players.iter().position(|p| !p.ready) {
@@ -294,25 +478,40 @@ async fn route_cmd(
                         );
                         return;
                     }
-                    if DEALER_MUST_START && starter_seat != r.dealer_seat {
+                    let dealer = acting_dealer_seat(r);
+                    if DEALER_MUST_START && starter_seat != dealer {
                         eprintln!(
                             "[START] rejected: starter={} dealer={} (dealer must start)",
-                            starter_seat, r.dealer_seat
+                            starter_seat, dealer
                         );
                         send_err_to(
                             r,
                             my_id,
-                            format!("Only dealer (seat {}) can start the hand.", r.dealer_seat),
+                            format!("Only dealer (seat {}) can start the hand.", dealer),
                         );
                         return;
                     }
 
                     eprintln!("[START] OK → dealing…");
                     start_hand(r);
-                    send_state_to(r, my_id);
                 });
             }
         }
+        ClientToServer::Muck => {
+            if let Some(room) = joined_room {
+                with_room(state, room, |r| player_muck(r, my_id));
+            }
+        }
+        ClientToServer::RabbitHunt => {
+            if let Some(room) = joined_room {
+                with_room(state, room, |r| player_rabbit_hunt(r, my_id, std::time::Instant::now()));
+            }
+        }
+        ClientToServer::ForceStart => {
+            if let Some(room) = joined_room {
+                with_room(state, room, |r| force_start(r, my_id));
+            }
+        }
         ClientToServer::Fold => {
             if let Some(room) = joined_room {
                 with_room(state, room, |r| {
@@ -320,6 +519,18 @@ async fn route_cmd(
                 });
             }
         }
+        ClientToServer::ShowCards => {
+            if let Some(room) = joined_room {
+                with_room(state, room, |r| {
+                    player_show_cards(r, my_id);
+                });
+            }
+        }
+        ClientToServer::SetPreAction { pre_action } => {
+            if let Some(room) = joined_room {
+                with_room(state, room, |r| handle_set_pre_action(r, my_id, pre_action));
+            }
+        }
         ClientToServer::Check => {
             if let Some(room) = joined_room {
                 with_room(state, room, |r| {
@@ -360,121 +571,49 @@ async fn route_cmd(
         ClientToServer::ScheduleGame { start_time } => {
             if let Some(room) = joined_room {
                 with_room(state, room, |r| {
-                    // Verify player is in the room
-                    if game::seat_of(r, my_id).is_none() {
-                        send_err_to(r, my_id, "You must be in the room to schedule a game.");
-                        return;
-                    }
-
-                    // Set the scheduled start time
-                    r.scheduled_start = Some(start_time.clone());
-                    r.checked_in_players.clear(); // Reset check-ins for new schedule
-
-                    // Notify all players about the scheduled game
-                    let info_msg = format!("Game scheduled to start at {}", start_time);
-                    for p in r.players.iter() {
-                        let _ = p.tx.send(ServerToClient::Info {
-                            message: info_msg.clone(),
-                        });
-                    }
-
-                    eprintln!("[SCHEDULE] Room {} scheduled for {}", r.name, start_time);
-                    broadcast_state(r);
+                    handle_schedule_game(r, my_id, &start_time);
                 });
             } else {
                 let _ = tx_out.send(ServerToClient::Error {
                     message: "You must join a room before scheduling a game.".to_string(),
+                    code: ErrorCode::Other,
                 });
             }
         }
         ClientToServer::CheckIn => {
             if let Some(room) = joined_room {
                 with_room(state, room, |r| {
-                    // Verify player is in the room
-                    if game::seat_of(r, my_id).is_none() {
-                        send_err_to(r, my_id, "You must be in the room to check in.");
-                        return;
-                    }
-
-                    // Check if there's a scheduled game
-                    if r.scheduled_start.is_none() {
-                        send_err_to(r, my_id, "No game is currently scheduled.");
-                        return;
-                    }
-
-                    // Add player to checked-in list if not already checked in
-                    if !r.checked_in_players.contains(&my_id) {
-                        r.checked_in_players.push(my_id);
-
-                        // Find player name for messaging
-                        let player_name = r.players.iter()
-                            .find(|p| p.id == my_id)
-                            .map(|p| p.name.clone())
-                            .unwrap_or_else(|| "Unknown".to_string());
-
-                        // Notify all players about the check-in
-                        let info_msg = format!("{} has checked in ({}/{})",
-                            player_name,
-                            r.checked_in_players.len(),
-                            r.players.len()
-                        );
-                        for p in r.players.iter() {
-                            let _ = p.tx.send(ServerToClient::Info {
-                                message: info_msg.clone(),
-                            });
-                        }
-
-                        eprintln!("[CHECKIN] {} checked in for room {} ({}/{})",
-                            player_name, r.name, r.checked_in_players.len(), r.players.len());
-                    } else {
-                        send_err_to(r, my_id, "You have already checked in.");
-                        return;
-                    }
-
-                    broadcast_state(r);
+                    handle_check_in(r, my_id);
                 });
             } else {
                 let _ = tx_out.send(ServerToClient::Error {
                     message: "You must join a room before checking in.".to_string(),
+                    code: ErrorCode::Other,
                 });
             }
         }
-        ClientToServer::SelectGameVariant { variant } => {
+        ClientToServer::CancelSchedule => {
             if let Some(room) = joined_room {
                 with_room(state, room, |r| {
-                    // Verify player is in the room
-                    if game::seat_of(r, my_id).is_none() {
-                        send_err_to(r, my_id, "You must be in the room to select game variant.");
-                        return;
-                    }
-
-                    // Only allow variant selection in lobby phase
-                    if r.phase != Phase::Lobby {
-                        send_err_to(r, my_id, "Game variant can only be changed in the lobby.");
-                        return;
-                    }
-
-                    // Update the game variant
-                    r.game_variant = variant;
-
-                    // Notify all players about the variant change
-                    let info_msg = format!("Game variant changed to {}", variant);
-                    for p in r.players.iter() {
-                        let _ = p.tx.send(ServerToClient::Info {
-                            message: info_msg.clone(),
-                        });
-                    }
-
-                    eprintln!("[VARIANT] Room {} changed to {}", r.name, variant);
-                    broadcast_state(r);
+                    handle_cancel_schedule(r, my_id);
                 });
+            }
+        }
+        ClientToServer::SelectGameVariant { variant } => {
+            if let Some(room) = joined_room {
+                with_room(state, room, |r| handle_select_game_variant(r, my_id, variant));
             } else {
                 let _ = tx_out.send(ServerToClient::Error {
                     message: "You must join a room before selecting game variant.".to_string(),
+                    code: ErrorCode::Other,
                 });
             }
         }
         ClientToServer::JoinAsSpectator { room, name } => {
+            let Some(name) = checked_player_name(&name, tx_out) else {
+                return;
+            };
+            leave_lounge_for_table(state, joined_room, my_id).await;
             let mut rooms = state.inner.lock();
             let r = rooms.entry(room.clone()).or_insert_with(|| game::Room::new(room.clone()));
 
@@ -482,9 +621,13 @@ async fn route_cmd(
             if r.spectators.iter().any(|s| s.id == my_id) {
                 let _ = tx_out.send(ServerToClient::Error {
                     message: "You are already spectating this room.".to_string(),
+                    code: ErrorCode::Other,
                 });
                 return;
             }
+            let Some(name) = room_name_for(r, my_id, &name, tx_out) else {
+                return;
+            };
 
             // Add as spectator
             r.spectators.push(game::Spectator {
@@ -513,6 +656,27 @@ async fn route_cmd(
                 remove_spectator(state, room, my_id);
             }
         }
+        ClientToServer::TakeSeat => {
+            if let Some(room) = joined_room {
+                with_room(state, room, |r| {
+                    handle_take_seat(r, my_id);
+                });
+            }
+        }
+        ClientToServer::RequestSeat => {
+            if let Some(room) = joined_room {
+                with_room(state, room, |r| {
+                    handle_request_seat(r, my_id);
+                });
+            }
+        }
+        ClientToServer::Rebuy => {
+            if let Some(room) = joined_room {
+                with_room(state, room, |r| {
+                    handle_rebuy(r, my_id);
+                });
+            }
+        }
         ClientToServer::ElectToStart => {
             if let Some(room) = joined_room {
                 with_room(state, room, |r| {
@@ -534,19 +698,90 @@ async fn route_cmd(
                 });
             }
         }
-        ClientToServer::CreateTable { name, game_variant, ante, limit_small, limit_big, max_raises } => {
-            handle_create_table(state, my_id, joined_room, tx_out, name, game_variant, ante, limit_small, limit_big, max_raises).await;
+        ClientToServer::SetDealersChoice { enabled } => {
+            if let Some(room) = joined_room {
+                with_room(state, room, |r| {
+                    handle_set_dealers_choice(r, my_id, enabled);
+                });
+            }
+        }
+        ClientToServer::UpdateTableConfig { ante, limit_small, limit_big, max_raises, max_buyin, max_draw_rounds } => {
+            if let Some(room) = joined_room {
+                with_room(state, room, |r| {
+                    let caps = TableCaps { max_buyin, max_draw_rounds };
+                    handle_update_table_config(r, my_id, ante, limit_small, limit_big, max_raises, caps);
+                });
+            }
+        }
+        ClientToServer::CreateTable {
+            name,
+            game_variant,
+            ante,
+            limit_small,
+            limit_big,
+            max_raises,
+            min_players,
+            rake_percent,
+            rake_cap,
+            max_buyin,
+            deck_count,
+            spectator_chat_to_players,
+            muck_window_secs,
+            max_draw_rounds,
+            auto_dealer,
+            rabbit_hunt,
+        } => {
+            leave_lounge_for_table(state, joined_room, my_id).await;
+            let options = TableOptions {
+                min_players,
+                rake_percent,
+                rake_cap,
+                caps: TableCaps { max_buyin, max_draw_rounds },
+                deck_count,
+                spectator_chat_to_players,
+                muck_window_secs,
+                auto_dealer,
+                rabbit_hunt,
+            };
+            handle_create_table(state, my_id, options, tx_out, name, game_variant, ante, limit_small, limit_big, max_raises).await;
         }
-        ClientToServer::PostComment { message } => {
-            handle_post_comment(state.clone(), my_id, joined_room.clone(), message).await;
+        ClientToServer::PostComment { message, about } => {
+            handle_post_comment(state.clone(), my_id, joined_room.clone(), message, about).await;
         }
         ClientToServer::ContinueToNextGame => {
             handle_continue_to_next_game(state.clone(), my_id, joined_room.clone()).await;
         }
+        ClientToServer::Rematch => {
+            if let Some(room) = joined_room {
+                with_room(state, room, |r| {
+                    handle_rematch(r, my_id);
+                });
+            }
+        }
         ClientToServer::RegisterTable { name, game_variant, ante, limit_small, limit_big, max_raises, server_port, player_count } => {
             handle_register_table(state.clone(), name, game_variant, ante, limit_small, limit_big, max_raises, server_port, player_count).await;
         }
+        ClientToServer::UnregisterTable { name } => {
+            if state.distributed_tables.lock().remove(&name).is_some() {
+                eprintln!("[REGISTER] Distributed table '{}' unregistered", name);
+            }
+        }
+        ClientToServer::KickPlayer { player_id } => {
+            if let Some(room) = joined_room.clone() {
+                handle_kick_player(state, &room, my_id, player_id);
+            }
+        }
+        ClientToServer::CloseTable => {
+            if let Some(room) = joined_room.clone() {
+                if handle_close_table(state, &room, my_id) {
+                    *joined_room = None;
+                }
+            }
+        }
         ClientToServer::JoinLounge { name } => {
+            let Some(name) = checked_player_name(&name, tx_out) else {
+                return;
+            };
             handle_join_lounge(state.clone(), my_id, name, tx_out.clone()).await;
             *joined_room = Some("lounge".to_string());
         }
@@ -560,6 +795,13 @@ async fn route_cmd(
         ClientToServer::SelectHost { host_name, port } => {
             handle_select_host(state.clone(), my_id, host_name.clone(), port).await;
         }
+        ClientToServer::FetchChatHistory { limit } => {
+            handle_fetch_chat_history(state, limit, tx_out).await;
+        }
+        ClientToServer::ListLoungePlayers => {
+            let _ = tx_out.send(ServerToClient::LoungePlayers { players: lounge_roster(state) });
+        }
+        ClientToServer::Ping { .. } => {} // answered above
     }
 }
 
@@ -581,14 +823,17 @@ async fn handle_chat_message(state: AppState, player_id: Uuid, joined_room: Opti
         }
     }
 
-    // Get player name and determine room context
-    let (player_name, room_name) = match scope {
+    // Get player name and determine room context; spectators_only marks a
+    // spectator's table chat at a table that keeps it away from the players
+    let (player_name, room_name, spectators_only) = match scope {
         MessageScope::Match => {
             if let Some(room) = &joined_room {
                 let rooms = state.inner.lock();
                 if let Some(r) = rooms.get(room) {
                     if let Some(player) = r.players.iter().find(|p| p.id == player_id) {
-                        (player.name.clone(), Some(room.clone()))
+                        (player.name.clone(), Some(room.clone()), false)
+                    } else if let Some(s) = r.spectators.iter().find(|s| s.id == player_id) {
+                        (s.name.clone(), Some(room.clone()), !r.spectator_chat_to_players)
                     } else {
                         return; // Player not in room
                     }
@@ -610,7 +855,7 @@ async fn handle_chat_message(state: AppState, player_id: Uuid, joined_room: Opti
                 }
             }
             if let Some(name) = found_name {
-                (name, None)
+                (name, None, false)
             } else {
                 return; // Player not found in any room
             }
@@ -647,44 +892,59 @@ async fn handle_chat_message(state: AppState, player_id: Uuid, joined_room: Opti
     };
 
     // Route message based on scope
+    let mut recipients = chat_recipients(&state, scope, room_name.as_deref());
+    if spectators_only {
+        let rooms = state.inner.lock();
+        let watching = |id: &Uuid| room_name.as_deref()
+            .and_then(|name| rooms.get(name))
+            .is_some_and(|r| r.spectators.iter().any(|s| s.id == *id));
+        recipients.retain(|(id, _)| watching(id));
+    }
+    for (_, tx) in recipients {
+        let _ = tx.send(chat_msg.clone());
+    }
+
+    eprintln!("[CHAT:{:?}] {} says: {}", scope, stored_message.player_name, message);
+}
+
+/// Who receives a chat message of the given scope:
+/// - Match: players and spectators at the sender's table
+/// - Group: players at any table on this server (not the lounge)
+/// - Global: everyone connected here, tables and lounge alike. Kept separate
+///   from Group so a multi-server setup can route it elsewhere later.
+fn chat_recipients(
+    state: &AppState,
+    scope: MessageScope,
+    room: Option<&str>,
+) -> Vec<(Uuid, mpsc::UnboundedSender<ServerToClient>)> {
+    let rooms = state.inner.lock();
+    let table_players = |r: &Room| -> Vec<(Uuid, mpsc::UnboundedSender<ServerToClient>)> {
+        r.players.iter().map(|p| (p.id, p.tx.clone())).collect()
+    };
+
     match scope {
-        MessageScope::Match => {
-            if let Some(room_name) = room_name {
-                let rooms = state.inner.lock();
-                if let Some(r) = rooms.get(&room_name) {
-                    // Send to all players in the room
-                    for p in r.players.iter() {
-                        let _ = p.tx.send(chat_msg.clone());
-                    }
-                }
-            }
-        },
-        MessageScope::Group => {
-            // Send to all players on the server (all rooms)
-            let rooms = state.inner.lock();
-            for (_, room) in rooms.iter() {
-                for p in room.players.iter() {
-                    let _ = p.tx.send(chat_msg.clone());
-                }
-            }
-        },
+        MessageScope::Match => room
+            .and_then(|name| rooms.get(name))
+            .map(|r| {
+                let mut at_table = table_players(r);
+                at_table.extend(r.spectators.iter().map(|s| (s.id, s.tx.clone())));
+                at_table
+            })
+            .unwrap_or_default(),
+        MessageScope::Group => rooms.values().flat_map(table_players).collect(),
         MessageScope::Global => {
-            // For now, Global scope behaves like Group scope
-            // In the future, this could be extended to support cross-server messaging
-            let rooms = state.inner.lock();
-            for (_, room) in rooms.iter() {
-                for p in room.players.iter() {
-                    let _ = p.tx.send(chat_msg.clone());
+            let mut recipients: Vec<_> = rooms.values().flat_map(table_players).collect();
+            let lounge = state.lounge.lock();
+            for p in lounge.players.values() {
+                if !recipients.iter().any(|(id, _)| *id == p.id) {
+                    recipients.push((p.id, p.tx.clone()));
                 }
             }
-        },
-        MessageScope::Private => {
-            // Private messages are handled by a separate function
-            // This case shouldn't be reached in normal chat flow
+            recipients
         }
+        // Private messages are handled by handle_private_message
+        MessageScope::Private => vec![],
     }
-
-    eprintln!("[CHAT:{:?}] {} says: {}", scope, stored_message.player_name, message);
 }
 
 async fn handle_private_message(state: AppState, sender_id: Uuid, recipient_id: Uuid, message: String) {
@@ -714,7 +974,10 @@ async fn handle_private_message(state: AppState, sender_id: Uuid, recipient_id:
         let rooms = state.inner.lock();
         let mut found = false;
         for (_, room) in rooms.iter() {
-            if let Some(recipient) = room.players.iter().find(|p| p.id == recipient_id) {
+            // Spectators can be messaged too, e.g. from the table's watcher list
+            let recipient_tx = room.players.iter().find(|p| p.id == recipient_id).map(|p| &p.tx)
+                .or_else(|| room.spectators.iter().find(|s| s.id == recipient_id).map(|s| &s.tx));
+            if let Some(recipient_tx) = recipient_tx {
                 // Create private message
                 let private_msg = ServerToClient::ChatMessage {
                     player_name: sender_name.clone(),
@@ -726,7 +989,7 @@ async fn handle_private_message(state: AppState, sender_id: Uuid, recipient_id:
                 };
 
                 // Send to recipient
-                let _ = recipient.tx.send(private_msg.clone());
+                let _ = recipient_tx.send(private_msg.clone());
 
                 // Also send to sender for confirmation/history
                 if let Some(sender) = room.players.iter().find(|p| p.id == sender_id) {
@@ -765,6 +1028,7 @@ async fn handle_private_message(state: AppState, sender_id: Uuid, recipient_id:
             if let Some(sender) = room.players.iter().find(|p| p.id == sender_id) {
                 let error_msg = ServerToClient::Error {
                     message: format!("Recipient not found: {}", recipient_id),
+                    code: ErrorCode::Other,
                 };
                 let _ = sender.tx.send(error_msg);
                 break;
@@ -773,67 +1037,550 @@ async fn handle_private_message(state: AppState, sender_id: Uuid, recipient_id:
     }
 }
 
-fn remove_player(state: &AppState, room: &str, id: Uuid) {
+/// Host ends the table between hands: everyone seated or watching is told
+/// and the room (and any registry entry for it) goes away. Returns true if
+/// the table was closed.
+fn handle_close_table(state: &AppState, room: &str, id: Uuid) -> bool {
     let mut rooms = state.inner.lock();
-    if let Some(r) = rooms.get_mut(room) {
-        r.players.retain(|p| p.id != id);
-        if r.players.is_empty() {
-            rooms.remove(room);
-            return;
-        }
-        broadcast_state(r);
+    let Some(r) = rooms.get(room) else {
+        return false;
+    };
+    if !is_dealer_or_host(r, id) {
+        send_err_to(r, id, "Only the dealer can close the table.");
+        return false;
+    }
+    if r.phase != Phase::Lobby && r.phase != Phase::Comments {
+        send_err_to(r, id, "The table can only be closed in the lobby or between hands.");
+        return false;
+    }
+
+    let host = r.players.iter().find(|p| p.id == id).map(|p| p.name.clone()).unwrap_or_default();
+    let closed = ServerToClient::Removed {
+        reason: format!("{} closed the table", host),
+    };
+    for p in r.players.iter() {
+        let _ = p.tx.send(closed.clone());
     }
+    for s in r.spectators.iter() {
+        let _ = s.tx.send(closed.clone());
+    }
+
+    eprintln!("[CLOSE_TABLE] Room {} closed by {} ({} seated, {} watching)", room, host, r.players.len(), r.spectators.len());
+    rooms.remove(room);
+    drop(rooms);
+    state.distributed_tables.lock().remove(room);
+    true
 }
 
-fn remove_spectator(state: &AppState, room: &str, id: Uuid) {
-    let mut rooms = state.inner.lock();
-    if let Some(r) = rooms.get_mut(room) {
-        if let Some(pos) = r.spectators.iter().position(|s| s.id == id) {
-            let spectator = r.spectators.remove(pos);
-            eprintln!("[SPECTATOR_LEAVE] {} left room {} as spectator", spectator.name, room);
+/// Take back readiness before the deal. Lobby only: in the comments phase
+/// `ready` means "continue", and once dealt it no longer matters. Auto-start
+/// only fires when everyone is ready, so this also holds it off.
+fn handle_sit_unready(r: &mut Room, id: Uuid) {
+    if r.phase != Phase::Lobby {
+        send_err_to(r, id, "You can only stop being ready in the lobby.");
+        return;
+    }
+    let Some(p) = r.players.iter_mut().find(|p| p.id == id) else {
+        return;
+    };
+    if !p.ready {
+        return;
+    }
+    p.ready = false;
+    eprintln!("[READY] room={} {} no longer ready", r.name, p.name);
+    broadcast_state(r);
+}
 
-            // Notify players that spectator left
-            for p in r.players.iter() {
-                let _ = p.tx.send(ServerToClient::Info {
-                    message: format!("{} left as spectator", spectator.name),
-                });
-            }
+/// Send one player or spectator everything they'd have been shown, e.g.
+/// after their socket dropped and came back.
+fn handle_resync(r: &Room, id: Uuid) {
+    let snapshot = game::public_room(r);
+    if let Some(seat) = r.players.iter().position(|p| p.id == id) {
+        let p = &r.players[seat];
+        let _ = p.tx.send(ServerToClient::UpdateState { snapshot });
+        let _ = p.tx.send(ServerToClient::YourHand {
+            hand: PrivateHand { down_cards: p.down_cards.clone() },
+        });
+        let options = game::turn_options(r, seat);
+        if options != TurnOptions::default() {
+            let _ = p.tx.send(ServerToClient::YourTurn { options });
         }
+    } else if let Some(s) = r.spectators.iter().find(|s| s.id == id) {
+        let _ = s.tx.send(ServerToClient::UpdateState { snapshot });
     }
 }
 
-fn start_hand(r: &mut Room) {
-    eprintln!(
-        "[DEAL] start_hand: players={} dealer_seat={} variant={}",
-        r.players.len(),
-        r.dealer_seat,
-        r.game_variant
-    );
-
-    r.phase = Phase::Dealing;
-    r.pot = (r.players.len() as u64) * r.ante;
-    r.deck = Some(Deck::standard_shuffled());
-    r.community_cards.clear();
-
-    for p in r.players.iter_mut() {
-        p.folded = false;
-        p.standing = false;
-        p.up_cards.clear();
-        p.down_cards.clear();
-        p.ready = false;
-        p.committed_round = 0;
+/// Set or clear the note shown on a player's seat.
+fn handle_set_status(r: &mut Room, id: Uuid, text: Option<String>) {
+    let status = match text.as_deref().map(cctmog_protocol::names::clean_status) {
+        None => None,
+        Some(Ok(status)) => status,
+        Some(Err(e)) => {
+            send_err_to(r, id, e);
+            return;
+        }
+    };
+    let Some(p) = r.players.iter_mut().find(|p| p.id == id) else {
+        return;
+    };
+    if p.status == status {
+        return;
     }
+    p.status = status;
+    eprintln!("[STATUS] room={} {} status={:?}", r.name, p.name, p.status);
+    broadcast_state(r);
+}
 
-    // Deal cards based on game variant
-    match r.game_variant {
-        GameVariant::SevenTwentySeven => {
-            // Deal one up card and one down card to each player
-            for p in r.players.iter_mut() {
-                let up = r.deck.as_mut().unwrap().draw(true).unwrap();
-                let down = r.deck.as_mut().unwrap().draw(false).unwrap();
-                p.up_cards.push(up);
-                p.down_cards.push(down);
-                let _ = p.tx.send(ServerToClient::YourHand {
+/// Host removes a seated player between hands.
+fn handle_kick_player(state: &AppState, room: &str, id: Uuid, target: Uuid) {
+    let name = {
+        let rooms = state.inner.lock();
+        let Some(r) = rooms.get(room) else {
+            return;
+        };
+        if !is_dealer_or_host(r, id) {
+            send_err_to(r, id, "Only the dealer can remove players.");
+            return;
+        }
+        if r.phase != Phase::Lobby && r.phase != Phase::Comments {
+            send_err_to(r, id, "Players can only be removed in the lobby or between hands.");
+            return;
+        }
+        match r.players.iter().find(|p| p.id == target) {
+            Some(p) if target != id => p.name.clone(),
+            _ => {
+                send_err_to(r, id, "That player isn't seated here.");
+                return;
+            }
+        }
+    };
+
+    eprintln!("[KICK] {} removed from {} by {}", name, room, &id.to_string()[..8]);
+    remove_player(state, room, target, "Removed from the table by the dealer");
+    with_room(state, room, |r| {
+        for p in r.players.iter() {
+            let _ = p.tx.send(ServerToClient::Info { message: format!("{} was removed from the table", name) });
+        }
+    });
+}
+
+/// Pulling a seat out from under a hand in play would break it, so a player
+/// who drops mid-hand is folded, sat out and unseated when the hand ends.
+/// Returns false when no hand is on and they can leave straight away.
+fn defer_departure(r: &mut Room, id: Uuid) -> bool {
+    if matches!(r.phase, Phase::Lobby | Phase::Comments) {
+        return false;
+    }
+    let Some(seat) = game::seat_of(r, id) else {
+        return false;
+    };
+    eprintln!("[DISCONNECT] {} dropped mid-hand, sitting them out", r.players[seat].name);
+    r.departed.push(id);
+    if !r.sitting_out.contains(&id) {
+        r.sitting_out.push(id);
+    }
+
+    if r.phase != Phase::Acting || r.players[seat].folded {
+        broadcast_state(r);
+    } else if r.to_act_seat == seat {
+        // Folding on their turn moves the action on
+        player_fold(r, id);
+    } else {
+        r.players[seat].folded = true;
+        record_action(r, seat, ActionKind::Fold, 0);
+        if game::alive_seats(r).len() <= 1 {
+            award_last_player_and_reset(r);
+        } else {
+            broadcast_state(r);
+        }
+    }
+    true
+}
+
+/// Take a player out of a room, telling them why first.
+fn remove_player(state: &AppState, room: &str, id: Uuid, reason: &str) {
+    let mut rooms = state.inner.lock();
+    if let Some(r) = rooms.get_mut(room) {
+        if let Some(p) = r.players.iter().find(|p| p.id == id) {
+            let _ = p.tx.send(ServerToClient::Removed { reason: reason.to_string() });
+        }
+        r.players.retain(|p| p.id != id);
+        if r.players.is_empty() {
+            rooms.remove(room);
+            return;
+        }
+        broadcast_state(r);
+        seat_queued_spectators(r);
+
+        // Offer the freed seat to whoever is watching
+        if r.players.len() < MAX_PLAYERS {
+            for s in r.spectators.iter() {
+                let _ = s.tx.send(ServerToClient::Info {
+                    message: "A seat just opened up — take it before the next hand.".to_string(),
+                });
+            }
+        }
+    }
+}
+
+/// Move a spectator into an open seat. Only allowed between hands so nobody
+/// is dealt into a hand already in progress.
+fn handle_take_seat(r: &mut Room, id: Uuid) {
+    let Some(pos) = r.spectators.iter().position(|s| s.id == id) else {
+        return;
+    };
+    let reject = |msg: &str| {
+        let _ = r.spectators[pos].tx.send(ServerToClient::Error { message: msg.to_string(), code: ErrorCode::Other });
+    };
+    if r.players.len() >= MAX_PLAYERS {
+        reject("Table is still full.");
+        return;
+    }
+    if !matches!(r.phase, Phase::Lobby | Phase::Comments) {
+        reject("You can take a seat once this hand is over.");
+        return;
+    }
+
+    let spectator = r.spectators.remove(pos);
+    let seat = r.players.len();
+    r.players.push(PlayerSeat {
+        id: spectator.id,
+        name: spectator.name,
+        chips: 1000,
+        folded: false,
+        standing: false,
+        up_cards: vec![],
+        down_cards: vec![],
+        ready: false,
+        committed_round: 0,
+        status: None,
+        tx: spectator.tx.clone(),
+    });
+    clamp_to_max_buyin(r, seat);
+    log_room("TAKE_SEAT", r);
+    broadcast_state(r);
+
+    let _ = spectator.tx.send(ServerToClient::Joined {
+        snapshot: game::public_room(r),
+        your_seat: seat,
+        your_hand: PrivateHand { down_cards: vec![] },
+    });
+}
+
+/// Queue a spectator for the next open seat. Seats them straight away if
+/// one is free between hands; otherwise `seat_queued_spectators` does it
+/// once the table is back in the lobby.
+fn handle_request_seat(r: &mut Room, id: Uuid) {
+    let Some(spectator) = r.spectators.iter().find(|s| s.id == id) else {
+        return;
+    };
+    if !r.seat_queue.contains(&id) {
+        r.seat_queue.push(id);
+    }
+    let place = r.seat_queue.iter().position(|q| *q == id).unwrap_or(0) + 1;
+    let _ = spectator.tx.send(ServerToClient::Info {
+        message: format!("You're #{} in line for a seat.", place),
+    });
+    seat_queued_spectators(r);
+}
+
+/// Seat queued spectators, first come first served, while the table is
+/// between hands and has room.
+fn seat_queued_spectators(r: &mut Room) {
+    // Drop anyone who has since left or been seated another way
+    let spectators: Vec<Uuid> = r.spectators.iter().map(|s| s.id).collect();
+    r.seat_queue.retain(|id| spectators.contains(id));
+
+    while !r.seat_queue.is_empty()
+        && r.players.len() < MAX_PLAYERS
+        && matches!(r.phase, Phase::Lobby | Phase::Comments)
+    {
+        let id = r.seat_queue.remove(0);
+        eprintln!("[SEAT_QUEUE] room={} seating {}", r.name, id);
+        handle_take_seat(r, id);
+    }
+}
+
+fn remove_spectator(state: &AppState, room: &str, id: Uuid) {
+    let mut rooms = state.inner.lock();
+    if let Some(r) = rooms.get_mut(room) {
+        r.seat_queue.retain(|q| *q != id);
+        if let Some(pos) = r.spectators.iter().position(|s| s.id == id) {
+            let spectator = r.spectators.remove(pos);
+            eprintln!("[SPECTATOR_LEAVE] {} left room {} as spectator", spectator.name, room);
+
+            // Notify players that spectator left
+            for p in r.players.iter() {
+                let _ = p.tx.send(ServerToClient::Info {
+                    message: format!("{} left as spectator", spectator.name),
+                });
+            }
+        }
+    }
+}
+
+fn handle_schedule_game(r: &mut Room, player_id: Uuid, start_time: &str) {
+    // Verify player is in the room
+    if game::seat_of(r, player_id).is_none() {
+        send_err_to(r, player_id, "You must be in the room to schedule a game.");
+        return;
+    }
+
+    // Only accept real, future RFC 3339 times; store them normalized to UTC
+    let start = match chrono::DateTime::parse_from_rfc3339(start_time.trim()) {
+        Ok(t) => t.with_timezone(&chrono::Utc),
+        Err(_) => {
+            send_err_to(
+                r,
+                player_id,
+                format!("Invalid start time '{}': use RFC 3339, e.g. 2030-01-01T20:00:00Z", start_time),
+            );
+            return;
+        }
+    };
+    if start <= chrono::Utc::now() {
+        send_err_to(r, player_id, "Start time must be in the future.");
+        return;
+    }
+    let start_time = start.to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+
+    // Set the scheduled start time
+    r.scheduled_start = Some(start_time.clone());
+    r.checked_in_players.clear(); // Reset check-ins for new schedule
+
+    // Notify all players about the scheduled game
+    let info_msg = format!("Game scheduled to start at {}", start_time);
+    for p in r.players.iter() {
+        let _ = p.tx.send(ServerToClient::Info {
+            message: info_msg.clone(),
+        });
+    }
+
+    eprintln!("[SCHEDULE] Room {} scheduled for {}", r.name, start_time);
+    broadcast_state(r);
+}
+
+fn handle_check_in(r: &mut Room, player_id: Uuid) {
+    // Verify player is in the room
+    if game::seat_of(r, player_id).is_none() {
+        send_err_to(r, player_id, "You must be in the room to check in.");
+        return;
+    }
+
+    // Check if there's a scheduled game
+    if r.scheduled_start.is_none() {
+        send_err_to(r, player_id, "No game is currently scheduled.");
+        return;
+    }
+
+    // Add player to checked-in list if not already checked in
+    if !r.checked_in_players.contains(&player_id) {
+        r.checked_in_players.push(player_id);
+
+        // Find player name for messaging
+        let player_name = r.players.iter()
+            .find(|p| p.id == player_id)
+            .map(|p| p.name.clone())
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        // Notify all players about the check-in
+        let info_msg = format!("{} has checked in ({}/{})",
+            player_name,
+            r.checked_in_players.len(),
+            r.players.len()
+        );
+        for p in r.players.iter() {
+            let _ = p.tx.send(ServerToClient::Info {
+                message: info_msg.clone(),
+            });
+        }
+
+        eprintln!("[CHECKIN] {} checked in for room {} ({}/{})",
+            player_name, r.name, r.checked_in_players.len(), r.players.len());
+    } else {
+        send_err_to(r, player_id, "You have already checked in.");
+        return;
+    }
+
+    broadcast_state(r);
+}
+
+/// The dealer once one has been set, otherwise whoever sits in the dealer
+/// seat (the table's first player before any hand is played).
+fn is_dealer_or_host(r: &Room, id: Uuid) -> bool {
+    match r.current_dealer_id {
+        Some(dealer) => dealer == id,
+        None => r.players.get(r.dealer_seat).is_some_and(|p| p.id == id),
+    }
+}
+
+/// Seat of the real dealer: the delegated `current_dealer_id` when it is
+/// seated, falling back to `dealer_seat`.
+fn acting_dealer_seat(r: &Room) -> usize {
+    r.current_dealer_id
+        .and_then(|id| seat_of(r, id))
+        .unwrap_or(r.dealer_seat)
+}
+
+fn handle_cancel_schedule(r: &mut Room, player_id: Uuid) {
+    if r.scheduled_start.is_none() {
+        send_err_to(r, player_id, "No game is currently scheduled.");
+        return;
+    }
+    if !is_dealer_or_host(r, player_id) {
+        send_err_to(r, player_id, "Only the dealer can cancel the scheduled game.");
+        return;
+    }
+
+    r.scheduled_start = None;
+    r.checked_in_players.clear();
+
+    let player_name = r.players.iter()
+        .find(|p| p.id == player_id)
+        .map(|p| p.name.clone())
+        .unwrap_or_else(|| "Unknown".to_string());
+    let info_msg = format!("{} cancelled the scheduled game", player_name);
+    for p in r.players.iter() {
+        let _ = p.tx.send(ServerToClient::Info {
+            message: info_msg.clone(),
+        });
+    }
+
+    eprintln!("[SCHEDULE] Room {} schedule cancelled by {}", r.name, player_name);
+    broadcast_state(r);
+}
+
+/// Background clock that starts (or cancels) scheduled games once their
+/// start time arrives.
+async fn run_schedule_clock(state: AppState) {
+    let mut ticker = tokio::time::interval(SCHEDULE_CHECK_INTERVAL);
+    loop {
+        ticker.tick().await;
+        let now = chrono::Utc::now();
+        let mut choice_windows = vec![];
+        {
+            let mut rooms = state.inner.lock();
+            for (name, r) in rooms.iter_mut() {
+                fire_scheduled_start(r, now);
+                expire_muck_window(r, std::time::Instant::now());
+                expire_rabbit_hunt(r, std::time::Instant::now());
+                if let Some(deadline) = expire_comments_phase(r, std::time::Instant::now()) {
+                    choice_windows.push((name.clone(), deadline));
+                }
+            }
+        }
+        for (room, deadline) in choice_windows {
+            spawn_choice_window_expiry(state.clone(), room, deadline);
+        }
+    }
+}
+
+/// Once the scheduled time has passed, deal a hand to the seated players
+/// who checked in if there are enough of them, otherwise cancel the
+/// schedule. `now` is passed in so tests can drive the clock.
+fn fire_scheduled_start(r: &mut Room, now: chrono::DateTime<chrono::Utc>) {
+    let Some(start) = r
+        .scheduled_start
+        .as_deref()
+        .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+    else {
+        return;
+    };
+    if start > now {
+        return;
+    }
+
+    r.scheduled_start = None;
+    let checked_in = std::mem::take(&mut r.checked_in_players);
+    let present = checked_in.iter().filter(|id| game::seat_of(r, **id).is_some()).count();
+
+    if r.phase != Phase::Lobby {
+        // Started by hand before the clock got there
+        eprintln!("[SCHEDULE] Room {} already in {:?}, dropping schedule", r.name, r.phase);
+        broadcast_state(r);
+        return;
+    }
+
+    let enough = present >= r.min_players;
+    let info_msg = if enough {
+        format!("Scheduled game starting with {} checked-in players", present)
+    } else {
+        format!(
+            "Scheduled game cancelled: {} checked in, {} needed",
+            present, r.min_players
+        )
+    };
+    for p in r.players.iter() {
+        let _ = p.tx.send(ServerToClient::Info {
+            message: info_msg.clone(),
+        });
+    }
+    eprintln!("[SCHEDULE] Room {}: {}", r.name, info_msg);
+
+    if enough {
+        // Only those who checked in are dealt in; the rest sit this one out
+        let sitting_out = r.players.iter().filter(|p| !checked_in.contains(&p.id)).map(|p| p.id).collect();
+        start_hand_sitting_out(r, sitting_out);
+    } else {
+        broadcast_state(r);
+    }
+}
+
+fn start_hand(r: &mut Room) {
+    start_hand_sitting_out(r, vec![]);
+}
+
+/// Deal a hand to everyone except `sitting_out`, who stay seated but are
+/// folded from the start: no ante, no cards, never asked to act.
+fn start_hand_sitting_out(r: &mut Room, sitting_out: Vec<Uuid>) {
+    eprintln!(
+        "[DEAL] start_hand: players={} sitting_out={} dealer_seat={} variant={}",
+        r.players.len(),
+        sitting_out.len(),
+        r.dealer_seat,
+        r.game_variant
+    );
+
+    r.sitting_out = sitting_out;
+    for id in r.departed.iter() {
+        if !r.sitting_out.contains(id) {
+            r.sitting_out.push(*id);
+        }
+    }
+    r.shown_cards.clear();
+    r.mucked.clear();
+    r.pre_actions.clear();
+    r.hand_started_at = Some(std::time::Instant::now());
+    r.phase = Phase::Dealing;
+    r.pot = (r.players.len() - r.sitting_out.len()) as u64 * r.ante;
+    r.contributed = r.players.iter().filter(|p| !r.sitting_out.contains(&p.id)).map(|p| (p.id, r.ante)).collect();
+    r.community_cards.clear();
+    r.actions.clear();
+    r.rabbit_board = None;
+
+    for p in r.players.iter_mut() {
+        p.folded = r.sitting_out.contains(&p.id);
+        p.standing = false;
+        p.up_cards.clear();
+        p.down_cards.clear();
+        p.ready = false;
+        p.committed_round = 0;
+    }
+
+    // Deal from a fresh local shoe; a full table needs well under 52 cards
+    let mut deck = Deck::standard_shuffled_n(r.deck_count);
+    let mut draw = |face_up: bool| deck.draw(face_up).expect("fresh deck covers the deal");
+
+    // Deal cards based on game variant
+    match r.game_variant {
+        GameVariant::SevenTwentySeven => {
+            // Deal one up card and one down card to each player
+            for p in r.players.iter_mut().filter(|p| !p.folded) {
+                let up = draw(true);
+                let down = draw(false);
+                p.up_cards.push(up);
+                p.down_cards.push(down);
+                let _ = p.tx.send(ServerToClient::YourHand {
                     hand: PrivateHand {
                         down_cards: p.down_cards.clone(),
                     },
@@ -842,9 +1589,9 @@ fn start_hand(r: &mut Room) {
         }
         GameVariant::Omaha => {
             // Deal 4 hole cards (all face down) to each player
-            for p in r.players.iter_mut() {
+            for p in r.players.iter_mut().filter(|p| !p.folded) {
                 for _ in 0..4 {
-                    let card = r.deck.as_mut().unwrap().draw(false).unwrap();
+                    let card = draw(false);
                     p.down_cards.push(card);
                 }
                 let _ = p.tx.send(ServerToClient::YourHand {
@@ -855,15 +1602,15 @@ fn start_hand(r: &mut Room) {
             }
             // Deal 3 community cards (the flop)
             for _ in 0..3 {
-                let card = r.deck.as_mut().unwrap().draw(true).unwrap();
+                let card = draw(true);
                 r.community_cards.push(card);
             }
         }
         GameVariant::TexasHoldem => {
             // Deal 2 hole cards (both face down) to each player
-            for p in r.players.iter_mut() {
+            for p in r.players.iter_mut().filter(|p| !p.folded) {
                 for _ in 0..2 {
-                    let card = r.deck.as_mut().unwrap().draw(false).unwrap();
+                    let card = draw(false);
                     p.down_cards.push(card);
                 }
                 let _ = p.tx.send(ServerToClient::YourHand {
@@ -874,14 +1621,21 @@ fn start_hand(r: &mut Room) {
             }
             // Deal 3 community cards (the flop)
             for _ in 0..3 {
-                let card = r.deck.as_mut().unwrap().draw(true).unwrap();
+                let card = draw(true);
                 r.community_cards.push(card);
             }
         }
     }
+    r.deck = Some(deck);
+    debug_assert!(
+        r.game_variant.uses_community_cards() || r.community_cards.is_empty(),
+        "{} dealt a board",
+        r.game_variant
+    );
 
     r.phase = Phase::Acting;
     r.round = 1;
+    start_turn_clock(r);
 
     // Set initial game state based on variant
     if r.game_variant.uses_community_cards() {
@@ -895,6 +1649,7 @@ fn start_hand(r: &mut Room) {
         for p in r.players.iter_mut() {
             p.committed_round = 0;
         }
+        r.betting_acted = (0..r.players.len()).map(|i| r.players[i].folded).collect();
     } else {
         // 7/27 starts with draw phase
         r.in_betting = false;
@@ -909,6 +1664,9 @@ fn start_hand(r: &mut Room) {
             .collect();
     }
 
+    let sound = check_deck_integrity(r, "deal");
+    debug_assert!(sound, "duplicate cards dealt");
+
     broadcast_state(r);
     eprintln!(
         "[DEAL] -> phase={:?} round={} to_act_seat={} in_betting={} variant={}",
@@ -916,6 +1674,38 @@ fn start_hand(r: &mut Room) {
     );
 }
 
+/// Dealer starts the hand with whoever is ready; everyone else sits it out.
+fn force_start(r: &mut Room, id: Uuid) {
+    let Some(seat) = seat_of(r, id) else {
+        send_err_to(r, id, "You are not seated.");
+        return;
+    };
+    let dealer = acting_dealer_seat(r);
+    if seat != dealer {
+        eprintln!("[FORCE-START] rejected: seat {} is not the dealer ({})", seat, dealer);
+        send_err_to(r, id, format!("Only the dealer (seat {}) can force-start.", dealer));
+        return;
+    }
+    if r.phase != Phase::Lobby {
+        send_err_to(r, id, format!("Cannot start: phase is {:?}.", r.phase));
+        return;
+    }
+    let ready = r.players.iter().filter(|p| p.ready).count();
+    if ready < r.min_players {
+        eprintln!("[FORCE-START] rejected: only {} ready", ready);
+        send_err_to(
+            r,
+            id,
+            format!("Need at least {} ready players to force-start ({} ready).", r.min_players, ready),
+        );
+        return;
+    }
+
+    let sitting_out: Vec<Uuid> = r.players.iter().filter(|p| !p.ready).map(|p| p.id).collect();
+    eprintln!("[FORCE-START] dealing {} ready, {} sitting out", ready, sitting_out.len());
+    start_hand_sitting_out(r, sitting_out);
+}
+
 fn next_alive_left_of(r: &Room, from: usize) -> usize {
     let n = r.players.len();
     let mut i = (from + 1) % n;
@@ -931,6 +1721,15 @@ fn player_take_card(r: &mut Room, id: Uuid) {
         eprintln!("[DRAW] reject: phase={:?}", r.phase);
         return;
     }
+    if r.deck.is_none() {
+        eprintln!("[DRAW] reject: no deck, hand already over");
+        return;
+    }
+    if r.game_variant.uses_community_cards() {
+        // Community games have no draw round; draw_acted is meaningless there
+        send_err_to(r, id, format!("You can't take cards in {}.", r.game_variant));
+        return;
+    }
     if r.in_betting {
         eprintln!("[DRAW] reject: currently in betting");
         return;
@@ -964,9 +1763,11 @@ fn player_take_card(r: &mut Room, id: Uuid) {
     let current_cards = r.players[seat].up_cards.len() + r.players[seat].down_cards.len();
     let max_cards = r.game_variant.max_cards_per_player();
     if current_cards >= max_cards {
-        eprintln!(
-            "[DRAW] reject: player already has max cards ({}/{})",
-            current_cards, max_cards
+        send_err_code_to(
+            r,
+            id,
+            ErrorCode::MaxCardsReached,
+            format!("You already have the maximum cards ({}/{}).", current_cards, max_cards),
         );
         return;
     }
@@ -981,6 +1782,7 @@ fn player_take_card(r: &mut Room, id: Uuid) {
 
     if let Some(c) = deck.draw(false) {
         r.players[seat].down_cards.push(c);
+        record_action(r, seat, ActionKind::TakeCard, 0);
         let _ = r.players[seat].tx.send(ServerToClient::YourHand {
             hand: PrivateHand {
                 down_cards: r.players[seat].down_cards.clone(),
@@ -991,6 +1793,8 @@ fn player_take_card(r: &mut Room, id: Uuid) {
             seat,
             r.players[seat].down_cards.len()
         );
+        let sound = check_deck_integrity(r, "draw");
+        debug_assert!(sound, "duplicate card drawn");
     } else {
         eprintln!("[DRAW] deck exhausted");
         // You may want to end the hand here; for now just return.
@@ -1000,6 +1804,7 @@ fn player_take_card(r: &mut Room, id: Uuid) {
     let sc = score_hand(&game::all_cards(&r.players[seat]));
     if sc.bust_27 {
         r.players[seat].folded = true;
+        record_action(r, seat, ActionKind::Fold, 0);
         let _ = r.players[seat].tx.send(ServerToClient::Info {
             message: "Busted (>27). You fold.".into(),
         });
@@ -1019,6 +1824,15 @@ fn player_stand(r: &mut Room, id: Uuid) {
         eprintln!("[DRAW] reject: phase={:?}", r.phase);
         return;
     }
+    if r.deck.is_none() {
+        eprintln!("[DRAW] reject: no deck, hand already over");
+        return;
+    }
+    if r.game_variant.uses_community_cards() {
+        // Community games have no draw round; draw_acted is meaningless there
+        send_err_to(r, id, format!("You can't stand in {}.", r.game_variant));
+        return;
+    }
     if r.in_betting {
         eprintln!("[DRAW] reject: currently in betting");
         return;
@@ -1050,6 +1864,7 @@ fn player_stand(r: &mut Room, id: Uuid) {
 
     r.players[seat].standing = true;
     r.draw_acted[seat] = true;
+    record_action(r, seat, ActionKind::Stand, 0);
     eprintln!("[DRAW] seat {} stands", seat);
     advance_after_draw_action(r);
 }
@@ -1067,9 +1882,12 @@ fn player_fold(r: &mut Room, id: Uuid) {
     }
 
     r.players[seat].folded = true;
-    r.draw_acted[seat] = true;
+    record_action(r, seat, ActionKind::Fold, 0);
+    // Community games never size draw_acted, so only touch the round in play
     if r.in_betting {
         r.betting_acted[seat] = true;
+    } else {
+        r.draw_acted[seat] = true;
     }
 
     if game::alive_seats(r).len() <= 1 {
@@ -1084,12 +1902,68 @@ fn player_fold(r: &mut Room, id: Uuid) {
     }
 }
 
+fn player_show_cards(r: &mut Room, id: Uuid) {
+    // Only during the hand the player folded in; cards are cleared on reset
+    if r.phase != Phase::Acting {
+        send_err_to(r, id, "You can only show your cards during the current hand.");
+        return;
+    }
+    let seat = match game::seat_of(r, id) {
+        Some(s) => s,
+        None => return,
+    };
+    if !r.players[seat].folded {
+        send_err_to(r, id, "You can only show your cards after folding.");
+        return;
+    }
+
+    let player_name = r.players[seat].name.clone();
+    let cards: Vec<Card> = game::all_cards(&r.players[seat])
+        .into_iter()
+        .map(|mut c| {
+            c.face_up = true;
+            c
+        })
+        .collect();
+    eprintln!("[SHOW] seat {} shows {} cards", seat, cards.len());
+    if !r.shown_cards.contains(&id) {
+        r.shown_cards.push(id);
+    }
+
+    let msg = ServerToClient::CardsShown {
+        player_id: id,
+        player_name,
+        cards,
+    };
+    for p in r.players.iter() {
+        let _ = p.tx.send(msg.clone());
+    }
+    for s in r.spectators.iter() {
+        let _ = s.tx.send(msg.clone());
+    }
+}
+
 /* ---------------- small helpers used above ---------------- */
 
 fn seat_of(r: &Room, id: Uuid) -> Option<usize> {
     r.players.iter().position(|p| p.id == id)
 }
 
+fn start_turn_clock(r: &mut Room) {
+    r.turn_started_at = Some(chrono::Utc::now().to_rfc3339());
+}
+
+fn record_action(r: &mut Room, seat: usize, kind: ActionKind, amount: u64) {
+    let p = &r.players[seat];
+    r.actions.push(ActionEvent {
+        round: r.round,
+        player_id: p.id,
+        player_name: p.name.clone(),
+        kind,
+        amount,
+    });
+}
+
 
 fn alive_seats(r: &Room) -> Vec<(usize, &PlayerSeat)> {
     r.players
@@ -1120,6 +1994,7 @@ fn advance_after_draw_action(r: &mut Room) {
 
     if let Some(next) = found_next {
         eprintln!("[DRAW] next to act → seat {}", next);
+        start_turn_clock(r);
         broadcast_state(r);
     } else {
         eprintln!("[DRAW] draw loop complete → start_betting_round");
@@ -1137,107 +2012,143 @@ fn start_betting_round(r: &mut Room) {
     for p in r.players.iter_mut() {
         p.committed_round = 0;
     }
-    r.betting_acted = (0..r.players.len()).map(|i| r.players[i].folded).collect();
-    r.to_act_seat = r.betting_started_seat;
+    // All-in players have nothing left to bet, so they count as acted
+    r.betting_acted = (0..r.players.len())
+        .map(|i| r.players[i].folded || r.players[i].chips == 0)
+        .collect();
+    let n = r.players.len();
+    r.to_act_seat = (0..n)
+        .map(|k| (r.betting_started_seat + k) % n)
+        .find(|&i| !r.betting_acted[i])
+        .unwrap_or(r.betting_started_seat);
+    if run_pre_action(r) {
+        return;
+    }
+    start_turn_clock(r);
     broadcast_state(r);
 }
 
-
-fn player_check(r: &mut Room, id: Uuid) {
-    if !r.in_betting || r.phase != Phase::Acting {
+fn handle_set_pre_action(r: &mut Room, id: Uuid, pre_action: Option<PreAction>) {
+    let Some(seat) = seat_of(r, id) else {
         return;
-    }
-    let seat = match game::seat_of(r, id) {
-        Some(s) => s,
-        None => return,
     };
-    if r.to_act_seat != seat || r.players[seat].folded {
+    let Some(pre_action) = pre_action else {
+        r.pre_actions.remove(&id);
         return;
-    }
-    if r.current_bet != 0 {
+    };
+    if r.phase != Phase::Acting || !r.in_betting || r.players[seat].folded {
+        send_err_to(r, id, "Pre-actions can only be set during a betting round.");
         return;
-    } // cannot check facing a bet
+    }
+    eprintln!("[PRE_ACTION] seat {} queues {:?}", seat, pre_action);
+    r.pre_actions.insert(id, pre_action);
+    // Already our turn: no reason to wait
+    if r.to_act_seat == seat {
+        run_pre_action(r);
+    }
+}
+
+/// Play the queued move of the player now to act, if any. Returns true
+/// when it did, in which case the turn has already moved on.
+fn run_pre_action(r: &mut Room) -> bool {
+    let Some(p) = r.players.get(r.to_act_seat) else {
+        return false;
+    };
+    let id = p.id;
+    let owes = r.current_bet > p.committed_round;
+    let Some(pre_action) = r.pre_actions.remove(&id) else {
+        return false;
+    };
+    match pre_action {
+        PreAction::CheckFold if owes => {
+            eprintln!("[PRE_ACTION] seat {} folds to the bet", r.to_act_seat);
+            player_fold(r, id);
+        }
+        PreAction::CheckFold => {
+            eprintln!("[PRE_ACTION] seat {} checks", r.to_act_seat);
+            player_check(r, id);
+        }
+    }
+    true
+}
+
+
+fn player_check(r: &mut Room, id: Uuid) {
+    let seat = match game::can_check(r, id) {
+        Ok(s) => s,
+        Err((code, e)) => {
+            send_err_code_to(r, id, code, e);
+            return;
+        }
+    };
     r.betting_acted[seat] = true;
+    record_action(r, seat, ActionKind::Check, 0);
     advance_betting_turn(r);
 }
 
 fn player_bet_or_raise(r: &mut Room, id: Uuid, is_raise: bool) {
-    if !r.in_betting || r.phase != Phase::Acting {
-        return;
-    }
-    let seat = match game::seat_of(r, id) {
-        Some(s) => s,
-        None => return,
+    let seat = match game::can_bet_or_raise(r, id, is_raise) {
+        Ok(s) => s,
+        Err((code, e)) => {
+            send_err_code_to(r, id, code, e);
+            return;
+        }
     };
-    if r.to_act_seat != seat || r.players[seat].folded {
-        return;
-    }
 
     let sz = game::bet_size_for_round(r);
 
     if r.current_bet == 0 {
-        if is_raise {
-            return;
-        }
-        game::commit(r, seat, sz);
+        // Opening bet: doesn't count towards the raise cap
+        let paid = game::commit(r, seat, sz);
+        record_action(r, seat, ActionKind::Bet, paid);
         r.current_bet = sz;
         r.last_aggressor_seat = Some(seat);
-        r.raises_made = 1;
-        // Reset all acting status except for folded and current player
-        for i in 0..r.betting_acted.len() {
-            r.betting_acted[i] = r.players[i].folded;
-        }
-        r.betting_acted[seat] = true;
-        advance_betting_turn(r);
+        r.raises_made = 0;
     } else {
-        if !is_raise || r.raises_made >= r.max_raises {
-            return;
-        }
         let new_bet = r.current_bet + sz;
         let to_put = new_bet - r.players[seat].committed_round;
-        game::commit(r, seat, to_put);
+        let paid = game::commit(r, seat, to_put);
+        record_action(r, seat, ActionKind::Raise, paid);
         r.current_bet = new_bet;
         r.last_aggressor_seat = Some(seat);
         r.raises_made += 1;
-        // Reset all acting status except for folded and current player
-        for i in 0..r.betting_acted.len() {
-            r.betting_acted[i] = r.players[i].folded;
-        }
-        r.betting_acted[seat] = true;
-        advance_betting_turn(r);
     }
+
+    // Reset all acting status except for folded, all-in and current player
+    for i in 0..r.betting_acted.len() {
+        r.betting_acted[i] = r.players[i].folded || r.players[i].chips == 0;
+    }
+    r.betting_acted[seat] = true;
+    advance_betting_turn(r);
 }
 
 fn player_call(r: &mut Room, id: Uuid) {
-    if !r.in_betting || r.phase != Phase::Acting {
-        return;
-    }
-    let seat = match game::seat_of(r, id) {
-        Some(s) => s,
-        None => return,
+    let seat = match game::can_call(r, id) {
+        Ok(s) => s,
+        Err((code, e)) => {
+            send_err_code_to(r, id, code, e);
+            return;
+        }
     };
-    if r.to_act_seat != seat || r.players[seat].folded {
-        return;
-    }
-    if r.current_bet == 0 {
-        return;
-    }
 
     let need = r.current_bet - r.players[seat].committed_round;
-    commit(r, seat, need);
+    let paid = commit(r, seat, need);
+    record_action(r, seat, ActionKind::Call, paid);
     r.betting_acted[seat] = true;
     advance_betting_turn(r);
 }
 
-fn commit(r: &mut Room, seat: usize, amount: u64) {
+fn commit(r: &mut Room, seat: usize, amount: u64) -> u64 {
     if amount == 0 {
-        return;
+        return 0;
     }
     let p = &mut r.players[seat];
     let pay = amount.min(p.chips);
     p.chips -= pay;
     p.committed_round += pay;
+    *r.contributed.entry(p.id).or_default() += pay;
     r.pot += pay;
+    pay
 }
 
 fn advance_betting_turn(r: &mut Room) {
@@ -1259,17 +2170,33 @@ fn advance_betting_turn(r: &mut Room) {
             break;
         }
     }
+    if run_pre_action(r) {
+        return;
+    }
+    start_turn_clock(r);
     broadcast_state(r);
 }
 
 fn end_betting_round(r: &mut Room) {
     r.in_betting = false;
+    // Pre-actions last one street; the client re-sends a standing one
+    r.pre_actions.clear();
+
+    if r.game_variant.uses_community_cards() {
+        end_community_street(r);
+        return;
+    }
 
     // If all remaining players are standing → showdown, else next draw round
     if r.players.iter().all(|p| p.folded || p.standing) {
         do_showdown(r);
         return;
     }
+    if r.max_draw_rounds.is_some_and(|cap| r.round >= cap) {
+        eprintln!("[ROUND] draw round cap {} reached → showdown", r.round);
+        do_showdown(r);
+        return;
+    }
 
     // new draw round: only non-standing, non-folded act
     r.round += 1;
@@ -1282,12 +2209,163 @@ fn end_betting_round(r: &mut Room) {
         })
         .collect();
 
+    start_turn_clock(r);
     broadcast_state(r);
 }
 
+/// Community games: deal the next street and bet again, or go to showdown
+/// after the river. Once at most one player can still bet (the rest are
+/// all-in) there is nobody to bet against, so the board is run out.
+fn end_community_street(r: &mut Room) {
+    if r.deck.is_none() {
+        eprintln!("[STREET] no deck, hand already over");
+        return;
+    }
+    let board = r.game_variant.community_cards();
+    let can_bet = r.players.iter().filter(|p| !p.folded && p.chips > 0).count();
+    if r.community_cards.len() < board && can_bet > 1 {
+        deal_community(r, 1);
+        r.round += 1;
+        start_betting_round(r);
+        return;
+    }
+
+    let missing = board - r.community_cards.len().min(board);
+    if missing > 0 {
+        eprintln!("[ALLIN] running out {} community cards", missing);
+        deal_community(r, missing);
+    }
+    do_showdown(r);
+}
+
+fn deal_community(r: &mut Room, count: usize) {
+    for _ in 0..count {
+        match r.deck.as_mut().and_then(|d| d.draw(true)) {
+            Some(card) => r.community_cards.push(card),
+            None => {
+                eprintln!("[DEAL] no card left for the board");
+                return;
+            }
+        }
+    }
+    let sound = check_deck_integrity(r, "board");
+    debug_assert!(sound, "duplicate card on the board");
+}
+
+/// Invariant: no card is in play twice. Logs every duplicate loudly and
+/// returns false; callers `debug_assert!` on it so tests fail hard while
+/// a live table keeps running.
+fn check_deck_integrity(r: &Room, after: &str) -> bool {
+    let dups = game::duplicate_cards_in_play(r);
+    if dups.is_empty() {
+        return true;
+    }
+    eprintln!(
+        "[INTEGRITY] !!! room {} has duplicate cards in play after {}: {:?}",
+        r.name, after, dups
+    );
+    false
+}
+
 /* ---------------- showdown / payouts ---------------- */
 
+/// Community games split the pot into a main pot and side pots by what each
+/// player put in, and pay each one to the best hands among the players who
+/// covered it. Everyone who won a share is reported in `winners7`.
+fn do_community_showdown(r: &mut Room) {
+    let hands: Vec<(Uuid, HandRank)> = r
+        .players
+        .iter()
+        .filter(|p| !p.folded)
+        .filter_map(|p| {
+            eval::best_community_hand(r.game_variant, &p.down_cards, &r.community_cards).map(|h| (p.id, h))
+        })
+        .collect();
+
+    let mut payouts: Vec<(Uuid, u64)> = vec![];
+    for (amount, eligible) in side_pots(r) {
+        let mut winners: Vec<Uuid> = vec![];
+        let mut best: Option<&HandRank> = None;
+        for (id, hand) in hands.iter().filter(|(id, _)| eligible.contains(id)) {
+            match best.map(|b| eval::compare(hand, b)) {
+                None | Some(std::cmp::Ordering::Greater) => {
+                    best = Some(hand);
+                    winners = vec![*id];
+                }
+                Some(std::cmp::Ordering::Equal) => winners.push(*id),
+                Some(std::cmp::Ordering::Less) => {}
+            }
+        }
+        if winners.is_empty() {
+            continue;
+        }
+
+        // Winners are in seat order; the first one takes any odd chips
+        let each = amount / winners.len() as u64;
+        let odd = amount % winners.len() as u64;
+        for (i, id) in winners.iter().enumerate() {
+            let share = if i == 0 { each + odd } else { each };
+            match payouts.iter_mut().find(|(p, _)| p == id) {
+                Some((_, total)) => *total += share,
+                None => payouts.push((*id, share)),
+            }
+        }
+    }
+
+    for (id, share) in &payouts {
+        if let Some(p) = r.players.iter_mut().find(|p| p.id == *id) {
+            p.chips += *share;
+        }
+    }
+    let winners = r.players.iter().filter(|p| payouts.iter().any(|(id, _)| *id == p.id)).map(|p| p.id).collect();
+
+    finish_showdown(r, winners, vec![], payouts);
+}
+
+/// Main pot first, then each side pot, with the live players who can win it.
+/// A pot layer ends at each live player's total for the hand; folded chips
+/// above the last layer go to the last pot. Rake comes off the main pot, as
+/// do chips in `r.pot` that no player is recorded as putting in.
+fn side_pots(r: &Room) -> Vec<(u64, Vec<Uuid>)> {
+    let put_in = |id: &Uuid| r.contributed.get(id).copied().unwrap_or(0);
+    let mut levels: Vec<u64> = r.players.iter().filter(|p| !p.folded).map(|p| put_in(&p.id)).collect();
+    levels.sort_unstable();
+    levels.dedup();
+
+    let mut pots: Vec<(u64, Vec<Uuid>)> = vec![];
+    let mut prev = 0;
+    for level in levels {
+        let amount = r.contributed.values().map(|c| (*c).min(level) - (*c).min(prev)).sum();
+        let eligible = r.players.iter().filter(|p| !p.folded && put_in(&p.id) >= level).map(|p| p.id).collect();
+        pots.push((amount, eligible));
+        prev = level;
+    }
+    let Some(last) = pots.last_mut() else {
+        return pots;
+    };
+    last.0 += r.contributed.values().map(|c| c.saturating_sub(prev)).sum::<u64>();
+
+    let tracked: u64 = pots.iter().map(|(amount, _)| amount).sum();
+    if r.pot >= tracked {
+        pots[0].0 += r.pot - tracked;
+    } else {
+        let mut rake = tracked - r.pot;
+        for (amount, _) in pots.iter_mut() {
+            let cut = rake.min(*amount);
+            *amount -= cut;
+            rake -= cut;
+        }
+    }
+    pots
+}
+
 fn do_showdown(r: &mut Room) {
+    take_rake(r);
+    if r.game_variant.uses_community_cards() {
+        do_community_showdown(r);
+        return;
+    }
+
     let evals: Vec<_> = r
         .players
         .iter()
@@ -1350,30 +2428,203 @@ fn do_showdown(r: &mut Room) {
         }
     }
 
-    reveal_and_reset(r, winners7, winners27);
+    finish_showdown(r, winners7, winners27, payouts);
+}
+
+/// Players who reached showdown without winning either pot.
+fn showdown_losers(r: &Room, winners7: &[Uuid], winners27: &[Uuid]) -> Vec<Uuid> {
+    r.players
+        .iter()
+        .filter(|p| !p.folded && !winners7.contains(&p.id) && !winners27.contains(&p.id))
+        .map(|p| p.id)
+        .collect()
+}
+
+/// Reveal now, or, with a muck window, hold the reveal in `Phase::Showdown`
+/// so losing players can muck first.
+fn finish_showdown(r: &mut Room, winners7: Vec<Uuid>, winners27: Vec<Uuid>, payouts: Vec<(Uuid, u64)>) {
+    let losers = showdown_losers(r, &winners7, &winners27);
+    let Some(window) = r.muck_window.filter(|_| !losers.is_empty()) else {
+        reveal_and_reset(r, winners7, winners27, payouts);
+        return;
+    };
+
+    eprintln!("[MUCK] {} losing hands may muck for {}s", losers.len(), window.as_secs());
+    r.phase = Phase::Showdown;
+    r.turn_started_at = None;
+    r.pending_showdown = Some(game::PendingShowdown {
+        winners7,
+        winners27,
+        payouts,
+        deadline: std::time::Instant::now() + window,
+    });
+    for p in r.players.iter().filter(|p| losers.contains(&p.id)) {
+        let _ = p.tx.send(ServerToClient::Info {
+            message: format!("Your hand doesn't win. Muck within {}s to keep it hidden.", window.as_secs()),
+        });
+    }
+    broadcast_state(r);
+}
+
+fn player_muck(r: &mut Room, id: Uuid) {
+    let Some(pending) = r.pending_showdown.as_ref() else {
+        send_err_to(r, id, "You can only muck at showdown.");
+        return;
+    };
+    let losers = showdown_losers(r, &pending.winners7, &pending.winners27);
+    if !losers.contains(&id) {
+        send_err_to(r, id, "Only a losing hand at showdown can be mucked.");
+        return;
+    }
+    if r.mucked.contains(&id) {
+        return;
+    }
+
+    r.mucked.push(id);
+    let name = r.players.iter().find(|p| p.id == id).map(|p| p.name.clone()).unwrap_or_default();
+    eprintln!("[MUCK] {} mucks", name);
+    for p in r.players.iter() {
+        let _ = p.tx.send(ServerToClient::Info { message: format!("{} mucks", name) });
+    }
+
+    if losers.iter().all(|l| r.mucked.contains(l)) {
+        close_muck_window(r);
+    }
+}
+
+fn close_muck_window(r: &mut Room) {
+    if let Some(pending) = r.pending_showdown.take() {
+        reveal_and_reset(r, pending.winners7, pending.winners27, pending.payouts);
+    }
+}
+
+/// Turn the hands over once the muck window's deadline has passed. `now`
+/// is passed in so tests can drive the clock.
+fn expire_muck_window(r: &mut Room, now: std::time::Instant) {
+    if r.pending_showdown.as_ref().is_some_and(|p| p.deadline <= now) {
+        close_muck_window(r);
+    }
+}
+
+/// Hands turned over at showdown: everyone still in who didn't muck, plus
+/// folded players who chose to show. Other folded hands stay private.
+fn showdown_reveal(r: &Room) -> Vec<(Uuid, Vec<Card>)> {
+    r.players
+        .iter()
+        .filter(|p| (!p.folded && !r.mucked.contains(&p.id)) || r.shown_cards.contains(&p.id))
+        .map(|p| (p.id, game::all_cards(p)))
+        .collect()
+}
+
+/// House cut: `rake_percent` of the pot, at most `rake_cap`, comes off
+/// before anything is paid out.
+fn take_rake(r: &mut Room) {
+    let mut rake = r.pot * r.rake_percent / 100;
+    if let Some(cap) = r.rake_cap {
+        rake = rake.min(cap);
+    }
+    if rake == 0 {
+        return;
+    }
+    r.pot -= rake;
+    r.raked_total += rake;
+    eprintln!("[RAKE] {} from the pot, {} raked so far", rake, r.raked_total);
 }
 
 fn award_last_player_and_reset(r: &mut Room) {
+    take_rake(r);
+    let mut payouts = vec![];
     if let Some((seat, _)) = alive_seats(r).first() {
         let id = r.players[*seat].id;
         if let Some(p) = r.players.iter_mut().find(|p| p.id == id) {
             p.chips += r.pot;
+            payouts.push((id, r.pot));
+        }
+    }
+    hold_rabbit_board(r);
+    reveal_and_reset(r, vec![], vec![], payouts);
+}
+
+/// Tables with rabbit hunting keep the cards the board would have drawn
+/// next, before the deck is thrown away.
+fn hold_rabbit_board(r: &mut Room) {
+    let board = r.game_variant.community_cards();
+    if !r.rabbit_hunt || r.community_cards.len() >= board {
+        return;
+    }
+    let Some(mut deck) = r.deck.clone() else {
+        return;
+    };
+    let cards: Vec<Card> = (r.community_cards.len()..board).filter_map(|_| deck.draw(true)).collect();
+    eprintln!("[RABBIT] holding {} undealt board cards", cards.len());
+    r.rabbit_board = Some((cards, std::time::Instant::now() + RABBIT_HUNT_WINDOW));
+    for p in r.players.iter() {
+        let _ = p.tx.send(ServerToClient::Info {
+            message: format!("Hand over early. Rabbit hunt within {}s to see the rest of the board.", RABBIT_HUNT_WINDOW.as_secs()),
+        });
+    }
+}
+
+/// Show everyone the board that would have come. One hunt per hand, and
+/// only while the window is open. `now` is passed in so tests can drive the
+/// clock.
+fn player_rabbit_hunt(r: &mut Room, id: Uuid, now: std::time::Instant) {
+    let Some(name) = r.players.iter().find(|p| p.id == id).map(|p| p.name.clone()) else {
+        send_err_to(r, id, "Only seated players can rabbit hunt.");
+        return;
+    };
+    let cards = match r.rabbit_board.take() {
+        Some((cards, deadline)) if now < deadline => cards,
+        Some(_) => {
+            send_err_to(r, id, "The rabbit hunt window has closed.");
+            broadcast_state(r);
+            return;
+        }
+        None => {
+            send_err_to(r, id, "There's no board to rabbit hunt.");
+            return;
         }
+    };
+
+    let shown: Vec<String> = cards.iter().map(|c| c.to_string()).collect();
+    eprintln!("[RABBIT] {} hunts: {}", name, shown.join(" "));
+    let message = format!("{} rabbit hunts: {}", name, shown.join(" "));
+    for p in r.players.iter() {
+        let _ = p.tx.send(ServerToClient::Info { message: message.clone() });
+    }
+    for s in r.spectators.iter() {
+        let _ = s.tx.send(ServerToClient::Info { message: message.clone() });
+    }
+    broadcast_state(r);
+}
+
+/// Throw the held board away once the rabbit hunt window has passed, so
+/// the table stops offering the hunt. `now` is passed in so tests can drive
+/// the clock.
+fn expire_rabbit_hunt(r: &mut Room, now: std::time::Instant) {
+    if r.rabbit_board.as_ref().is_some_and(|(_, deadline)| *deadline <= now) {
+        r.rabbit_board = None;
+        eprintln!("[RABBIT] window closed in room {}", r.name);
+        broadcast_state(r);
     }
-    reveal_and_reset(r, vec![], vec![]);
 }
 
-fn reveal_and_reset(r: &mut Room, winners7: Vec<Uuid>, winners27: Vec<Uuid>) {
-    let reveal: Vec<(Uuid, Vec<Card>)> = r.players.iter().map(|p| (p.id, game::all_cards(p))).collect();
+fn reveal_and_reset(r: &mut Room, winners7: Vec<Uuid>, winners27: Vec<Uuid>, payouts: Vec<(Uuid, u64)>) {
+    let reveal = showdown_reveal(r);
     for p in r.players.iter() {
         let _ = p.tx.send(ServerToClient::Showdown {
             winners7: winners7.clone(),
             winners27: winners27.clone(),
-            payouts: vec![],
+            payouts: payouts.clone(),
             reveal: reveal.clone(),
+            actions: r.actions.clone(),
         });
     }
 
+    if let Some(started) = r.hand_started_at.take() {
+        r.stats.record_hand(started.elapsed(), r.pot);
+    }
+
     // Rotate dealer to the next player (to the left)
     let old_dealer_seat = r.dealer_seat;
     r.dealer_seat = (r.dealer_seat + 1) % r.players.len();
@@ -1403,14 +2654,18 @@ fn reveal_and_reset(r: &mut Room, winners7: Vec<Uuid>, winners27: Vec<Uuid>) {
 
     // Transition to Comments phase
     r.phase = Phase::Comments;
+    r.comments_deadline = r.comments_timeout.map(|t| std::time::Instant::now() + t);
+    r.comments_warned = false;
 
     // Reset game state
     r.pot = 0;
+    r.contributed.clear();
     r.deck = None;
     r.in_betting = false;
     r.current_bet = 0;
     r.round = 0;
     r.raises_made = 0;
+    r.turn_started_at = None;
 
     // Reset all player states for next game
     for player in r.players.iter_mut() {
@@ -1422,7 +2677,12 @@ fn reveal_and_reset(r: &mut Room, winners7: Vec<Uuid>, winners27: Vec<Uuid>) {
         player.committed_round = 0;
     }
 
+    // Players who dropped out during the hand give up their seats now
+    let departed = std::mem::take(&mut r.departed);
+    r.players.retain(|p| !departed.contains(&p.id));
+
     broadcast_state(r);
+    seat_queued_spectators(r);
 }
 
 /* ---------------- public snapshot & broadcast ---------------- */
@@ -1453,6 +2713,16 @@ fn broadcast_state(r: &game::Room) {
         }
     }
 
+    // Tell the player to act what they can do
+    if r.phase == Phase::Acting {
+        if let Some(p) = r.players.get(r.to_act_seat) {
+            let options = game::turn_options(r, r.to_act_seat);
+            if options != TurnOptions::default() {
+                let _ = p.tx.send(ServerToClient::YourTurn { options });
+            }
+        }
+    }
+
     // Also broadcast to spectators
     for (i, s) in r.spectators.iter().enumerate() {
         if s.tx
@@ -1489,13 +2759,23 @@ fn send_state_to(r: &game::Room, pid: Uuid) {
     }
 }
 fn send_err_to(r: &Room, pid: Uuid, msg: impl Into<String>) {
+    send_err_code_to(r, pid, ErrorCode::Other, msg);
+}
+
+fn send_err_code_to(r: &Room, pid: Uuid, code: ErrorCode, msg: impl Into<String>) {
     let msg = msg.into();
-    eprintln!("[server validation] {}", msg); // <--- ADD THIS LINE
+    eprintln!("[server validation] {:?}: {}", code, msg);
     if let Some(p) = r.players.iter().find(|p| p.id == pid) {
-        let _ = p.tx.send(ServerToClient::Error { message: msg });
+        let _ = p.tx.send(ServerToClient::Error { message: msg, code });
     }
 }
 
+/// Players needed before the table elects a dealer: the table minimum, but
+/// never below `ELECTED_DEALER_MIN_PLAYERS`.
+fn election_min_players(r: &Room) -> usize {
+    r.min_players.max(ELECTED_DEALER_MIN_PLAYERS)
+}
+
 fn handle_elect_to_start(r: &mut Room, player_id: Uuid) {
     // Verify player is in the room
     if game::seat_of(r, player_id).is_none() {
@@ -1503,15 +2783,16 @@ fn handle_elect_to_start(r: &mut Room, player_id: Uuid) {
         return;
     }
 
-    // Check minimum 4 players requirement
-    if r.players.len() < 4 {
-        send_err_to(r, player_id, "Minimum 4 players required to start game.");
+    let needed = election_min_players(r);
+    if r.players.len() < needed {
+        send_err_to(r, player_id, format!("Minimum {} players required to start game.", needed));
         return;
     }
 
-    // Only allow election in Lobby phase
-    if r.phase != Phase::Lobby {
-        send_err_to(r, player_id, "Can only elect to start when in lobby phase.");
+    // Only allow election between games: the lobby, or waiting for a dealer
+    // after the comments phase
+    if !matches!(r.phase, Phase::Lobby | Phase::WaitingForDealer) {
+        send_err_to(r, player_id, "Can only elect to start between games.");
         return;
     }
 
@@ -1525,12 +2806,30 @@ fn handle_elect_to_start(r: &mut Room, player_id: Uuid) {
     if r.elected_players.len() == r.players.len() {
         eprintln!("[DEALER] All players elected, moving to dealer selection phase");
         r.phase = Phase::DealerSelection;
+
+        if r.auto_dealer {
+            if let Some(dealer) = natural_dealer(r) {
+                eprintln!("[DEALER] auto_dealer picks {}", &dealer.to_string()[..8]);
+                handle_delegate_dealer(r, dealer, dealer);
+                return;
+            }
+        }
     }
 
     // Broadcast updated state
     broadcast_to_room(r);
 }
 
+/// The obvious dealer when nobody needs to choose: the player after the last
+/// dealer if one is still seated, otherwise whoever elected to start first.
+fn natural_dealer(r: &Room) -> Option<Uuid> {
+    let last_dealer_seat = r.current_dealer_id.and_then(|id| game::seat_of(r, id));
+    match last_dealer_seat {
+        Some(seat) => game::next_dealer_left_of(r, seat),
+        None => r.elected_players.first().copied(),
+    }
+}
+
 fn handle_delegate_dealer(r: &mut Room, requesting_player_id: Uuid, dealer_id: Uuid) {
     // Verify requesting player is in the room
     if game::seat_of(r, requesting_player_id).is_none() {
@@ -1566,8 +2865,50 @@ fn handle_delegate_dealer(r: &mut Room, requesting_player_id: Uuid, dealer_id: U
         }
     }
 
-    // Broadcast updated state
-    broadcast_to_room(r);
+    // Broadcast updated state
+    broadcast_to_room(r);
+}
+
+fn handle_select_game_variant(r: &mut Room, player_id: Uuid, variant: GameVariant) {
+    // Verify player is in the room
+    if game::seat_of(r, player_id).is_none() {
+        send_err_to(r, player_id, "You must be in the room to select game variant.");
+        return;
+    }
+
+    // In dealer's choice the game is the current dealer's call alone
+    if r.dealers_choice && r.current_dealer_id != Some(player_id) {
+        send_err_to(r, player_id, "Dealer's choice: only the dealer can pick the game.");
+        return;
+    }
+
+    // Allow variant selection in the lobby, or between hands so a table can
+    // rotate games without going back to the lobby
+    let info_msg = match r.phase {
+        Phase::Lobby => format!("Game variant changed to {}", variant),
+        Phase::Comments => format!("Next hand will be {}", variant),
+        _ => {
+            send_err_to(r, player_id, "Game variant can only be changed in the lobby or between hands.");
+            return;
+        }
+    };
+
+    // Update the game variant; start_hand deals according to it. The last
+    // hand's board only means something to the game that dealt it.
+    if r.game_variant != variant {
+        r.community_cards.clear();
+    }
+    r.game_variant = variant;
+
+    // Notify all players about the variant change
+    for p in r.players.iter() {
+        let _ = p.tx.send(ServerToClient::Info {
+            message: info_msg.clone(),
+        });
+    }
+
+    eprintln!("[VARIANT] Room {} changed to {} (phase={:?})", r.name, variant, r.phase);
+    broadcast_state(r);
 }
 
 fn handle_choose_game_variant(r: &mut Room, player_id: Uuid, variant: GameVariant) {
@@ -1606,6 +2947,7 @@ fn handle_choose_game_variant(r: &mut Room, player_id: Uuid, variant: GameVarian
 
     // Reset election state for next game
     r.elected_players.clear();
+    r.variant_choice_deadline = None;
 
     // Move to dealing phase and start the game
     r.phase = Phase::Dealing;
@@ -1615,10 +2957,187 @@ fn handle_choose_game_variant(r: &mut Room, player_id: Uuid, variant: GameVarian
     broadcast_to_room(r);
 }
 
+fn handle_set_dealers_choice(r: &mut Room, player_id: Uuid, enabled: bool) {
+    // Verify player is in the room
+    if game::seat_of(r, player_id).is_none() {
+        send_err_to(r, player_id, "You must be in the room to change dealer's choice.");
+        return;
+    }
+
+    // Table mode can only change outside of a hand
+    if r.phase != Phase::Lobby && r.phase != Phase::Comments {
+        send_err_to(r, player_id, "Dealer's choice can only be changed in the lobby or between hands.");
+        return;
+    }
+
+    r.dealers_choice = enabled;
+
+    let info_msg = if enabled {
+        "Dealer's choice on: each new dealer picks the next game".to_string()
+    } else {
+        "Dealer's choice off".to_string()
+    };
+    for p in r.players.iter() {
+        let _ = p.tx.send(ServerToClient::Info {
+            message: info_msg.clone(),
+        });
+    }
+
+    eprintln!("[DEALERS_CHOICE] Room {} dealers_choice={}", r.name, enabled);
+    broadcast_state(r);
+}
+
+/// Why a set of table limits is unusable, if it is. Shared by table
+/// creation and mid-session changes.
+fn table_limits_error(
+    ante: u64,
+    limit_small: u64,
+    limit_big: u64,
+    max_raises: u32,
+    caps: TableCaps,
+) -> Option<&'static str> {
+    if ante == 0 || limit_small == 0 || limit_big == 0 || max_raises == 0 {
+        return Some("Table configuration values must be greater than 0");
+    }
+    if limit_big <= limit_small {
+        return Some("Big limit must be greater than small limit");
+    }
+    if caps.max_buyin.is_some_and(|m| m < ante + limit_big) {
+        return Some("Maximum buy-in must cover the ante plus a big bet");
+    }
+    if caps.max_draw_rounds == Some(0) {
+        return Some("Draw round cap must be at least 1");
+    }
+    None
+}
+
+/// Ceilings a table can be created with and change between hands.
+#[derive(Clone, Copy, Default)]
+struct TableCaps {
+    max_buyin: Option<u64>,
+    max_draw_rounds: Option<u32>,
+}
+
+/// Voluntary buy-ins never take a stack past the table's `max_buyin`. Call
+/// after seating a player or topping them up; winnings are never clamped.
+fn clamp_to_max_buyin(r: &mut Room, seat: usize) {
+    if let (Some(cap), Some(p)) = (r.max_buyin, r.players.get_mut(seat)) {
+        p.chips = p.chips.min(cap);
+    }
+}
+
+/// Change the stakes between hands. The new limits apply from the next deal;
+/// a new `max_buyin` caps later buy-ins but leaves current stacks alone, and
+/// a new draw round cap applies from the next hand too.
+fn handle_update_table_config(
+    r: &mut Room,
+    player_id: Uuid,
+    ante: u64,
+    limit_small: u64,
+    limit_big: u64,
+    max_raises: u32,
+    caps: TableCaps,
+) {
+    if !is_dealer_or_host(r, player_id) {
+        send_err_to(r, player_id, "Only the dealer can change the table limits.");
+        return;
+    }
+    if r.phase != Phase::Lobby && r.phase != Phase::Comments {
+        send_err_to(r, player_id, "Table limits can only be changed in the lobby or between hands.");
+        return;
+    }
+    if let Some(e) = table_limits_error(ante, limit_small, limit_big, max_raises, caps) {
+        send_err_to(r, player_id, e);
+        return;
+    }
+
+    r.ante = ante;
+    r.limit_small = limit_small;
+    r.limit_big = limit_big;
+    r.max_raises = max_raises;
+    r.max_buyin = caps.max_buyin;
+    r.max_draw_rounds = caps.max_draw_rounds;
+
+    let mut info_msg = format!(
+        "Stakes changed: ante {}, limits {}/{}, max {} raises",
+        ante, limit_small, limit_big, max_raises
+    );
+    if let Some(cap) = caps.max_draw_rounds {
+        info_msg.push_str(&format!(", showdown after {} rounds", cap));
+    }
+    for p in r.players.iter() {
+        let _ = p.tx.send(ServerToClient::Info {
+            message: info_msg.clone(),
+        });
+    }
+
+    eprintln!("[TABLE_CONFIG] Room {} {}", r.name, info_msg);
+    broadcast_state(r);
+}
+
+/// Give the rotated dealer a window to pick the next game. The returned
+/// deadline identifies this window for `expire_dealers_choice_window`.
+fn open_dealers_choice_window(r: &mut Room) -> std::time::Instant {
+    let deadline = std::time::Instant::now() + DEALERS_CHOICE_WINDOW;
+    r.phase = Phase::GameSelection;
+    r.variant_choice_deadline = Some(deadline);
+
+    if let Some(dealer_id) = r.current_dealer_id {
+        if let Some(dealer) = r.players.iter().find(|p| p.id == dealer_id) {
+            let _ = dealer.tx.send(ServerToClient::Info {
+                message: format!(
+                    "Dealer's choice: pick the next game within {}s or {} continues",
+                    DEALERS_CHOICE_WINDOW.as_secs(),
+                    r.game_variant
+                ),
+            });
+        }
+    }
+
+    eprintln!("[DEALERS_CHOICE] Room {} waiting for dealer to choose", r.name);
+    broadcast_state(r);
+    deadline
+}
+
+/// Start the next hand with the current variant if the dealer let the
+/// window identified by `deadline` run out without choosing.
+fn expire_dealers_choice_window(r: &mut Room, deadline: std::time::Instant) {
+    if r.phase != Phase::GameSelection || r.variant_choice_deadline != Some(deadline) {
+        return;
+    }
+
+    r.variant_choice_deadline = None;
+    r.elected_players.clear();
+
+    let info_msg = format!("Dealer did not choose in time, keeping {}", r.game_variant);
+    for p in r.players.iter() {
+        let _ = p.tx.send(ServerToClient::Info {
+            message: info_msg.clone(),
+        });
+    }
+
+    eprintln!("[DEALERS_CHOICE] Room {} window expired, keeping {}", r.name, r.game_variant);
+    r.phase = Phase::Dealing;
+    start_new_hand(r);
+}
+
+/// Optional settings a new table can be created with, beyond the limits.
+struct TableOptions {
+    min_players: Option<usize>,
+    rake_percent: u64,
+    rake_cap: Option<u64>,
+    caps: TableCaps,
+    deck_count: Option<usize>,
+    spectator_chat_to_players: Option<bool>,
+    muck_window_secs: Option<u64>,
+    auto_dealer: bool,
+    rabbit_hunt: bool,
+}
+
 async fn handle_create_table(
     state: &AppState,
     creator_id: Uuid,
-    joined_room: &mut Option<String>,
+    options: TableOptions,
     tx_out: &mpsc::UnboundedSender<ServerToClient>,
     name: String,
     game_variant: GameVariant,
@@ -1632,21 +3151,66 @@ async fn handle_create_table(
     if trimmed_name.is_empty() {
         let _ = tx_out.send(ServerToClient::Error {
             message: "Table name cannot be empty".to_string(),
+            code: ErrorCode::Other,
         });
         return;
     }
+    let trimmed_name = match cctmog_protocol::names::clean_name(trimmed_name, TABLE_NAME_MAX) {
+        Ok(cleaned) => cleaned,
+        Err(e) => {
+            let _ = tx_out.send(ServerToClient::Error {
+                message: format!("Invalid table name: {}", e),
+                code: ErrorCode::Other,
+            });
+            return;
+        }
+    };
+    let trimmed_name = trimmed_name.as_str();
 
     // Validate table configuration
-    if ante == 0 || limit_small == 0 || limit_big == 0 || max_raises == 0 {
+    if let Some(e) = table_limits_error(ante, limit_small, limit_big, max_raises, options.caps) {
+        let _ = tx_out.send(ServerToClient::Error {
+            message: e.to_string(),
+            code: ErrorCode::Other,
+        });
+        return;
+    }
+    if let Some(n) = options.min_players.filter(|n| !(2..=MAX_PLAYERS).contains(n)) {
+        let _ = tx_out.send(ServerToClient::Error {
+            message: format!("Minimum players must be between 2 and {} (got {})", MAX_PLAYERS, n),
+            code: ErrorCode::Other,
+        });
+        return;
+    }
+    if options.rake_percent > MAX_RAKE_PERCENT {
         let _ = tx_out.send(ServerToClient::Error {
-            message: "Table configuration values must be greater than 0".to_string(),
+            message: format!("Rake must be between 0 and {}% (got {}%)", MAX_RAKE_PERCENT, options.rake_percent),
+            code: ErrorCode::Other,
         });
         return;
     }
 
-    if limit_big <= limit_small {
+    if let Some(secs) = options.muck_window_secs.filter(|&s| s > MAX_MUCK_WINDOW_SECS) {
+        let _ = tx_out.send(ServerToClient::Error {
+            message: format!("Muck window must be at most {}s (got {}s)", MAX_MUCK_WINDOW_SECS, secs),
+            code: ErrorCode::Other,
+        });
+        return;
+    }
+
+    if let Some(n) = options.deck_count.filter(|n| !(1..=MAX_DECKS).contains(n)) {
+        let _ = tx_out.send(ServerToClient::Error {
+            message: format!("Deck count must be between 1 and {} (got {})", MAX_DECKS, n),
+            code: ErrorCode::Other,
+        });
+        return;
+    }
+    // A shoe can deal exact duplicates, which flush and straight logic
+    // doesn't expect
+    if options.deck_count.is_some_and(|n| n > 1) && game_variant.uses_community_cards() {
         let _ = tx_out.send(ServerToClient::Error {
-            message: "Big limit must be greater than small limit".to_string(),
+            message: format!("{} is dealt from a single deck", game_variant),
+            code: ErrorCode::Other,
         });
         return;
     }
@@ -1657,6 +3221,7 @@ async fn handle_create_table(
     if rooms.contains_key(trimmed_name) {
         let _ = tx_out.send(ServerToClient::Error {
             message: format!("Table '{}' already exists", trimmed_name),
+            code: ErrorCode::Other,
         });
         return;
     }
@@ -1668,6 +3233,22 @@ async fn handle_create_table(
     new_room.limit_small = limit_small;
     new_room.limit_big = limit_big;
     new_room.max_raises = max_raises;
+    if let Some(n) = options.min_players {
+        new_room.min_players = n;
+    }
+    new_room.rake_percent = options.rake_percent;
+    new_room.rake_cap = options.rake_cap;
+    new_room.max_buyin = options.caps.max_buyin;
+    new_room.max_draw_rounds = options.caps.max_draw_rounds;
+    if let Some(n) = options.deck_count {
+        new_room.deck_count = n;
+    }
+    if let Some(open) = options.spectator_chat_to_players {
+        new_room.spectator_chat_to_players = open;
+    }
+    new_room.muck_window = options.muck_window_secs.filter(|&s| s > 0).map(std::time::Duration::from_secs);
+    new_room.auto_dealer = options.auto_dealer;
+    new_room.rabbit_hunt = options.rabbit_hunt;
 
     rooms.insert(trimmed_name.to_string(), new_room);
     drop(rooms); // Release the lock
@@ -1688,7 +3269,13 @@ fn start_new_hand(r: &mut game::Room) {
     start_hand(r);
 }
 
-async fn handle_post_comment(state: AppState, player_id: Uuid, joined_room: Option<String>, message: String) {
+async fn handle_post_comment(
+    state: AppState,
+    player_id: Uuid,
+    joined_room: Option<String>,
+    message: String,
+    about: Option<cctmog_protocol::CommentTarget>,
+) {
     use chrono::Utc;
 
     let room = match joined_room {
@@ -1713,12 +3300,25 @@ async fn handle_post_comment(state: AppState, player_id: Uuid, joined_room: Opti
         None => return,
     };
 
+    // A target must point at something from the hand just played
+    let on_record = match &about {
+        None => true,
+        Some(cctmog_protocol::CommentTarget::Round(round)) => room_obj.actions.iter().any(|a| a.round == *round),
+        Some(cctmog_protocol::CommentTarget::Action(action)) => room_obj.actions.contains(action),
+    };
+    if !on_record {
+        eprintln!("[COMMENT] reject: {} commented about something not in the last hand", player_name);
+        send_err_to(room_obj, player_id, "That comment refers to something that didn't happen in the last hand.");
+        return;
+    }
+
     // Create the comment
     let comment = cctmog_protocol::GameComment {
         player_id,
         player_name,
         message,
         timestamp: Utc::now().to_rfc3339(),
+        about,
     };
 
     // Broadcast the comment to all players in the room
@@ -1735,6 +3335,8 @@ async fn handle_continue_to_next_game(state: AppState, player_id: Uuid, joined_r
         None => return,
     };
 
+    let mut choice_window = None;
+
     with_room(&state, &room, |r| {
         // Only allow this action in Comments phase
         if r.phase != cctmog_protocol::Phase::Comments {
@@ -1747,26 +3349,155 @@ async fn handle_continue_to_next_game(state: AppState, player_id: Uuid, joined_r
         }
 
         // Check if all players are ready to continue
-        let all_ready = r.players.iter().all(|p| p.ready);
+        if r.players.iter().all(|p| p.ready) {
+            choice_window = advance_from_comments(r, true);
+        } else {
+            broadcast_state(r); // everyone sees who's still deciding
+        }
+    });
 
-        if all_ready {
-            // Transition to the appropriate next phase
-            if r.players.len() >= 4 {
-                r.phase = cctmog_protocol::Phase::WaitingForDealer;
-            } else {
-                r.phase = cctmog_protocol::Phase::Lobby;
-            }
+    if let Some(deadline) = choice_window {
+        spawn_choice_window_expiry(state, room, deadline);
+    }
+}
 
-            // Reset ready states for next time
-            for player in r.players.iter_mut() {
-                player.ready = false;
-            }
+/// Leave the comments phase for the next game. Returns the dealer's-choice
+/// deadline when a choice window opened instead. Without `everyone_continued`
+/// bigger tables skip the election, which needs every player, and go to the
+/// lobby where the dealer can force-start.
+fn advance_from_comments(r: &mut Room, everyone_continued: bool) -> Option<std::time::Instant> {
+    r.comments_deadline = None;
 
-            broadcast_state(r);
-        }
+    // Reset ready states for next time
+    for player in r.players.iter_mut() {
+        player.ready = false;
+    }
+
+    // Dealer's choice: the rotated dealer picks the next game
+    if r.dealers_choice && r.current_dealer_id.is_some() {
+        return Some(open_dealers_choice_window(r));
+    }
+
+    // Transition to the appropriate next phase
+    if everyone_continued && r.players.len() >= election_min_players(r) {
+        r.phase = cctmog_protocol::Phase::WaitingForDealer;
+    } else {
+        r.phase = cctmog_protocol::Phase::Lobby;
+    }
+
+    broadcast_state(r);
+    None
+}
+
+/// Keep the current variant if the dealer doesn't choose in time
+fn spawn_choice_window_expiry(state: AppState, room: String, deadline: std::time::Instant) {
+    tokio::spawn(async move {
+        tokio::time::sleep(DEALERS_CHOICE_WINDOW).await;
+        with_room(&state, &room, |r| expire_dealers_choice_window(r, deadline));
     });
 }
 
+/// Stalled comments phase: warn `COMMENTS_WARNING` ahead, then move on
+/// without whoever hasn't continued. They stay seated and simply aren't
+/// ready, so the dealer can force-start around them. `now` is passed in so
+/// tests can drive the clock.
+fn expire_comments_phase(r: &mut Room, now: std::time::Instant) -> Option<std::time::Instant> {
+    let deadline = r.comments_deadline?;
+    if r.phase != Phase::Comments {
+        r.comments_deadline = None;
+        return None;
+    }
+
+    let waiting: Vec<String> = r.players.iter().filter(|p| !p.ready).map(|p| p.name.clone()).collect();
+    if now + COMMENTS_WARNING >= deadline && !r.comments_warned {
+        r.comments_warned = true;
+        let info_msg = format!(
+            "Next game in {}s — still waiting for {}",
+            deadline.saturating_duration_since(now).as_secs(),
+            waiting.join(", ")
+        );
+        for p in r.players.iter() {
+            let _ = p.tx.send(ServerToClient::Info { message: info_msg.clone() });
+        }
+    }
+    if now < deadline {
+        return None;
+    }
+
+    eprintln!("[COMMENTS] Room {} timed out waiting for {:?}", r.name, waiting);
+    let info_msg = format!("Moving on without {}", waiting.join(", "));
+    for p in r.players.iter() {
+        let _ = p.tx.send(ServerToClient::Info { message: info_msg.clone() });
+    }
+    advance_from_comments(r, false)
+}
+
+/// Top a short-stacked player back up to `REBUY_CHIPS`, or the table's
+/// `max_buyin` if that's lower. Only between hands so a stack never changes
+/// mid-hand.
+fn handle_rebuy(r: &mut Room, player_id: Uuid) {
+    if r.phase != Phase::Lobby && r.phase != Phase::Comments {
+        send_err_to(r, player_id, "You can rebuy once this hand is over.");
+        return;
+    }
+    let Some(seat) = game::seat_of(r, player_id) else {
+        return;
+    };
+    let top_up = r.max_buyin.map_or(REBUY_CHIPS, |cap| cap.min(REBUY_CHIPS));
+    let before = r.players[seat].chips;
+    if before >= top_up {
+        let msg = format!("Rebuy is only for stacks under {}.", top_up);
+        send_err_to(r, player_id, msg);
+        return;
+    }
+
+    r.players[seat].chips = REBUY_CHIPS;
+    clamp_to_max_buyin(r, seat);
+    let p = &r.players[seat];
+    let info_msg = format!("{} rebought {} chips", p.name, p.chips - before);
+    for p in r.players.iter() {
+        let _ = p.tx.send(ServerToClient::Info {
+            message: info_msg.clone(),
+        });
+    }
+
+    eprintln!("[REBUY] Room {}: {}", r.name, info_msg);
+    broadcast_state(r);
+}
+
+/// Dealer skips the continue/ready round and re-deals straight away with the
+/// same seats, chips and table settings.
+fn handle_rematch(r: &mut Room, player_id: Uuid) {
+    if r.phase != Phase::Comments {
+        send_err_to(r, player_id, "A rematch can only be started between hands.");
+        return;
+    }
+    if r.current_dealer_id != Some(player_id) {
+        send_err_to(r, player_id, "Only the dealer can start a rematch.");
+        return;
+    }
+    if r.players.len() < r.min_players {
+        send_err_to(r, player_id, format!("Need at least {} players to start.", r.min_players));
+        return;
+    }
+
+    r.elected_players.clear();
+    r.variant_choice_deadline = None;
+
+    let dealer_name = r.players.iter()
+        .find(|p| p.id == player_id)
+        .map(|p| p.name.clone())
+        .unwrap_or_default();
+    for p in r.players.iter() {
+        let _ = p.tx.send(ServerToClient::Info {
+            message: format!("{} dealt a rematch of {}", dealer_name, r.game_variant),
+        });
+    }
+
+    eprintln!("[REMATCH] Room {} rematch by {}", r.name, dealer_name);
+    start_new_hand(r);
+}
+
 async fn handle_register_table(state: AppState, name: String, game_variant: cctmog_protocol::GameVariant, _ante: u64, _limit_small: u64, _limit_big: u64, _max_raises: u32, server_port: u16, player_count: usize) {
     println!("[REGISTER] Distributed table '{}' on port {} with {} players", name, server_port, player_count);
 
@@ -1819,6 +3550,131 @@ async fn handle_list_tables(state: AppState, tx_out: &tokio::sync::mpsc::Unbound
     println!("[LIST] Sent {} tables to client", table_count);
 }
 
+/// Current lounge roster, volunteered hosts and host picks.
+fn lounge_update(lounge: &LoungeState) -> ServerToClient {
+    ServerToClient::LoungeUpdate {
+        players: lounge.players.values().map(|p| p.name.clone()).collect(),
+        available_hosts: lounge.players.values()
+            .filter_map(|p| p.hosting_port.map(|port| (p.name.clone(), port)))
+            .collect(),
+        player_selections: lounge.players.values()
+            .map(|p| (p.name.clone(), p.selected_host.as_ref().map(|(name, _)| name.clone())))
+            .collect(),
+    }
+}
+
+fn broadcast_lounge_update(lounge: &LoungeState) {
+    let update = lounge_update(lounge);
+    eprintln!("[LOUNGE] Broadcasting update to {} players", lounge.players.len());
+    for player in lounge.players.values() {
+        let _ = player.tx.send(update.clone());
+    }
+}
+
+/// A lounge member heading to a table leaves the lounge, so the roster
+/// doesn't keep listing them (their disconnect is then handled by the table).
+async fn leave_lounge_for_table(state: &AppState, joined_room: &Option<String>, player_id: Uuid) {
+    if joined_room.as_deref() == Some("lounge") {
+        handle_leave_lounge(state.clone(), player_id).await;
+    }
+}
+
+/// The player's name cleaned up by the shared name rules, or `None` after
+/// telling them why it was refused. Same check the client runs on entry.
+fn checked_player_name(name: &str, tx_out: &mpsc::UnboundedSender<ServerToClient>) -> Option<String> {
+    match cctmog_protocol::names::clean_name(name, cctmog_protocol::names::NAME_MAX) {
+        Ok(cleaned) => Some(cleaned),
+        Err(e) => {
+            eprintln!("[JOIN] rejected name {:?}: {}", name, e);
+            let _ = tx_out.send(ServerToClient::Error { message: e, code: ErrorCode::Other });
+            None
+        }
+    }
+}
+
+/// `name` if nobody else in the room (seated or watching) uses it, ignoring
+/// case. Otherwise the first free "name (2)", "name (3)", … when `suffix`
+/// is set, or an error to send back.
+fn unique_name_in_room(r: &Room, id: Uuid, name: &str, suffix: bool) -> Result<String, String> {
+    let taken = |candidate: &str| {
+        r.players.iter().map(|p| (p.id, &p.name))
+            .chain(r.spectators.iter().map(|s| (s.id, &s.name)))
+            .any(|(other, n)| other != id && n.to_lowercase() == candidate.to_lowercase())
+    };
+    if !taken(name) {
+        return Ok(name.to_string());
+    }
+    if !suffix {
+        return Err(format!("Someone at this table is already called {}. Pick another name.", name));
+    }
+    Ok((2..)
+        .map(|n| format!("{} ({})", name, n))
+        .find(|candidate| !taken(candidate))
+        .expect("a free suffix exists"))
+}
+
+/// The name this player goes by in the room, suffixed or refused per
+/// `SUFFIX_DUPLICATE_NAMES` when it's taken. `None` once they've been told why.
+fn room_name_for(r: &Room, id: Uuid, name: &str, tx_out: &mpsc::UnboundedSender<ServerToClient>) -> Option<String> {
+    match unique_name_in_room(r, id, name, SUFFIX_DUPLICATE_NAMES) {
+        Ok(resolved) => {
+            if resolved != name {
+                eprintln!("[JOIN] {} already at {}, joining as {}", name, r.name, resolved);
+                let _ = tx_out.send(ServerToClient::Info {
+                    message: format!("Someone here is already called {}, so you're {}.", name, resolved),
+                });
+            }
+            Some(resolved)
+        }
+        Err(e) => {
+            let _ = tx_out.send(ServerToClient::Error { message: e, code: ErrorCode::Other });
+            None
+        }
+    }
+}
+
+/// Everyone connected here: lounge members first, then whoever is seated at
+/// or watching a table, each group sorted by name.
+fn lounge_roster(state: &AppState) -> Vec<cctmog_protocol::LoungePlayer> {
+    let mut in_lounge: Vec<_> = state.lounge.lock().players.values()
+        .map(|p| cctmog_protocol::LoungePlayer {
+            name: p.name.clone(),
+            hosting: p.hosting_port.is_some(),
+            room: None,
+        })
+        .collect();
+    in_lounge.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut at_tables: Vec<_> = state.inner.lock().values()
+        .flat_map(|r| {
+            let names = r.players.iter().map(|p| p.name.clone()).chain(r.spectators.iter().map(|s| s.name.clone()));
+            names.map(|name| cctmog_protocol::LoungePlayer { name, hosting: false, room: Some(r.name.clone()) })
+        })
+        .collect();
+    at_tables.sort_by(|a, b| a.name.cmp(&b.name));
+
+    in_lounge.extend(at_tables);
+    in_lounge
+}
+
+/// Send back the latest lounge-wide chat (Group and Global merged), oldest
+/// first, so a returning player can catch up.
+async fn handle_fetch_chat_history(state: &AppState, limit: usize, tx_out: &mpsc::UnboundedSender<ServerToClient>) {
+    let limit = limit.min(CHAT_HISTORY_MAX);
+    let mut messages = vec![];
+    for scope in [MessageScope::Group, MessageScope::Global] {
+        match state.message_store.get_messages(scope, None, None, Some(limit)).await {
+            Ok(found) => messages.extend(found),
+            Err(e) => eprintln!("[HISTORY] failed to read {:?} messages: {}", scope, e),
+        }
+    }
+    messages.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+    let skip = messages.len().saturating_sub(limit);
+    messages.drain(..skip);
+    eprintln!("[HISTORY] sending {} messages", messages.len());
+    let _ = tx_out.send(ServerToClient::ChatHistory { messages });
+}
+
 async fn handle_join_lounge(state: AppState, player_id: Uuid, name: String, tx_out: mpsc::UnboundedSender<ServerToClient>) {
     eprintln!("[LOUNGE] {} (id={}) joining lounge", name, player_id);
 
@@ -1844,6 +3700,7 @@ async fn handle_join_lounge(state: AppState, player_id: Uuid, name: String, tx_o
             if name_exists {
                 let error_msg = ServerToClient::Error {
                     message: format!("Name '{}' is already taken. Please choose a different name.", name),
+                    code: ErrorCode::Other,
                 };
                 let _ = tx_out.send(error_msg);
                 eprintln!("[LOUNGE] Rejected {} (id={}) - name already taken by another player", name, player_id);
@@ -1894,24 +3751,7 @@ async fn handle_join_lounge(state: AppState, player_id: Uuid, name: String, tx_o
     // Broadcast lounge update to all players
     {
         let lounge = state.lounge.lock();
-        let players: Vec<String> = lounge.players.values().map(|p| p.name.clone()).collect();
-        let available_hosts: Vec<(String, u16)> = lounge.players.values()
-            .filter_map(|p| p.hosting_port.map(|port| (p.name.clone(), port)))
-            .collect();
-        let player_selections: Vec<(String, Option<String>)> = lounge.players.values()
-            .map(|p| (p.name.clone(), p.selected_host.as_ref().map(|(name, _)| name.clone())))
-            .collect();
-        let update = ServerToClient::LoungeUpdate {
-            players: players.clone(),
-            available_hosts,
-            player_selections,
-        };
-
-        eprintln!("[LOUNGE] Broadcasting update to {} players: {:?}", lounge.players.len(), players);
-        for player in lounge.players.values() {
-            eprintln!("[LOUNGE] Sending LoungeUpdate to {} (id={})", player.name, player.id);
-            let _ = player.tx.send(update.clone());
-        }
+        broadcast_lounge_update(&lounge);
 
         eprintln!("[LOUNGE] {} joined, {} players total", name, lounge.players.len());
     }
@@ -1926,22 +3766,7 @@ async fn handle_leave_lounge(state: AppState, player_id: Uuid) {
     lounge.players.remove(&player_id);
 
     // Broadcast lounge update to remaining players
-    let players: Vec<String> = lounge.players.values().map(|p| p.name.clone()).collect();
-    let available_hosts: Vec<(String, u16)> = lounge.players.values()
-        .filter_map(|p| p.hosting_port.map(|port| (p.name.clone(), port)))
-        .collect();
-    let player_selections: Vec<(String, Option<String>)> = lounge.players.values()
-        .map(|p| (p.name.clone(), p.selected_host.as_ref().map(|(name, _)| name.clone())))
-        .collect();
-    let update = ServerToClient::LoungeUpdate {
-        players: players.clone(),
-        available_hosts,
-        player_selections,
-    };
-
-    for player in lounge.players.values() {
-        let _ = player.tx.send(update.clone());
-    }
+    broadcast_lounge_update(&lounge);
 
     if let Some(name) = leaving_player_name {
         eprintln!("[LOUNGE] {} left, {} players remaining", name, lounge.players.len());
@@ -1957,22 +3782,7 @@ async fn handle_volunteer_to_host(state: AppState, player_id: Uuid, port: u16) {
     }
 
     // Broadcast updated host list to all players
-    let players: Vec<String> = lounge.players.values().map(|p| p.name.clone()).collect();
-    let available_hosts: Vec<(String, u16)> = lounge.players.values()
-        .filter_map(|p| p.hosting_port.map(|port| (p.name.clone(), port)))
-        .collect();
-    let player_selections: Vec<(String, Option<String>)> = lounge.players.values()
-        .map(|p| (p.name.clone(), p.selected_host.as_ref().map(|(name, _)| name.clone())))
-        .collect();
-    let update = ServerToClient::LoungeUpdate {
-        players,
-        available_hosts,
-        player_selections,
-    };
-
-    for player in lounge.players.values() {
-        let _ = player.tx.send(update.clone());
-    }
+    broadcast_lounge_update(&lounge);
 }
 
 async fn handle_select_host(state: AppState, player_id: Uuid, host_name: String, port: u16) {
@@ -1997,22 +3807,7 @@ async fn handle_select_host(state: AppState, player_id: Uuid, host_name: String,
     let consensus = check_consensus(&all_selections);
 
     // Broadcast updated selections to all players
-    let players: Vec<String> = lounge.players.values().map(|p| p.name.clone()).collect();
-    let available_hosts: Vec<(String, u16)> = lounge.players.values()
-        .filter_map(|p| p.hosting_port.map(|port| (p.name.clone(), port)))
-        .collect();
-    let player_selections: Vec<(String, Option<String>)> = lounge.players.values()
-        .map(|p| (p.name.clone(), p.selected_host.as_ref().map(|(name, _)| name.clone())))
-        .collect();
-    let update = ServerToClient::LoungeUpdate {
-        players,
-        available_hosts,
-        player_selections,
-    };
-
-    for player in lounge.players.values() {
-        let _ = player.tx.send(update.clone());
-    }
+    broadcast_lounge_update(&lounge);
 
     // If consensus reached, send StartGame to all players
     if let Some((consensus_host, consensus_port)) = consensus {
@@ -9,8 +9,9 @@ mod game_tests {
 
     /// Creates a test player with given name, seat, and initial chips
     pub fn create_test_player(name: &str, seat: usize, chips: u64) -> PublicPlayer {
+        let id = Uuid::new_v4();
         PublicPlayer {
-            id: Uuid::new_v4(),
+            id,
             name: name.to_string(),
             seat,
             chips,
@@ -20,6 +21,8 @@ mod game_tests {
             cards_count: 0,
             committed_round: 0,
             ready: false,
+            color_seed: PublicPlayer::color_seed_for(id),
+            status: None,
         }
     }
 
@@ -43,6 +46,9 @@ mod game_tests {
             raises_made: 0,
             max_raises: 3,
             round: 0,
+            round_label: String::new(),
+            street: None,
+            split_pot: false,
             limit_small: 10,
             limit_big: 20,
             community_cards: vec![],
@@ -50,7 +56,15 @@ mod game_tests {
             checked_in_players: vec![],
             elected_players: vec![],
             current_dealer_id: None,
+            current_dealer_name: None,
+            elected_count: 0,
+            rabbit_hunt_open: false,
+            raked_total: 0,
+            showdown_winners: vec![],
             available_variants: vec![GameVariant::SevenTwentySeven, GameVariant::Omaha, GameVariant::TexasHoldem],
+            dealers_choice: false,
+            turn_started_at: None,
+            spectators: vec![],
         }
     }
 
@@ -653,6 +667,9 @@ mod game_tests {
             raises_made: 0,
             max_raises: 3,
             round: 1,
+            round_label: String::new(),
+            street: None,
+            split_pot: false,
             limit_small: 5,
             limit_big: 10,
             community_cards: vec![],
@@ -660,7 +677,15 @@ mod game_tests {
             checked_in_players: vec![],
             elected_players: vec![],
             current_dealer_id: None,
+            current_dealer_name: None,
+            elected_count: 0,
+            rabbit_hunt_open: false,
+            raked_total: 0,
+            showdown_winners: vec![],
             available_variants: vec![GameVariant::SevenTwentySeven, GameVariant::Omaha, GameVariant::TexasHoldem],
+            dealers_choice: false,
+            turn_started_at: None,
+            spectators: vec![],
         };
 
         // Simulate ante collection with varied stacks
@@ -701,6 +726,50 @@ mod server_tests {
     use super::*;
     use crate::game;
 
+    /// Empty server state backed by a throwaway message store. Keep the
+    /// returned dir alive for the duration of the test.
+    fn create_test_state() -> (crate::AppState, tempfile::TempDir) {
+        use std::sync::Arc;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let state = crate::AppState {
+            inner: Arc::new(parking_lot::Mutex::new(HashMap::new())),
+            message_store: Arc::new(crate::messages::MessageStore::new(temp_dir.path().to_str().unwrap()).unwrap()),
+            distributed_tables: Arc::new(parking_lot::Mutex::new(HashMap::new())),
+            lounge: Arc::new(parking_lot::Mutex::new(crate::LoungeState {
+                players: HashMap::new(),
+            })),
+            socket: crate::SocketConfig::default(),
+        };
+        (state, temp_dir)
+    }
+
+    /// Seats a ready player with `chips` at the next seat and returns their id.
+    fn seat(room: &mut game::Room, name: &str, chips: u64) -> Uuid {
+        seat_with_rx(room, name, chips).0
+    }
+
+    /// Like `seat`, keeping the receiving end of the player's channel for
+    /// tests that check what they were sent.
+    fn seat_with_rx(room: &mut game::Room, name: &str, chips: u64) -> (Uuid, mpsc::UnboundedReceiver<ServerToClient>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let id = Uuid::new_v4();
+        room.players.push(game::PlayerSeat {
+            id,
+            name: name.to_string(),
+            chips,
+            folded: false,
+            standing: false,
+            up_cards: vec![],
+            down_cards: vec![],
+            ready: true,
+            committed_round: 0,
+            status: None,
+            tx,
+        });
+        (id, rx)
+    }
+
     /// Test distributed table registry functionality
     #[test]
     fn test_distributed_table_registry() {
@@ -847,6 +916,7 @@ mod server_tests {
             down_cards: vec![],
             ready: true,
             committed_round: 0,
+            status: None,
             tx: tx.clone(),
         });
 
@@ -860,6 +930,7 @@ mod server_tests {
             down_cards: vec![],
             ready: false,
             committed_round: 50,
+            status: None,
             tx: tx.clone(),
         });
 
@@ -910,20 +981,8 @@ mod server_tests {
     #[test]
     fn test_chip_commitment() {
         let mut room = game::Room::new("Chip Test Room".to_string());
-        let (tx, _rx) = mpsc::unbounded_channel();
 
-        room.players.push(game::PlayerSeat {
-            id: Uuid::new_v4(),
-            name: "Test Player".to_string(),
-            chips: 1000,
-            folded: false,
-            standing: false,
-            up_cards: vec![],
-            down_cards: vec![],
-            ready: true,
-            committed_round: 0,
-            tx,
-        });
+        seat(&mut room, "Test Player", 1000);
 
         let initial_chips = room.players[0].chips;
         let initial_pot = room.pot;
@@ -965,6 +1024,7 @@ mod server_tests {
             down_cards: vec![],
             ready: true,
             committed_round: 0,
+            status: None,
             tx,
         });
 
@@ -1020,6 +1080,7 @@ mod server_tests {
                 down_cards: vec![],
                 ready: true,
                 committed_round: 0,
+                status: None,
                 tx: tx.clone(),
             });
         }
@@ -1047,6 +1108,2999 @@ mod server_tests {
         println!("   - Handles edge cases correctly");
     }
 
+    /// Test that a variant picked between hands applies to the next deal
+    #[test]
+    fn test_variant_change_between_hands() {
+        let mut room = game::Room::new("Dealer's Choice Room".to_string());
+        for i in 0..4 {
+            seat(&mut room, &format!("Player {}", i + 1), 1000);
+            room.players[i].ready = false;
+        }
+        let chooser = room.players[1].id;
+
+        // Mid-hand changes are still rejected
+        room.phase = Phase::Acting;
+        crate::handle_select_game_variant(&mut room, chooser, GameVariant::Omaha);
+        assert_eq!(room.game_variant, GameVariant::SevenTwentySeven);
+
+        // Between hands the change is accepted
+        room.phase = Phase::Comments;
+        crate::handle_select_game_variant(&mut room, chooser, GameVariant::Omaha);
+        assert_eq!(room.game_variant, GameVariant::Omaha);
+        assert_eq!(room.phase, Phase::Comments);
+
+        // The next hand is dealt as Omaha
+        crate::start_hand(&mut room);
+        assert_eq!(room.game_variant, GameVariant::Omaha);
+        assert_eq!(room.community_cards.len(), 3);
+        for p in &room.players {
+            assert_eq!(p.down_cards.len(), 4);
+            assert!(p.up_cards.is_empty());
+        }
+
+        println!("✅ Variant change between hands test passed");
+        println!("   - Rejected during play, accepted in Comments");
+        println!("   - Next hand dealt with the new variant");
+    }
+
+    /// Test that in dealer's choice mode the variant follows each rotated dealer
+    #[test]
+    fn test_dealers_choice_rotation() {
+        let mut room = game::Room::new("Dealer's Choice Rotation".to_string());
+        for i in 0..4 {
+            seat(&mut room, &format!("Player {}", i + 1), 1000);
+            room.players[i].ready = false;
+        }
+        let ids: Vec<Uuid> = room.players.iter().map(|p| p.id).collect();
+
+        room.phase = Phase::Lobby;
+        crate::handle_set_dealers_choice(&mut room, ids[0], true);
+        assert!(room.dealers_choice);
+        assert!(game::public_room(&room).dealers_choice);
+
+        // First hand ends; the button moves to seat 1
+        room.current_dealer_id = Some(ids[0]);
+        crate::start_hand(&mut room);
+        crate::reveal_and_reset(&mut room, vec![], vec![], vec![]);
+        assert_eq!(room.current_dealer_id, Some(ids[1]));
+
+        // New dealer picks Omaha within the window
+        crate::open_dealers_choice_window(&mut room);
+        assert_eq!(room.phase, Phase::GameSelection);
+        crate::handle_choose_game_variant(&mut room, ids[0], GameVariant::TexasHoldem);
+        assert_eq!(room.game_variant, GameVariant::SevenTwentySeven); // old dealer can't choose
+        crate::handle_choose_game_variant(&mut room, ids[1], GameVariant::Omaha);
+        assert_eq!(room.game_variant, GameVariant::Omaha);
+        assert_eq!(room.phase, Phase::Acting);
+        assert!(room.variant_choice_deadline.is_none());
+        assert!(room.players.iter().all(|p| p.down_cards.len() == 4));
+
+        // Next rotation: seat 2 picks Hold'em
+        crate::reveal_and_reset(&mut room, vec![], vec![], vec![]);
+        assert_eq!(room.current_dealer_id, Some(ids[2]));
+        crate::open_dealers_choice_window(&mut room);
+        crate::handle_choose_game_variant(&mut room, ids[2], GameVariant::TexasHoldem);
+        assert_eq!(room.game_variant, GameVariant::TexasHoldem);
+        assert!(room.players.iter().all(|p| p.down_cards.len() == 2));
+
+        // Seat 3 lets the window run out: the current variant is kept
+        crate::reveal_and_reset(&mut room, vec![], vec![], vec![]);
+        assert_eq!(room.current_dealer_id, Some(ids[3]));
+        let deadline = crate::open_dealers_choice_window(&mut room);
+        crate::expire_dealers_choice_window(&mut room, deadline);
+        assert_eq!(room.game_variant, GameVariant::TexasHoldem);
+        assert_eq!(room.phase, Phase::Acting);
+
+        // A stale timer from an earlier window does nothing
+        crate::reveal_and_reset(&mut room, vec![], vec![], vec![]);
+        crate::open_dealers_choice_window(&mut room);
+        crate::expire_dealers_choice_window(&mut room, deadline);
+        assert_eq!(room.phase, Phase::GameSelection);
+
+        println!("✅ Dealer's choice rotation test passed");
+        println!("   - Each rotated dealer picks the next variant");
+        println!("   - Variant kept when the window expires");
+    }
+
+    /// Test that in dealer's choice mode only the dealer can change the variant
+    #[test]
+    fn test_dealers_choice_select_by_dealer_only() {
+        let mut room = game::Room::new("Dealer's Choice Select".to_string());
+        let mut receivers = vec![];
+        for i in 0..3 {
+            receivers.push(seat_with_rx(&mut room, &format!("Player {}", i + 1), 1000).1);
+        }
+        let ids: Vec<Uuid> = room.players.iter().map(|p| p.id).collect();
+        room.dealers_choice = true;
+        room.current_dealer_id = Some(ids[1]);
+        room.phase = Phase::Comments;
+
+        crate::handle_select_game_variant(&mut room, ids[0], GameVariant::Omaha);
+        assert_eq!(room.game_variant, GameVariant::SevenTwentySeven);
+        assert!(std::iter::from_fn(|| receivers[0].try_recv().ok())
+            .any(|m| matches!(m, ServerToClient::Error { message, .. } if message.contains("only the dealer"))));
+
+        crate::handle_select_game_variant(&mut room, ids[1], GameVariant::Omaha);
+        assert_eq!(room.game_variant, GameVariant::Omaha);
+
+        println!("✅ Dealer's choice select test passed");
+        println!("   - Non-dealer's variant pick refused");
+        println!("   - Dealer's pick applies");
+    }
+
+    /// Test that a folded player's ShowCards reaches the rest of the table
+    #[test]
+    fn test_show_cards_after_fold() {
+        let mut room = game::Room::new("Show Cards Room".to_string());
+        let mut receivers = vec![];
+
+        for i in 0..4 {
+            receivers.push(seat_with_rx(&mut room, &format!("Player {}", i + 1), 1000).1);
+        }
+        let folder = room.players[1].id;
+        let stayer = room.players[2].id;
+
+        crate::start_hand(&mut room);
+        let folder_cards = game::all_cards(&room.players[1]);
+
+        // Showing without folding is rejected
+        crate::player_show_cards(&mut room, stayer);
+        crate::player_fold(&mut room, folder);
+        crate::player_show_cards(&mut room, folder);
+
+        let mut shown = vec![];
+        while let Ok(msg) = receivers[0].try_recv() {
+            if let ServerToClient::CardsShown { player_id, player_name, cards } = msg {
+                shown.push((player_id, player_name, cards));
+            }
+        }
+        assert_eq!(shown.len(), 1);
+        let (player_id, player_name, cards) = &shown[0];
+        assert_eq!(*player_id, folder);
+        assert_eq!(player_name, "Player 2");
+        assert_eq!(cards.len(), folder_cards.len());
+        for (shown_card, dealt) in cards.iter().zip(folder_cards.iter()) {
+            assert_eq!((shown_card.rank, shown_card.suit), (dealt.rank, dealt.suit));
+            assert!(shown_card.face_up);
+        }
+
+        let mut stayer_errors = 0;
+        while let Ok(msg) = receivers[2].try_recv() {
+            if let ServerToClient::Error { .. } = msg {
+                stayer_errors += 1;
+            }
+        }
+        assert_eq!(stayer_errors, 1);
+
+        // After the hand resets, the cards are gone and ShowCards is rejected
+        crate::reveal_and_reset(&mut room, vec![], vec![], vec![]);
+        while receivers[0].try_recv().is_ok() {}
+        crate::player_show_cards(&mut room, folder);
+        assert!(!std::iter::from_fn(|| receivers[0].try_recv().ok())
+            .any(|m| matches!(m, ServerToClient::CardsShown { .. })));
+
+        println!("✅ Show cards after fold test passed");
+        println!("   - Folder's hand broadcast to the table");
+        println!("   - Rejected for active players and after the hand");
+    }
+
+    /// Showdown reveal omits folded hands unless the player chose to show them
+    #[test]
+    fn test_showdown_reveal_hides_folded_hands() {
+        let mut room = game::Room::new("Reveal Room".to_string());
+        let mut receivers = vec![];
+        for i in 0..4 {
+            receivers.push(seat_with_rx(&mut room, &format!("Player {}", i + 1), 1000).1);
+        }
+        crate::start_hand(&mut room);
+        let silent_folder = room.players[1].id;
+        let showing_folder = room.players[2].id;
+        crate::player_fold(&mut room, silent_folder);
+        crate::player_fold(&mut room, showing_folder);
+        crate::player_show_cards(&mut room, showing_folder);
+
+        crate::reveal_and_reset(&mut room, vec![], vec![], vec![]);
+
+        let reveal = std::iter::from_fn(|| receivers[0].try_recv().ok())
+            .find_map(|m| match m {
+                ServerToClient::Showdown { reveal, .. } => Some(reveal),
+                _ => None,
+            })
+            .expect("showdown sent");
+        let revealed: Vec<Uuid> = reveal.iter().map(|(id, _)| *id).collect();
+        assert!(!revealed.contains(&silent_folder), "folded hand leaked at showdown");
+        assert!(revealed.contains(&showing_folder));
+        assert!(revealed.contains(&room.players[0].id));
+        assert!(revealed.contains(&room.players[3].id));
+        assert_eq!(revealed.len(), 3);
+
+        // Voluntary shows don't carry over to the next hand
+        crate::start_hand(&mut room);
+        assert!(room.shown_cards.is_empty());
+
+        println!("✅ Showdown reveal test passed");
+        println!("   - Folded hands stay private");
+        println!("   - Voluntarily shown hands are included");
+    }
+
+    /// With a muck window, a losing hand can be mucked and stays out of the reveal
+    #[test]
+    fn test_muck_losing_hand() {
+        let mut room = game::Room::new("Muck Room".to_string());
+        room.game_variant = GameVariant::TexasHoldem;
+        room.muck_window = Some(std::time::Duration::from_secs(5));
+        let mut receivers = vec![];
+        for i in 0..2 {
+            receivers.push(seat_with_rx(&mut room, &format!("Player {}", i + 1), 1000).1);
+        }
+        crate::start_hand(&mut room);
+
+        // Board plays a straight for the winner; the loser holds nothing
+        fn rig_board(room: &mut game::Room) {
+            use Rank::*;
+            let card = |rank, suit| Card { rank, suit, face_up: true };
+            room.community_cards = vec![
+                card(Two, Suit::Clubs), card(Seven, Suit::Diamonds), card(Nine, Suit::Hearts),
+                card(Ten, Suit::Spades), card(King, Suit::Clubs),
+            ];
+            room.players[0].down_cards = vec![card(Jack, Suit::Hearts), card(Queen, Suit::Hearts)];
+            room.players[1].down_cards = vec![card(Three, Suit::Spades), card(Four, Suit::Diamonds)];
+        }
+        rig_board(&mut room);
+        let (winner, loser) = (room.players[0].id, room.players[1].id);
+
+        crate::do_showdown(&mut room);
+        assert_eq!(room.phase, Phase::Showdown);
+        assert!(room.pending_showdown.is_some());
+
+        // The winner can't muck; the loser can
+        crate::player_muck(&mut room, winner);
+        assert_eq!(room.phase, Phase::Showdown);
+        crate::player_muck(&mut room, loser);
+        assert_eq!(room.phase, Phase::Comments, "last loser mucking closes the window");
+
+        let reveal = std::iter::from_fn(|| receivers[0].try_recv().ok())
+            .find_map(|m| match m {
+                ServerToClient::Showdown { reveal, winners7, .. } => Some((reveal, winners7)),
+                _ => None,
+            })
+            .expect("showdown sent");
+        assert_eq!(reveal.1, vec![winner]);
+        let revealed: Vec<Uuid> = reveal.0.iter().map(|(id, _)| *id).collect();
+        assert_eq!(revealed, vec![winner]);
+
+        // Without anyone mucking, the window expires and every hand shows
+        crate::start_hand(&mut room);
+        rig_board(&mut room);
+        crate::do_showdown(&mut room);
+        assert_eq!(room.phase, Phase::Showdown);
+        crate::expire_muck_window(&mut room, std::time::Instant::now());
+        assert_eq!(room.phase, Phase::Showdown, "window still open");
+        crate::expire_muck_window(&mut room, std::time::Instant::now() + std::time::Duration::from_secs(6));
+        assert_eq!(room.phase, Phase::Comments);
+        assert!(room.mucked.is_empty());
+
+        println!("✅ Muck test passed");
+        println!("   - Mucked losing hand withheld from the reveal");
+        println!("   - Winners can't muck; the window expires on its own");
+    }
+
+    /// Test that each street allows one bet plus exactly max_raises raises
+    #[test]
+    fn test_max_raises_per_street() {
+        // Bet, raise up to the cap, then check the next raise is rejected with an Error
+        fn exhaust_raises(room: &mut game::Room, receivers: &mut [mpsc::UnboundedReceiver<ServerToClient>]) {
+            assert_eq!(room.raises_made, 0);
+            let opener = room.players[room.to_act_seat].id;
+            crate::player_bet_or_raise(room, opener, false);
+            assert_eq!(room.raises_made, 0); // the opening bet is not a raise
+
+            for n in 1..=room.max_raises {
+                let raiser = room.players[room.to_act_seat].id;
+                crate::player_bet_or_raise(room, raiser, true);
+                assert_eq!(room.raises_made, n);
+            }
+
+            let seat = room.to_act_seat;
+            let bet_before = room.current_bet;
+            while receivers[seat].try_recv().is_ok() {}
+            crate::player_bet_or_raise(room, room.players[seat].id, true);
+            assert_eq!(room.raises_made, room.max_raises);
+            assert_eq!(room.current_bet, bet_before);
+            assert_eq!(room.to_act_seat, seat);
+            let errors = std::iter::from_fn(|| receivers[seat].try_recv().ok())
+                .filter(|m| matches!(m, ServerToClient::Error { .. }))
+                .count();
+            assert_eq!(errors, 1);
+        }
+
+        for variant in [GameVariant::SevenTwentySeven, GameVariant::Omaha, GameVariant::TexasHoldem] {
+            let mut room = game::Room::new("Raise Cap Room".to_string());
+            room.game_variant = variant;
+            room.max_raises = 3;
+            let mut receivers = vec![];
+            for i in 0..4 {
+                receivers.push(seat_with_rx(&mut room, &format!("Player {}", i + 1), 1000).1);
+            }
+
+            crate::start_hand(&mut room);
+            if !variant.uses_community_cards() {
+                // 7/27 opens with a draw round before the first betting street
+                crate::start_betting_round(&mut room);
+            }
+
+            for street in 1..=3 {
+                assert!(room.in_betting, "{} street {} not betting", variant, street);
+                exhaust_raises(&mut room, &mut receivers);
+                room.round += 1;
+                crate::start_betting_round(&mut room);
+            }
+        }
+
+        println!("✅ Max raises per street test passed");
+        println!("   - One bet plus max_raises raises per street");
+        println!("   - Extra raise rejected with an error on every street");
+    }
+
+    /// Test the read-only JSON snapshot route used by streaming overlays
+    #[tokio::test]
+    async fn test_room_snapshot_route() {
+        use http_body_util::BodyExt;
+        use std::sync::Arc;
+        use tower::ServiceExt;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let state = crate::AppState {
+            inner: Arc::new(parking_lot::Mutex::new(HashMap::new())),
+            message_store: Arc::new(crate::messages::MessageStore::new(temp_dir.path().to_str().unwrap()).unwrap()),
+            distributed_tables: Arc::new(parking_lot::Mutex::new(HashMap::new())),
+            lounge: Arc::new(parking_lot::Mutex::new(crate::LoungeState {
+                players: HashMap::new(),
+            })),
+            socket: crate::SocketConfig::default(),
+        };
+
+        let mut room = game::Room::new("Overlay Room".to_string());
+        for i in 0..4 {
+            seat(&mut room, &format!("Player {}", i + 1), 1000);
+        }
+        crate::start_hand(&mut room);
+        state.inner.lock().insert("Overlay Room".to_string(), room);
+
+        let app = crate::app_router(state.clone());
+        let response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri("/room/Overlay%20Room")
+                    .body(axum::body::Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let snapshot: PublicRoom = serde_json::from_slice(&body).unwrap();
+        assert_eq!(snapshot.room, "Overlay Room");
+        assert_eq!(snapshot.players.len(), 4);
+        assert_eq!(snapshot.phase, Phase::Acting);
+        assert!(snapshot.players.iter().all(|p| p.cards_count == 2));
+
+        // Unknown rooms are a 404
+        let response = app
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri("/room/missing")
+                    .body(axum::body::Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), axum::http::StatusCode::NOT_FOUND);
+
+        println!("✅ Room snapshot route test passed");
+        println!("   - GET /room/{{name}} returns the PublicRoom JSON");
+        println!("   - Unknown rooms return 404");
+    }
+
+    /// Test that a simple hand records the expected action timeline
+    #[test]
+    fn test_hand_action_timeline() {
+        let mut room = game::Room::new("Timeline Room".to_string());
+        let mut receivers = vec![];
+        let names = ["John", "Joe", "Frank", "Santo"];
+        for name in names {
+            receivers.push(seat_with_rx(&mut room, name, 1000).1);
+        }
+        let ids: Vec<Uuid> = room.players.iter().map(|p| p.id).collect();
+
+        // Dealer is seat 0, so seat 1 acts first; everyone stands
+        crate::start_hand(&mut room);
+        for seat in [1, 2, 3, 0] {
+            assert_eq!(room.to_act_seat, seat);
+            crate::player_stand(&mut room, ids[seat]);
+        }
+
+        // Betting: Joe bets, Frank calls, Santo raises, John folds, Joe and Frank call
+        assert!(room.in_betting);
+        crate::player_bet_or_raise(&mut room, ids[1], false);
+        crate::player_call(&mut room, ids[2]);
+        crate::player_bet_or_raise(&mut room, ids[3], true);
+        crate::player_fold(&mut room, ids[0]);
+        crate::player_call(&mut room, ids[1]);
+        crate::player_call(&mut room, ids[2]);
+        assert_eq!(room.phase, Phase::Comments);
+
+        let expected = vec![
+            (1, "Joe", ActionKind::Stand, 0),
+            (2, "Frank", ActionKind::Stand, 0),
+            (3, "Santo", ActionKind::Stand, 0),
+            (0, "John", ActionKind::Stand, 0),
+            (1, "Joe", ActionKind::Bet, 10),
+            (2, "Frank", ActionKind::Call, 10),
+            (3, "Santo", ActionKind::Raise, 20),
+            (0, "John", ActionKind::Fold, 0),
+            (1, "Joe", ActionKind::Call, 10),
+            (2, "Frank", ActionKind::Call, 10),
+        ];
+        let actual: Vec<(usize, &str, ActionKind, u64)> = room
+            .actions
+            .iter()
+            .map(|a| {
+                let seat = ids.iter().position(|id| *id == a.player_id).unwrap();
+                (seat, a.player_name.as_str(), a.kind, a.amount)
+            })
+            .collect();
+        assert_eq!(actual, expected);
+        assert!(room.actions.iter().all(|a| a.round == 1));
+        assert_eq!(room.actions[4].to_string(), "Joe bets 10");
+
+        // The timeline goes out with the showdown
+        let showdown_actions = std::iter::from_fn(|| receivers[0].try_recv().ok())
+            .find_map(|m| match m {
+                ServerToClient::Showdown { actions, .. } => Some(actions),
+                _ => None,
+            })
+            .expect("showdown sent");
+        assert_eq!(showdown_actions, room.actions);
+
+        // And is cleared when the next hand starts
+        crate::start_hand(&mut room);
+        assert!(room.actions.is_empty());
+
+        println!("✅ Hand action timeline test passed");
+        println!("   - Draw and betting actions recorded in order with amounts");
+        println!("   - Timeline included in the showdown message");
+    }
+
+    /// Test that the turn clock starts with each new turn and stops after the hand
+    #[test]
+    fn test_turn_started_at_tracking() {
+        let mut room = game::Room::new("Turn Clock Room".to_string());
+        for i in 0..4 {
+            seat(&mut room, &format!("Player {}", i + 1), 1000);
+        }
+        assert!(game::public_room(&room).turn_started_at.is_none());
+
+        crate::start_hand(&mut room);
+        let first = game::public_room(&room).turn_started_at.expect("clock starts with the hand");
+        assert!(chrono::DateTime::parse_from_rfc3339(&first).is_ok());
+
+        // Acting moves the turn on and restarts the clock
+        room.turn_started_at = Some("2000-01-01T00:00:00+00:00".to_string());
+        let acting = room.players[room.to_act_seat].id;
+        crate::player_stand(&mut room, acting);
+        assert_ne!(room.turn_started_at.as_deref(), Some("2000-01-01T00:00:00+00:00"));
+
+        crate::reveal_and_reset(&mut room, vec![], vec![], vec![]);
+        assert!(room.turn_started_at.is_none());
+
+        println!("✅ Turn clock tracking test passed");
+    }
+
+    /// Test the per-player turn options sent with YourTurn
+    #[test]
+    fn test_turn_options_facing_bet() {
+        let mut room = game::Room::new("Turn Options Room".to_string());
+        room.game_variant = GameVariant::TexasHoldem;
+        let mut receivers = vec![];
+        for i in 0..4 {
+            receivers.push(seat_with_rx(&mut room, &format!("Player {}", i + 1), 1000).1);
+        }
+
+        crate::start_hand(&mut room);
+        assert!(room.in_betting);
+
+        // Nobody has bet yet: check or open
+        let opener = room.to_act_seat;
+        let opts = game::turn_options(&room, opener);
+        assert!(opts.can_check && opts.can_fold);
+        assert_eq!(opts.can_bet, Some(room.limit_small));
+        assert_eq!(opts.can_call, None);
+
+        let opener_id = room.players[opener].id;
+        crate::player_bet_or_raise(&mut room, opener_id, false);
+
+        // Facing a bet: call, raise or fold, no draw actions
+        let seat = room.to_act_seat;
+        assert_ne!(seat, opener);
+        let expected = TurnOptions {
+            can_call: Some(room.limit_small),
+            can_raise: Some(room.limit_small),
+            can_fold: true,
+            ..TurnOptions::default()
+        };
+        assert_eq!(game::turn_options(&room, seat), expected);
+
+        // Only the player to act gets options
+        assert_eq!(game::turn_options(&room, opener), TurnOptions::default());
+
+        // broadcast_state delivers them to the acting player only
+        for rx in receivers.iter_mut() {
+            while rx.try_recv().is_ok() {}
+        }
+        crate::broadcast_state(&room);
+        let got = |rx: &mut mpsc::UnboundedReceiver<ServerToClient>| {
+            std::iter::from_fn(|| rx.try_recv().ok()).find_map(|m| match m {
+                ServerToClient::YourTurn { options } => Some(options),
+                _ => None,
+            })
+        };
+        assert_eq!(got(&mut receivers[seat]), Some(expected));
+        assert_eq!(got(&mut receivers[opener]), None);
+
+        // Once the raise cap is hit the raise option disappears
+        room.raises_made = room.max_raises;
+        assert_eq!(game::turn_options(&room, seat).can_raise, None);
+
+        println!("✅ Turn options test passed");
+        println!("   - Check/bet when unopened, call/raise/fold facing a bet");
+        println!("   - YourTurn sent only to the acting player");
+    }
+
+    /// Test that draw actions are rejected in community-card games
+    #[test]
+    fn test_take_card_rejected_in_omaha() {
+        let mut room = game::Room::new("Omaha Draw Room".to_string());
+        room.game_variant = GameVariant::Omaha;
+        let mut receivers = vec![];
+        for i in 0..3 {
+            receivers.push(seat_with_rx(&mut room, &format!("Player {}", i + 1), 1000).1);
+        }
+
+        crate::start_hand(&mut room);
+        // Force the state a confused client might assume: not betting
+        room.in_betting = false;
+        let seat = room.to_act_seat;
+        let id = room.players[seat].id;
+        let cards_before = room.players[seat].down_cards.len() + room.players[seat].up_cards.len();
+        for rx in receivers.iter_mut() {
+            while rx.try_recv().is_ok() {}
+        }
+
+        crate::player_take_card(&mut room, id);
+        crate::player_stand(&mut room, id);
+
+        let p = &room.players[seat];
+        assert_eq!(p.down_cards.len() + p.up_cards.len(), cards_before);
+        assert!(!p.standing);
+        assert_eq!(room.to_act_seat, seat);
+        let errors = std::iter::from_fn(|| receivers[seat].try_recv().ok())
+            .filter(|m| matches!(m, ServerToClient::Error { .. }))
+            .count();
+        assert_eq!(errors, 2);
+
+        println!("✅ Omaha draw rejection test passed");
+        println!("   - TakeCard and Stand rejected with an error");
+    }
+
+    /// Test that a spectator at a full table can take the next open seat
+    #[test]
+    fn test_take_seat_after_table_full() {
+        let mut room = game::Room::new("Full Table Room".to_string());
+        for i in 0..crate::MAX_PLAYERS {
+            seat(&mut room, &format!("Player {}", i + 1), 1000);
+        }
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let watcher = Uuid::new_v4();
+        room.spectators.push(game::Spectator { id: watcher, name: "Watcher".to_string(), tx });
+
+        // Still full: rejected and left spectating
+        crate::handle_take_seat(&mut room, watcher);
+        assert_eq!(room.players.len(), crate::MAX_PLAYERS);
+        assert_eq!(room.spectators.len(), 1);
+        assert!(matches!(rx.try_recv(), Ok(ServerToClient::Error { .. })));
+
+        // A seat frees up: the spectator is seated and told their seat
+        room.players.pop();
+        crate::handle_take_seat(&mut room, watcher);
+        assert!(room.spectators.is_empty());
+        assert_eq!(room.players.last().map(|p| p.id), Some(watcher));
+        let joined = std::iter::from_fn(|| rx.try_recv().ok()).find_map(|m| match m {
+            ServerToClient::Joined { your_seat, .. } => Some(your_seat),
+            _ => None,
+        });
+        assert_eq!(joined, Some(crate::MAX_PLAYERS - 1));
+
+        println!("✅ Take seat test passed");
+        println!("   - Rejected while the table is full");
+        println!("   - Spectator seated once a seat opens");
+    }
+
+    /// A spectator who asks for a seat mid-hand is seated automatically when
+    /// the hand ends, in the order the requests came in
+    #[test]
+    fn test_seat_queue_fills_at_next_lobby() {
+        let mut room = game::Room::new("Queue Room".to_string());
+        for i in 0..crate::MAX_PLAYERS {
+            seat(&mut room, &format!("Player {}", i + 1), 1000);
+        }
+        crate::start_hand(&mut room);
+        assert_eq!(room.phase, Phase::Acting);
+
+        let mut watchers = vec![];
+        for name in ["First", "Second"] {
+            let (tx, rx) = mpsc::unbounded_channel();
+            let id = Uuid::new_v4();
+            room.spectators.push(game::Spectator { id, name: name.to_string(), tx });
+            crate::handle_request_seat(&mut room, id);
+            watchers.push((id, rx));
+        }
+        assert_eq!(room.seat_queue, vec![watchers[0].0, watchers[1].0]);
+        assert_eq!(room.spectators.len(), 2, "nobody is seated mid-hand");
+
+        // One player leaves mid-hand, then the hand ends: only the first
+        // in line gets the freed seat
+        room.players.pop();
+        let winner = room.players[0].id;
+        for p in room.players.iter_mut().filter(|p| p.id != winner) {
+            p.folded = true;
+        }
+        crate::award_last_player_and_reset(&mut room);
+        assert_eq!(room.phase, Phase::Comments);
+        assert_eq!(room.players.last().map(|p| p.id), Some(watchers[0].0));
+        assert_eq!(room.seat_queue, vec![watchers[1].0]);
+
+        let joined = std::iter::from_fn(|| watchers[0].1.try_recv().ok())
+            .any(|m| matches!(m, ServerToClient::Joined { .. }));
+        assert!(joined, "seated spectator is sent Joined");
+
+        println!("✅ Seat queue test passed");
+        println!("   - Requests queue while a hand is in progress");
+        println!("   - First in line seated when the hand ends");
+    }
+
+    /// Test that the dealer can rematch straight from the comments phase
+    #[test]
+    fn test_rematch_from_dealer() {
+        let mut room = game::Room::new("Rematch Room".to_string());
+        let mut receivers = vec![];
+        for (i, chips) in [940, 1100, 960].into_iter().enumerate() {
+            receivers.push(seat_with_rx(&mut room, &format!("Player {}", i + 1), chips).1);
+            room.players[i].ready = false;
+        }
+        room.phase = Phase::Comments;
+        room.current_dealer_id = Some(room.players[1].id);
+        let chips_before: Vec<u64> = room.players.iter().map(|p| p.chips).collect();
+
+        // Not the dealer: rejected
+        let other = room.players[0].id;
+        crate::handle_rematch(&mut room, other);
+        assert_eq!(room.phase, Phase::Comments);
+        assert!(std::iter::from_fn(|| receivers[0].try_recv().ok())
+            .any(|m| matches!(m, ServerToClient::Error { .. })));
+
+        // Dealer: new hand dealt without anyone readying up
+        let dealer = room.players[1].id;
+        crate::handle_rematch(&mut room, dealer);
+        assert_eq!(room.phase, Phase::Acting);
+        assert_eq!(room.round, 1);
+        assert_eq!(room.players.len(), 3);
+        assert!(room.players.iter().all(|p| !p.down_cards.is_empty()));
+        let chips_after: Vec<u64> = room.players.iter().map(|p| p.chips).collect();
+        assert_eq!(chips_after, chips_before);
+
+        println!("✅ Rematch test passed");
+        println!("   - Only the dealer can rematch");
+        println!("   - Same players and chip stacks in the new hand");
+    }
+
+    /// Test that a scheduled game starts (or is cancelled) when its time arrives
+    #[test]
+    fn test_scheduled_game_auto_start() {
+        use chrono::{Duration, TimeZone, Utc};
+
+        fn scheduled_room(start: &str) -> game::Room {
+            let mut room = game::Room::new("Scheduled Room".to_string());
+            for i in 0..3 {
+                seat(&mut room, &format!("Player {}", i + 1), 1000);
+                room.players[i].ready = false;
+            }
+            room.scheduled_start = Some(start.to_string());
+            room
+        }
+
+        let start = Utc.with_ymd_and_hms(2030, 1, 1, 20, 0, 0).unwrap();
+
+        // Enough check-ins: nothing happens early, the hand starts on time
+        let mut room = scheduled_room("2030-01-01T20:00:00+00:00");
+        room.checked_in_players = vec![room.players[0].id, room.players[2].id];
+        crate::fire_scheduled_start(&mut room, start - Duration::seconds(1));
+        assert_eq!(room.phase, Phase::Lobby);
+        assert!(room.scheduled_start.is_some());
+
+        crate::fire_scheduled_start(&mut room, start);
+        assert_eq!(room.phase, Phase::Acting);
+        assert!(room.scheduled_start.is_none());
+        assert!(room.checked_in_players.is_empty());
+        assert_eq!(room.sitting_out, vec![room.players[1].id], "only checked-in players are dealt in");
+        assert!(room.players[1].folded && room.players[1].down_cards.is_empty());
+
+        // Too few check-ins: the schedule is cancelled and the table stays in the lobby
+        let mut room = scheduled_room("2030-01-01T20:00:00+00:00");
+        room.checked_in_players = vec![room.players[1].id];
+        crate::fire_scheduled_start(&mut room, start + Duration::seconds(5));
+        assert_eq!(room.phase, Phase::Lobby);
+        assert!(room.scheduled_start.is_none());
+        assert!(room.checked_in_players.is_empty());
+
+        println!("✅ Scheduled auto-start test passed");
+        println!("   - Hand starts once the scheduled time arrives");
+        println!("   - Players who didn't check in sit it out");
+        println!("   - Schedule cancelled without enough check-ins");
+    }
+
+    /// Test that ScheduleGame only accepts valid future RFC 3339 times
+    #[test]
+    fn test_schedule_game_validation() {
+        let mut room = game::Room::new("Schedule Room".to_string());
+        let (id, mut rx) = seat_with_rx(&mut room, "Scheduler", 1000);
+        let errors = |rx: &mut mpsc::UnboundedReceiver<ServerToClient>| {
+            std::iter::from_fn(|| rx.try_recv().ok())
+                .filter(|m| matches!(m, ServerToClient::Error { .. }))
+                .count()
+        };
+
+        // Valid future time, stored normalized to UTC
+        room.checked_in_players.push(id);
+        crate::handle_schedule_game(&mut room, id, "2099-06-01T22:30:00+02:00");
+        assert_eq!(room.scheduled_start.as_deref(), Some("2099-06-01T20:30:00Z"));
+        assert!(room.checked_in_players.is_empty());
+        assert_eq!(errors(&mut rx), 0);
+
+        // Not a time: rejected, previous schedule kept
+        crate::handle_schedule_game(&mut room, id, "not a time");
+        assert_eq!(room.scheduled_start.as_deref(), Some("2099-06-01T20:30:00Z"));
+        assert_eq!(errors(&mut rx), 1);
+
+        // In the past: rejected
+        crate::handle_schedule_game(&mut room, id, "2000-01-01T20:00:00Z");
+        assert_eq!(room.scheduled_start.as_deref(), Some("2099-06-01T20:30:00Z"));
+        assert_eq!(errors(&mut rx), 1);
+
+        println!("✅ Schedule validation test passed");
+        println!("   - Future times accepted and normalized");
+        println!("   - Invalid and past times rejected");
+    }
+
+    /// Test that the host can cancel a scheduled game
+    #[test]
+    fn test_cancel_schedule() {
+        let mut room = game::Room::new("Cancel Schedule Room".to_string());
+        let mut receivers = vec![];
+        for i in 0..2 {
+            receivers.push(seat_with_rx(&mut room, &format!("Player {}", i + 1), 1000).1);
+        }
+        let host = room.players[0].id;
+        let guest = room.players[1].id;
+        let last_error = |rx: &mut mpsc::UnboundedReceiver<ServerToClient>| {
+            std::iter::from_fn(|| rx.try_recv().ok())
+                .filter_map(|m| match m {
+                    ServerToClient::Error { message, .. } => Some(message),
+                    _ => None,
+                })
+                .last()
+        };
+
+        crate::handle_schedule_game(&mut room, host, "2099-01-01T20:00:00Z");
+        crate::handle_check_in(&mut room, guest);
+        assert_eq!(room.checked_in_players, vec![guest]);
+
+        // Only the dealer/host may cancel
+        crate::handle_cancel_schedule(&mut room, guest);
+        assert!(room.scheduled_start.is_some());
+        assert!(last_error(&mut receivers[1]).is_some());
+
+        crate::handle_cancel_schedule(&mut room, host);
+        assert!(room.scheduled_start.is_none());
+        assert!(room.checked_in_players.is_empty());
+
+        // Nothing left to check in for, or to cancel
+        crate::handle_check_in(&mut room, guest);
+        assert!(room.checked_in_players.is_empty());
+        assert_eq!(last_error(&mut receivers[1]).as_deref(), Some("No game is currently scheduled."));
+        crate::handle_cancel_schedule(&mut room, host);
+        assert_eq!(last_error(&mut receivers[0]).as_deref(), Some("No game is currently scheduled."));
+
+        println!("✅ Cancel schedule test passed");
+        println!("   - Host clears the schedule and check-ins");
+        println!("   - Check-in rejected afterwards");
+    }
+
+    /// Test that a short stack can rebuy back up to the starting stack
+    #[test]
+    fn test_rebuy_tops_up_short_stack() {
+        let mut room = game::Room::new("Rebuy Room".to_string());
+        let mut receivers = vec![];
+        for (i, chips) in [15, 1400].into_iter().enumerate() {
+            receivers.push(seat_with_rx(&mut room, &format!("Player {}", i + 1), chips).1);
+        }
+        let short = room.players[0].id;
+        let big = room.players[1].id;
+
+        // Not during a hand
+        room.phase = Phase::Acting;
+        crate::handle_rebuy(&mut room, short);
+        assert_eq!(room.players[0].chips, 15);
+
+        room.phase = Phase::Lobby;
+        crate::handle_rebuy(&mut room, short);
+        assert_eq!(room.players[0].chips, crate::REBUY_CHIPS);
+
+        // A big stack has nothing to rebuy
+        crate::handle_rebuy(&mut room, big);
+        assert_eq!(room.players[1].chips, 1400);
+        assert!(std::iter::from_fn(|| receivers[1].try_recv().ok())
+            .any(|m| matches!(m, ServerToClient::Error { .. })));
+
+        println!("✅ Rebuy test passed");
+        println!("   - Short stack topped up between hands");
+        println!("   - Rejected mid-hand and for full stacks");
+    }
+
+    /// Test which players each chat scope reaches
+    #[test]
+    fn test_chat_scope_recipients() {
+        let (state, _dir) = create_test_state();
+        let mut ids = HashMap::new();
+        for (table, names) in [("Table A", ["a1", "a2"]), ("Table B", ["b1", "b2"])] {
+            let mut room = game::Room::new(table.to_string());
+            for name in names {
+                ids.insert(name, seat(&mut room, name, 1000));
+            }
+            state.inner.lock().insert(table.to_string(), room);
+        }
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let lounger = Uuid::new_v4();
+        ids.insert("lounger", lounger);
+        state.lounge.lock().players.insert(lounger, crate::LoungePlayer {
+            id: lounger,
+            name: "lounger".to_string(),
+            tx,
+            history_sent: true,
+            hosting_port: None,
+            selected_host: None,
+        });
+
+        let recipients = |scope, room| {
+            let mut names: Vec<&str> = crate::chat_recipients(&state, scope, room)
+                .into_iter()
+                .map(|(id, _)| *ids.iter().find(|(_, v)| **v == id).unwrap().0)
+                .collect();
+            names.sort();
+            names
+        };
+
+        assert_eq!(recipients(MessageScope::Match, Some("Table A")), vec!["a1", "a2"]);
+        assert_eq!(recipients(MessageScope::Group, Some("Table A")), vec!["a1", "a2", "b1", "b2"]);
+        assert_eq!(recipients(MessageScope::Global, Some("Table A")), vec!["a1", "a2", "b1", "b2", "lounger"]);
+        assert!(recipients(MessageScope::Private, Some("Table A")).is_empty());
+        assert!(recipients(MessageScope::Match, None).is_empty());
+
+        println!("✅ Chat scope recipients test passed");
+        println!("   - Match stays at the table, Group spans tables");
+        println!("   - Only Global reaches the lounge");
+    }
+
+    /// Test that lounge join/volunteer/leave each broadcast a fresh LoungeUpdate
+    #[tokio::test]
+    async fn test_lounge_updates() {
+        let (state, _dir) = create_test_state();
+        let latest_update = |rx: &mut mpsc::UnboundedReceiver<ServerToClient>| {
+            std::iter::from_fn(|| rx.try_recv().ok())
+                .filter_map(|m| match m {
+                    ServerToClient::LoungeUpdate { mut players, available_hosts, .. } => {
+                        players.sort();
+                        Some((players, available_hosts))
+                    }
+                    _ => None,
+                })
+                .last()
+        };
+
+        let (ann_tx, mut ann_rx) = mpsc::unbounded_channel();
+        let (bob_tx, mut bob_rx) = mpsc::unbounded_channel();
+        let ann = Uuid::new_v4();
+        let bob = Uuid::new_v4();
+
+        crate::handle_join_lounge(state.clone(), ann, "Ann".to_string(), ann_tx).await;
+        crate::handle_join_lounge(state.clone(), bob, "Bob".to_string(), bob_tx).await;
+        assert_eq!(latest_update(&mut ann_rx), Some((vec!["Ann".to_string(), "Bob".to_string()], vec![])));
+        assert_eq!(latest_update(&mut bob_rx), Some((vec!["Ann".to_string(), "Bob".to_string()], vec![])));
+
+        crate::handle_volunteer_to_host(state.clone(), bob, 9100).await;
+        assert_eq!(
+            latest_update(&mut ann_rx),
+            Some((vec!["Ann".to_string(), "Bob".to_string()], vec![("Bob".to_string(), 9100)]))
+        );
+        assert!(latest_update(&mut bob_rx).is_some());
+
+        // Leaving drops both the player and the host they offered
+        crate::handle_leave_lounge(state.clone(), bob).await;
+        assert_eq!(latest_update(&mut ann_rx), Some((vec!["Ann".to_string()], vec![])));
+        assert_eq!(latest_update(&mut bob_rx), None);
+
+        // Heading to a table counts as leaving the lounge
+        crate::leave_lounge_for_table(&state, &Some("lounge".to_string()), ann).await;
+        assert!(state.lounge.lock().players.is_empty());
+
+        println!("✅ Lounge updates test passed");
+        println!("   - Join, volunteer and leave broadcast the roster and hosts");
+    }
+
+    /// Test that a volunteered host shows up for the other lounge members
+    #[tokio::test]
+    async fn test_volunteer_host_visible_in_lounge() {
+        let (state, _dir) = create_test_state();
+        let (ann_tx, mut ann_rx) = mpsc::unbounded_channel();
+        let (bob_tx, _bob_rx) = mpsc::unbounded_channel();
+        let ann = Uuid::new_v4();
+        let bob = Uuid::new_v4();
+        crate::handle_join_lounge(state.clone(), ann, "Ann".to_string(), ann_tx).await;
+        crate::handle_join_lounge(state.clone(), bob, "Bob".to_string(), bob_tx).await;
+        while ann_rx.try_recv().is_ok() {}
+
+        let hosts_seen_by_ann = |rx: &mut mpsc::UnboundedReceiver<ServerToClient>| {
+            std::iter::from_fn(|| rx.try_recv().ok())
+                .filter_map(|m| match m {
+                    ServerToClient::LoungeUpdate { available_hosts, .. } => Some(available_hosts),
+                    _ => None,
+                })
+                .last()
+        };
+
+        crate::handle_volunteer_to_host(state.clone(), bob, 9100).await;
+        assert_eq!(hosts_seen_by_ann(&mut ann_rx), Some(vec![("Bob".to_string(), 9100)]));
+
+        // Someone outside the lounge can't add themselves as a host
+        crate::handle_volunteer_to_host(state.clone(), Uuid::new_v4(), 9200).await;
+        assert_eq!(hosts_seen_by_ann(&mut ann_rx), Some(vec![("Bob".to_string(), 9100)]));
+
+        println!("✅ Volunteer host test passed");
+        println!("   - Volunteered host broadcast to other lounge members");
+    }
+
+    /// Players all-in on the flop get the board run out to a showdown
+    #[test]
+    fn test_all_in_runs_out_board() {
+        let mut room = game::Room::new("All-in Room".to_string());
+        room.game_variant = GameVariant::TexasHoldem;
+        let mut receivers = vec![];
+        for i in 0..2 {
+            receivers.push(seat_with_rx(&mut room, &format!("Player {}", i + 1), 1000).1);
+        }
+
+        crate::start_hand(&mut room);
+        assert_eq!(room.community_cards.len(), 3);
+
+        // Both stacks only cover the opening bet
+        let bet = game::bet_size_for_round(&room);
+        for p in room.players.iter_mut() {
+            p.chips = bet;
+        }
+
+        let opener = room.players[room.to_act_seat].id;
+        crate::player_bet_or_raise(&mut room, opener, false);
+        assert!(room.in_betting, "other player still has to call");
+        let caller = room.players[room.to_act_seat].id;
+        assert_ne!(caller, opener);
+        let pot = room.pot + bet;
+        crate::player_call(&mut room, caller);
+
+        // No further betting: turn and river dealt, hand settled
+        assert_eq!(room.community_cards.len(), 5);
+        assert_eq!(room.phase, Phase::Comments);
+        assert!(!room.in_betting);
+        assert_eq!(room.players.iter().map(|p| p.chips).sum::<u64>(), pot);
+
+        let mut saw_showdown = false;
+        while let Ok(msg) = receivers[0].try_recv() {
+            if let ServerToClient::Showdown { winners7, .. } = msg {
+                assert!(!winners7.is_empty());
+                saw_showdown = true;
+            }
+        }
+        assert!(saw_showdown);
+
+        println!("✅ All-in run-out test passed");
+        println!("   - Board dealt to the river without betting");
+        println!("   - Pot awarded at showdown");
+    }
+
+    /// PublicRoom.street follows the board from flop to river through a checked-down hand
+    #[test]
+    fn test_street_advances_through_hand() {
+        let mut room = game::Room::new("Street Room".to_string());
+        room.game_variant = GameVariant::TexasHoldem;
+        for i in 0..2 {
+            seat(&mut room, &format!("Player {}", i + 1), 1000);
+        }
+
+        assert_eq!(game::public_room(&room).street, Some(Street::Preflop));
+        crate::start_hand(&mut room);
+
+        for (round, street) in [(1, Street::Flop), (2, Street::Turn), (3, Street::River)] {
+            let snapshot = game::public_room(&room);
+            assert_eq!(snapshot.street, Some(street));
+            assert_eq!(snapshot.round, round);
+            assert_eq!(snapshot.phase, Phase::Acting);
+            assert!(snapshot.in_betting);
+            for _ in 0..2 {
+                let id = room.players[room.to_act_seat].id;
+                crate::player_check(&mut room, id);
+            }
+        }
+
+        let snapshot = game::public_room(&room);
+        assert_eq!(snapshot.phase, Phase::Comments);
+        assert_eq!(snapshot.street, Some(Street::River));
+        assert_eq!(snapshot.community_cards.len(), 5);
+
+        // Draw games have no streets
+        room.game_variant = GameVariant::SevenTwentySeven;
+        assert_eq!(game::public_room(&room).street, None);
+
+        println!("✅ Street progression test passed");
+        println!("   - Flop, turn and river follow the board");
+        println!("   - 7/27 rooms report no street");
+    }
+
+    /// Dealer force-start deals only the ready players and sits out the rest
+    #[test]
+    fn test_force_start_sits_out_unready() {
+        let mut room = game::Room::new("Force Room".to_string());
+        let mut receivers = vec![];
+        for i in 0..4 {
+            receivers.push(seat_with_rx(&mut room, &format!("Player {}", i + 1), 1000).1);
+            room.players[i].ready = i < 2;
+        }
+        room.dealer_seat = 0;
+
+        // Only the dealer may force-start
+        let not_dealer = room.players[1].id;
+        crate::force_start(&mut room, not_dealer);
+        assert_eq!(room.phase, Phase::Lobby);
+        assert!(matches!(receivers[1].try_recv(), Ok(ServerToClient::Error { .. })));
+
+        // Not enough ready players
+        room.players[1].ready = false;
+        let dealer = room.players[0].id;
+        crate::force_start(&mut room, dealer);
+        assert_eq!(room.phase, Phase::Lobby);
+        assert!(matches!(receivers[0].try_recv(), Ok(ServerToClient::Error { .. })));
+
+        room.players[1].ready = true;
+        crate::force_start(&mut room, dealer);
+        assert_eq!(room.phase, Phase::Acting);
+        assert_eq!(room.pot, 2 * room.ante);
+        assert_eq!(room.sitting_out, vec![room.players[2].id, room.players[3].id]);
+        for (i, p) in room.players.iter().enumerate() {
+            let dealt_in = i < 2;
+            assert_eq!(p.folded, !dealt_in, "seat {}", i);
+            assert_eq!(p.down_cards.is_empty(), !dealt_in, "seat {}", i);
+            assert_eq!(p.up_cards.is_empty(), !dealt_in, "seat {}", i);
+        }
+        assert!(room.to_act_seat < 2);
+        while let Ok(msg) = receivers[2].try_recv() {
+            assert!(!matches!(msg, ServerToClient::YourHand { .. }), "sitting-out player was dealt a hand");
+        }
+
+        println!("✅ Force start test passed");
+        println!("   - Dealer-only, needs 2 ready players");
+        println!("   - Unready players sit out without cards");
+    }
+
+    /// A delegated dealer can force-start; the player who merely holds the
+    /// button seat can't
+    #[test]
+    fn test_force_start_by_delegated_dealer() {
+        let mut room = game::Room::new("Delegated Force Room".to_string());
+        let mut receivers = vec![];
+        for i in 0..3 {
+            receivers.push(seat_with_rx(&mut room, &format!("Player {}", i + 1), 1000).1);
+            room.players[i].ready = i > 0;
+        }
+        room.dealer_seat = 0;
+        room.current_dealer_id = Some(room.players[2].id);
+
+        let button = room.players[0].id;
+        crate::force_start(&mut room, button);
+        assert_eq!(room.phase, Phase::Lobby);
+        assert!(matches!(receivers[0].try_recv(), Ok(ServerToClient::Error { message, .. }) if message.contains("seat 2")));
+
+        let delegated = room.players[2].id;
+        crate::force_start(&mut room, delegated);
+        assert_eq!(room.phase, Phase::Acting);
+        assert_eq!(room.sitting_out, vec![button]);
+
+        println!("✅ Delegated dealer force start test passed");
+        println!("   - Button seat refused when another player deals");
+        println!("   - Delegated dealer deals the ready players");
+    }
+
+    /// Full Omaha hand: deal, bet the flop, check the turn and river, and the
+    /// pot goes to the best exactly-two-hole-card hand, not the best seven
+    #[test]
+    fn test_omaha_full_hand() {
+        use Rank::*;
+        use Suit::*;
+        let card = |rank, suit| Card { rank, suit, face_up: false };
+
+        let mut room = game::Room::new("Omaha Room".to_string());
+        room.game_variant = GameVariant::Omaha;
+        let mut receivers = vec![];
+        for i in 0..3 {
+            receivers.push(seat_with_rx(&mut room, &format!("Player {}", i + 1), 1000).1);
+        }
+
+        crate::start_hand(&mut room);
+        assert_eq!(room.phase, Phase::Acting);
+        assert!(room.players.iter().all(|p| p.down_cards.len() == 4));
+        assert_eq!(room.community_cards.len(), 3);
+
+        // Rig the hand. Four hearts reach the board by the turn:
+        // - Player 1 holds a single heart: a Q-high flush in Hold'em, nothing in Omaha
+        // - Player 2 holds two hearts: an ace-high flush using exactly two hole cards
+        // - Player 3 holds kings: trip kings
+        room.players[0].down_cards = vec![card(Queen, Hearts), card(Three, Clubs), card(Four, Diamonds), card(Five, Spades)];
+        room.players[1].down_cards = vec![card(Six, Hearts), card(Eight, Hearts), card(Ten, Clubs), card(Jack, Clubs)];
+        room.players[2].down_cards = vec![card(King, Spades), card(King, Diamonds), card(Nine, Diamonds), card(Nine, Spades)];
+        room.community_cards = vec![card(Ace, Hearts), card(King, Hearts), card(Seven, Hearts)];
+        // Deck draws from the back: turn, then river
+        room.deck = Some(Deck { cards: vec![card(Nine, Clubs), card(Two, Hearts)] });
+        let trap = room.players[0].id;
+        let winner = room.players[1].id;
+
+        // Flop: open and get called twice
+        let bet = game::bet_size_for_round(&room);
+        let opener = room.players[room.to_act_seat].id;
+        crate::player_bet_or_raise(&mut room, opener, false);
+        for _ in 0..2 {
+            let id = room.players[room.to_act_seat].id;
+            crate::player_call(&mut room, id);
+        }
+        assert_eq!(room.community_cards.len(), 4, "turn dealt after flop betting");
+        assert!(room.players.iter().all(|p| p.chips == 1000 - bet));
+
+        // Turn and river: checked down
+        let mut pot = 0;
+        for street in [Street::Turn, Street::River] {
+            assert_eq!(game::public_room(&room).street, Some(street));
+            pot = room.pot;
+            for _ in 0..3 {
+                let id = room.players[room.to_act_seat].id;
+                crate::player_check(&mut room, id);
+            }
+        }
+        assert_eq!(room.phase, Phase::Comments);
+
+        // Hold'em rules would have paid the trap hand; Omaha rules don't
+        let board = [card(Ace, Hearts), card(King, Hearts), card(Seven, Hearts), card(Two, Hearts), card(Nine, Clubs)];
+        let trap_hole = [card(Queen, Hearts), card(Three, Clubs), card(Four, Diamonds), card(Five, Spades)];
+        assert!(matches!(eval::best_community_hand(GameVariant::TexasHoldem, &trap_hole, &board), Some(HandRank::Flush(_))));
+        assert!(matches!(eval::best_community_hand(GameVariant::Omaha, &trap_hole, &board), Some(HandRank::HighCard(_))));
+
+        let winners = std::iter::from_fn(|| receivers[0].try_recv().ok())
+            .find_map(|m| match m {
+                ServerToClient::Showdown { winners7, .. } => Some(winners7),
+                _ => None,
+            })
+            .expect("showdown sent");
+        assert_eq!(winners, vec![winner]);
+        assert_ne!(winners, vec![trap]);
+        assert_eq!(room.players[1].chips, 1000 - bet + pot);
+        assert_eq!(room.players[0].chips, 1000 - bet);
+        assert_eq!(room.players[2].chips, 1000 - bet);
+
+        println!("✅ Omaha full hand test passed");
+        println!("   - Flop bet, turn and river checked down");
+        println!("   - Winner needs exactly two hole cards for the flush");
+    }
+
+    /// Actions that arrive after the hand has been reset find no deck and are
+    /// ignored instead of panicking
+    #[test]
+    fn test_stray_take_card_after_showdown() {
+        let mut room = game::Room::new("Stray Room".to_string());
+        room.game_variant = GameVariant::SevenTwentySeven;
+        for i in 0..2 {
+            seat(&mut room, &format!("Player {}", i + 1), 1000);
+        }
+        crate::start_hand(&mut room);
+        let late = room.players[room.to_act_seat].id;
+        let other = room.players.iter().find(|p| p.id != late).unwrap().id;
+        crate::player_fold(&mut room, other);
+        assert!(room.deck.is_none(), "hand is over and the deck cleared");
+
+        crate::player_take_card(&mut room, late);
+        crate::player_stand(&mut room, late);
+        assert!(room.players.iter().all(|p| p.down_cards.is_empty()));
+
+        // Same with a hand that somehow lost its deck mid-play
+        room.phase = Phase::Acting;
+        room.in_betting = false;
+        crate::player_take_card(&mut room, late);
+        assert!(room.players.iter().all(|p| p.down_cards.is_empty()));
+
+        println!("✅ Stray action test passed");
+        println!("   - TakeCard/Stand after the reset are ignored");
+        println!("   - A missing deck never panics");
+    }
+
+    /// A fresh deal has no card in play twice; an injected duplicate is caught
+    #[test]
+    fn test_deck_integrity_check() {
+        for variant in [GameVariant::SevenTwentySeven, GameVariant::Omaha, GameVariant::TexasHoldem] {
+            let mut room = game::Room::new("Integrity Room".to_string());
+            room.game_variant = variant;
+            for i in 0..7 {
+                seat(&mut room, &format!("Player {}", i + 1), 1000);
+            }
+            crate::start_hand(&mut room);
+            assert!(game::duplicate_cards_in_play(&room).is_empty(), "{} deal", variant);
+            assert!(crate::check_deck_integrity(&room, "test"));
+
+            // Copy a dealt card into another hand
+            let stolen = room.players[0].down_cards[0];
+            room.players[1].down_cards.push(stolen);
+            assert_eq!(game::duplicate_cards_in_play(&room), vec![(stolen.rank, stolen.suit)]);
+            assert!(!crate::check_deck_integrity(&room, "test"));
+
+            // From a two-deck shoe a card may turn up twice, not three times
+            room.deck_count = 2;
+            assert!(crate::check_deck_integrity(&room, "test"));
+            room.players[2].down_cards.push(stolen);
+            assert!(!crate::check_deck_integrity(&room, "test"));
+        }
+
+        println!("✅ Deck integrity test passed");
+        println!("   - Every variant deals 7 players without duplicates");
+        println!("   - A duplicated card is reported");
+        println!("   - A shoe allows one copy per deck");
+    }
+
+    /// Standing in 7/27 only ends your drawing: a standing player still bets,
+    /// and the round played once everyone stands reaches showdown
+    #[test]
+    fn test_standing_players_still_bet() {
+        let mut room = game::Room::new("Standing Room".to_string());
+        room.game_variant = GameVariant::SevenTwentySeven;
+        for i in 0..2 {
+            seat(&mut room, &format!("Player {}", i + 1), 1000);
+        }
+        crate::start_hand(&mut room);
+
+        for _ in 0..2 {
+            let id = room.players[room.to_act_seat].id;
+            crate::player_stand(&mut room, id);
+        }
+        assert!(room.in_betting, "everyone standing moves to betting");
+        assert!(room.players.iter().all(|p| p.standing));
+
+        let pot = room.pot;
+        let bettor = room.players[room.to_act_seat].id;
+        crate::player_bet_or_raise(&mut room, bettor, false);
+        assert!(room.pot > pot, "a standing player's bet is taken");
+
+        let caller = room.players[room.to_act_seat].id;
+        assert_ne!(caller, bettor);
+        crate::player_call(&mut room, caller);
+        assert!(!room.in_betting);
+        assert_ne!(room.phase, Phase::Acting, "last betting round ends the hand");
+
+        println!("✅ Standing players betting test passed");
+        println!("   - Bet and call accepted from standing players");
+        println!("   - Hand finishes once everyone has stood and bet");
+    }
+
+    /// The dealer can change stakes between hands; they apply to the next deal
+    /// and can't be touched while a hand is running
+    #[test]
+    fn test_update_table_config_between_hands() {
+        let mut room = game::Room::new("Stakes Room".to_string());
+        let mut receivers = vec![];
+        for i in 0..2 {
+            receivers.push(seat_with_rx(&mut room, &format!("Player {}", i + 1), 1000).1);
+        }
+        let dealer = room.players[room.dealer_seat].id;
+        let other = room.players.iter().find(|p| p.id != dealer).unwrap().id;
+        let errors = |rx: &mut mpsc::UnboundedReceiver<ServerToClient>| {
+            std::iter::from_fn(|| rx.try_recv().ok()).filter(|m| matches!(m, ServerToClient::Error { .. })).count()
+        };
+
+        // Only the dealer, and only sane limits
+        crate::handle_update_table_config(&mut room, other, 50, 20, 40, 4, crate::TableCaps::default());
+        crate::handle_update_table_config(&mut room, dealer, 50, 40, 20, 4, crate::TableCaps::default());
+        crate::handle_update_table_config(&mut room, dealer, 0, 20, 40, 4, crate::TableCaps::default());
+        assert_eq!(errors(&mut receivers[1 - room.dealer_seat]), 1);
+        assert_eq!(errors(&mut receivers[room.dealer_seat]), 2);
+        assert_eq!(room.ante, game::Room::new(String::new()).ante, "rejected changes leave the room alone");
+
+        crate::handle_update_table_config(&mut room, dealer, 50, 20, 40, 4, crate::TableCaps::default());
+        assert_eq!((room.ante, room.limit_small, room.limit_big, room.max_raises), (50, 20, 40, 4));
+
+        // The draw round cap changes with the stakes, and can't be zero
+        let capped = |n| crate::TableCaps { max_draw_rounds: Some(n), ..Default::default() };
+        crate::handle_update_table_config(&mut room, dealer, 50, 20, 40, 4, capped(0));
+        assert_eq!(errors(&mut receivers[room.dealer_seat]), 1);
+        assert_eq!(room.max_draw_rounds, None);
+        crate::handle_update_table_config(&mut room, dealer, 50, 20, 40, 4, capped(5));
+        assert_eq!(room.max_draw_rounds, Some(5));
+
+        crate::start_hand(&mut room);
+        assert_eq!(room.pot, 100, "new ante taken on the next deal");
+        assert_eq!(game::bet_size_for_round(&room), 20);
+
+        // Mid-hand: rejected
+        crate::handle_update_table_config(&mut room, dealer, 5, 10, 20, 2, crate::TableCaps::default());
+        assert_eq!(errors(&mut receivers[room.dealer_seat]), 1);
+        assert_eq!(room.ante, 50);
+
+        println!("✅ Table config update test passed");
+        println!("   - Non-dealer and invalid limits rejected");
+        println!("   - New stakes used from the next hand");
+        println!("   - Draw round cap set between hands");
+        println!("   - Changes refused during a hand");
+    }
+
+    /// A table created with min_players 3 won't deal to two ready players,
+    /// and starts on its own once a third readies up
+    #[tokio::test]
+    async fn test_min_players_three_table() {
+        let (state, _temp_dir) = create_test_state();
+        let (tx_out, mut rx_out) = mpsc::unbounded_channel();
+        let create = |min_players| ClientToServer::CreateTable {
+            name: "Three Up".to_string(),
+            game_variant: GameVariant::SevenTwentySeven,
+            ante: 10,
+            limit_small: 10,
+            limit_big: 20,
+            max_raises: 3,
+            min_players,
+            rake_percent: 0,
+            rake_cap: None,
+            max_buyin: None,
+            deck_count: None,
+            spectator_chat_to_players: None,
+            muck_window_secs: None,
+            max_draw_rounds: None,
+            auto_dealer: false,
+            rabbit_hunt: false,
+        };
+
+        let mut none = None;
+        crate::route_cmd(create(Some(1)), &state, &mut none, Uuid::new_v4(), &tx_out).await;
+        assert!(matches!(rx_out.try_recv(), Ok(ServerToClient::Error { .. })));
+        assert!(!state.inner.lock().contains_key("Three Up"));
+
+        crate::route_cmd(create(Some(3)), &state, &mut none, Uuid::new_v4(), &tx_out).await;
+        assert_eq!(state.inner.lock().get("Three Up").map(|r| r.min_players), Some(3));
+
+        let mut players = vec![];
+        let take_seat = |state: &crate::AppState, name: &str| {
+            let mut rooms = state.inner.lock();
+            let room = rooms.get_mut("Three Up").unwrap();
+            let seated = seat_with_rx(room, name, 1000);
+            room.players.last_mut().unwrap().ready = false;
+            seated
+        };
+        let phase = |state: &crate::AppState| state.inner.lock().get("Three Up").unwrap().phase.clone();
+        let mut joined = Some("Three Up".to_string());
+
+        for name in ["Ann", "Bob"] {
+            let (id, rx) = take_seat(&state, name);
+            crate::route_cmd(ClientToServer::SitReady, &state, &mut joined, id, &tx_out).await;
+            players.push((id, rx));
+        }
+        assert_eq!(phase(&state), Phase::Lobby, "two ready players aren't enough");
+
+        crate::route_cmd(ClientToServer::StartHand, &state, &mut joined, players[0].0, &tx_out).await;
+        assert_eq!(phase(&state), Phase::Lobby);
+        let refused = std::iter::from_fn(|| players[0].1.try_recv().ok()).any(|m| {
+            matches!(m, ServerToClient::Error { message, .. } if message.contains("at least 3"))
+        });
+        assert!(refused, "start refused with the table's minimum");
+
+        // A scheduled start needs the table's minimum checked in too
+        {
+            let mut rooms = state.inner.lock();
+            let room = rooms.get_mut("Three Up").unwrap();
+            room.scheduled_start = Some("2030-01-01T20:00:00+00:00".to_string());
+            room.checked_in_players = players.iter().map(|(id, _)| *id).collect();
+            crate::fire_scheduled_start(room, "2030-01-01T20:00:00Z".parse().unwrap());
+            assert_eq!(room.phase, Phase::Lobby);
+            assert!(room.scheduled_start.is_none());
+        }
+        let cancelled = std::iter::from_fn(|| players[0].1.try_recv().ok()).any(|m| {
+            matches!(m, ServerToClient::Info { message } if message.contains("2 checked in, 3 needed"))
+        });
+        assert!(cancelled, "schedule cancelled with two of three checked in");
+
+        let (cat, _rx) = take_seat(&state, "Cat");
+        crate::route_cmd(ClientToServer::SitReady, &state, &mut joined, cat, &tx_out).await;
+        assert_eq!(phase(&state), Phase::Acting, "third ready player starts the hand");
+
+        println!("✅ Min players test passed");
+        println!("   - Out-of-range minimum rejected at table creation");
+        println!("   - Two ready players can't start a min-3 table");
+        println!("   - Nor can two check-ins for a scheduled start");
+        println!("   - Auto-start once the third player is ready");
+    }
+
+    /// With auto_dealer on, electing to start goes straight to game selection
+    /// with a dealer picked, no DelegateDealer needed
+    #[test]
+    fn test_auto_dealer_skips_delegation() {
+        let ids: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+        let table = |auto_dealer: bool| {
+            let mut room = game::Room::new("Auto Dealer Room".to_string());
+            room.auto_dealer = auto_dealer;
+            for (i, id) in ids.iter().enumerate() {
+                seat(&mut room, &format!("Player {}", i + 1), 1000);
+                room.players[i].id = *id;
+                room.players[i].ready = false;
+            }
+            room
+        };
+
+        // Without the flag the table waits in dealer selection
+        let mut manual = table(false);
+        for id in &ids {
+            crate::handle_elect_to_start(&mut manual, *id);
+        }
+        assert_eq!(manual.phase, Phase::DealerSelection);
+        assert_eq!(manual.current_dealer_id, None);
+
+        // First hand: the first player to elect deals
+        let mut room = table(true);
+        for id in [ids[2], ids[0], ids[1], ids[3]] {
+            crate::handle_elect_to_start(&mut room, id);
+        }
+        assert_eq!(room.phase, Phase::GameSelection);
+        assert_eq!(room.current_dealer_id, Some(ids[2]));
+
+        // Later elections rotate from the last dealer
+        room.phase = Phase::Lobby;
+        room.elected_players.clear();
+        for id in &ids {
+            crate::handle_elect_to_start(&mut room, *id);
+        }
+        assert_eq!(room.current_dealer_id, Some(ids[3]));
+
+        println!("✅ Auto dealer test passed");
+        println!("   - Dealer assigned without DelegateDealer");
+        println!("   - Dealer rotates on the next election");
+    }
+
+    /// The snapshot carries the elector count and the dealer's name so the
+    /// dealer-selection screen doesn't have to look them up
+    #[test]
+    fn test_public_room_dealer_fields() {
+        let mut room = game::Room::new("Dealer Name Room".to_string());
+        for name in ["Ann", "Bob", "Joe"] {
+            seat(&mut room, name, 1000);
+        }
+        let snapshot = game::public_room(&room);
+        assert_eq!(snapshot.elected_count, 0);
+        assert_eq!(snapshot.current_dealer_name, None);
+
+        room.elected_players = vec![room.players[0].id, room.players[2].id];
+        room.current_dealer_id = Some(room.players[2].id);
+        let snapshot = game::public_room(&room);
+        assert_eq!(snapshot.elected_count, 2);
+        assert_eq!(snapshot.current_dealer_name.as_deref(), Some("Joe"));
+
+        // A dealer who has left the table has no name to show
+        room.current_dealer_id = Some(Uuid::new_v4());
+        assert_eq!(game::public_room(&room).current_dealer_name, None);
+
+        println!("✅ Public room dealer fields test passed");
+        println!("   - Elector count and dealer name in the snapshot");
+    }
+
+    /// One player who never presses continue doesn't hold the table in the
+    /// comments phase forever, at small tables or ones big enough to elect
+    #[test]
+    fn test_comments_phase_times_out() {
+        for seats in [3, 4] {
+            let mut room = game::Room::new("Comments Timeout Room".to_string());
+            let mut receivers = vec![];
+            for i in 0..seats {
+                receivers.push(seat_with_rx(&mut room, &format!("Player {}", i + 1), 1000).1);
+            }
+            crate::start_hand(&mut room);
+            crate::award_last_player_and_reset(&mut room);
+            assert_eq!(room.phase, Phase::Comments);
+            let deadline = room.comments_deadline.expect("comments phase has a deadline");
+
+            // Everyone but Player 3 continues
+            let afk = room.players[2].id;
+            for p in room.players.iter_mut() {
+                p.ready = p.id != afk;
+            }
+            for rx in receivers.iter_mut() {
+                while rx.try_recv().is_ok() {}
+            }
+            let infos = |rx: &mut mpsc::UnboundedReceiver<ServerToClient>| -> Vec<String> {
+                std::iter::from_fn(|| rx.try_recv().ok())
+                    .filter_map(|m| match m {
+                        ServerToClient::Info { message } => Some(message),
+                        _ => None,
+                    })
+                    .collect()
+            };
+
+            let timeout = room.comments_timeout.unwrap();
+            assert_eq!(crate::expire_comments_phase(&mut room, deadline - timeout), None);
+            assert!(infos(&mut receivers[0]).is_empty(), "no warning while there's time");
+
+            crate::expire_comments_phase(&mut room, deadline - std::time::Duration::from_secs(5));
+            assert_eq!(room.phase, Phase::Comments);
+            let warning = infos(&mut receivers[0]);
+            assert!(warning.iter().any(|m| m.contains("still waiting for Player 3")), "{:?}", warning);
+
+            crate::expire_comments_phase(&mut room, deadline);
+            assert_eq!(room.phase, Phase::Lobby, "{} seats: table moves on without the AFK player", seats);
+            assert!(infos(&mut receivers[1]).iter().any(|m| m == "Moving on without Player 3"));
+            assert_eq!(room.players.len(), seats, "the AFK player keeps their seat");
+            assert_eq!(room.comments_deadline, None);
+
+            // The others ready up and the dealer deals around the AFK player
+            for p in room.players.iter_mut() {
+                p.ready = p.id != afk;
+            }
+            let dealer = room.players[crate::acting_dealer_seat(&room)].id;
+            crate::force_start(&mut room, dealer);
+            assert_eq!(room.phase, Phase::Acting, "{} seats: force-started", seats);
+            assert!(room.players.iter().find(|p| p.id == afk).is_some_and(|p| p.folded));
+        }
+
+        println!("✅ Comments timeout test passed");
+        println!("   - Warning before the deadline");
+        println!("   - Table advances to the lobby without the AFK player, even at 4 seats");
+        println!("   - Dealer can force-start around them");
+    }
+
+    /// Comments can point at a round or a play from the hand just finished,
+    /// but not at something that never happened
+    #[tokio::test]
+    async fn test_comment_about_hand_action() {
+        let (state, _temp_dir) = create_test_state();
+        let (tx_out, _rx_out) = mpsc::unbounded_channel();
+        let mut room = game::Room::new("Chatty".to_string());
+        room.game_variant = GameVariant::TexasHoldem;
+        room.phase = Phase::Comments;
+
+        let (john, mut rx) = seat_with_rx(&mut room, "John", 1000);
+        let river_bet = ActionEvent {
+            round: 3,
+            player_id: john,
+            player_name: "John".to_string(),
+            kind: ActionKind::Bet,
+            amount: 40,
+        };
+        room.actions.push(river_bet.clone());
+        state.inner.lock().insert("Chatty".to_string(), room);
+
+        let mut joined = Some("Chatty".to_string());
+        let post = |about| ClientToServer::PostComment { message: "bold".to_string(), about };
+
+        crate::route_cmd(post(Some(CommentTarget::Action(river_bet.clone()))), &state, &mut joined, john, &tx_out).await;
+        let about = std::iter::from_fn(|| rx.try_recv().ok()).find_map(|m| match m {
+            ServerToClient::GameComment { comment } => Some(comment.about),
+            _ => None,
+        });
+        assert_eq!(about, Some(Some(CommentTarget::Action(river_bet.clone()))));
+        assert_eq!(
+            CommentTarget::Action(river_bet.clone()).label(GameVariant::TexasHoldem),
+            "about John's River bet"
+        );
+
+        // A round or play that isn't in the timeline is refused
+        let invented = ActionEvent { amount: 500, ..river_bet };
+        for bogus in [CommentTarget::Round(1), CommentTarget::Action(invented)] {
+            crate::route_cmd(post(Some(bogus)), &state, &mut joined, john, &tx_out).await;
+            let msgs: Vec<_> = std::iter::from_fn(|| rx.try_recv().ok()).collect();
+            assert!(msgs.iter().any(|m| matches!(m, ServerToClient::Error { .. })));
+            assert!(!msgs.iter().any(|m| matches!(m, ServerToClient::GameComment { .. })));
+        }
+
+        println!("✅ Comment target test passed");
+        println!("   - Comments carry the round or play they refer to");
+        println!("   - Targets outside the last hand are rejected");
+    }
+
+    /// A hold'em hand that ends on a fold at the flop can be rabbit hunted:
+    /// turn and river come off the top of the remaining deck
+    #[test]
+    fn test_rabbit_hunt_reveals_top_of_deck() {
+        let mut room = game::Room::new("Rabbit Room".to_string());
+        room.game_variant = GameVariant::TexasHoldem;
+        room.rabbit_hunt = true;
+        let mut receivers = vec![];
+        for i in 0..2 {
+            receivers.push(seat_with_rx(&mut room, &format!("Player {}", i + 1), 1000).1);
+        }
+        crate::start_hand(&mut room);
+        assert_eq!(room.community_cards.len(), 3, "flop is out");
+
+        let mut expected = room.deck.clone().expect("deck dealt");
+        let expected: Vec<String> = (0..2).map(|_| expected.draw(true).unwrap().to_string()).collect();
+
+        let folder = room.players[room.to_act_seat].id;
+        crate::player_fold(&mut room, folder);
+        assert_eq!(room.phase, Phase::Comments);
+        assert!(room.deck.is_none());
+        assert!(game::public_room(&room).rabbit_hunt_open);
+
+        let hunter = room.players[1].id;
+        crate::player_rabbit_hunt(&mut room, hunter, std::time::Instant::now());
+        let hunt = std::iter::from_fn(|| receivers[0].try_recv().ok())
+            .find_map(|m| match m {
+                ServerToClient::Info { message } if message.contains("rabbit hunts") => Some(message),
+                _ => None,
+            })
+            .expect("rabbit hunt announced");
+        assert!(hunt.ends_with(&expected.join(" ")), "{hunt}");
+        assert!(!game::public_room(&room).rabbit_hunt_open, "one hunt per hand");
+
+        // Once the window has passed the board is gone
+        crate::start_hand(&mut room);
+        let folder = room.players[room.to_act_seat].id;
+        crate::player_fold(&mut room, folder);
+        crate::player_rabbit_hunt(&mut room, hunter, std::time::Instant::now() + std::time::Duration::from_secs(60));
+        let closed = std::iter::from_fn(|| receivers[1].try_recv().ok())
+            .any(|m| matches!(m, ServerToClient::Error { message, .. } if message.contains("closed")));
+        assert!(closed);
+
+        // Tables without the house rule never hold the board
+        room.rabbit_hunt = false;
+        crate::start_hand(&mut room);
+        let folder = room.players[room.to_act_seat].id;
+        crate::player_fold(&mut room, folder);
+        assert!(room.rabbit_board.is_none());
+
+        println!("✅ Rabbit hunt test passed");
+        println!("   - Undealt board drawn from the top of the remaining deck");
+        println!("   - One hunt per hand, only inside the window");
+    }
+
+    /// The schedule clock closes an unclaimed rabbit hunt once its window
+    /// has passed and tells the table
+    #[test]
+    fn test_rabbit_hunt_window_expires() {
+        let mut room = game::Room::new("Rabbit Clock Room".to_string());
+        room.game_variant = GameVariant::TexasHoldem;
+        room.rabbit_hunt = true;
+        let mut receivers = vec![];
+        for i in 0..2 {
+            receivers.push(seat_with_rx(&mut room, &format!("Player {}", i + 1), 1000).1);
+        }
+        crate::start_hand(&mut room);
+        let folder = room.players[room.to_act_seat].id;
+        crate::player_fold(&mut room, folder);
+        assert!(game::public_room(&room).rabbit_hunt_open);
+        for rx in receivers.iter_mut() {
+            while rx.try_recv().is_ok() {}
+        }
+
+        // Still inside the window: nothing changes
+        let now = std::time::Instant::now();
+        crate::expire_rabbit_hunt(&mut room, now);
+        assert!(room.rabbit_board.is_some());
+        assert!(receivers[0].try_recv().is_err());
+
+        crate::expire_rabbit_hunt(&mut room, now + crate::RABBIT_HUNT_WINDOW);
+        assert!(room.rabbit_board.is_none());
+        let closed = std::iter::from_fn(|| receivers[0].try_recv().ok())
+            .any(|m| matches!(m, ServerToClient::UpdateState { snapshot } if !snapshot.rabbit_hunt_open));
+        assert!(closed, "table told the hunt is over");
+
+        println!("✅ Rabbit hunt expiry test passed");
+        println!("   - Held board kept until the deadline");
+        println!("   - Dropped and broadcast once it passes");
+    }
+
+    /// A 5% rake comes off the pot before it's split, up to the cap, and
+    /// the running total shows in the snapshot
+    #[test]
+    fn test_rake_comes_off_the_pot() {
+        let mut room = game::Room::new("Rake Room".to_string());
+        room.game_variant = GameVariant::TexasHoldem;
+        room.rake_percent = 5;
+        for i in 0..2 {
+            seat(&mut room, &format!("Player {}", i + 1), 1000);
+        }
+        crate::start_hand(&mut room);
+
+        // Both play a Broadway board and split what's left of a 1000 pot
+        use Rank::*;
+        let card = |rank, suit| Card { rank, suit, face_up: true };
+        room.community_cards = vec![
+            card(Ace, Suit::Clubs), card(King, Suit::Diamonds), card(Queen, Suit::Hearts),
+            card(Jack, Suit::Spades), card(Ten, Suit::Clubs),
+        ];
+        room.players[0].down_cards = vec![card(Two, Suit::Hearts), card(Three, Suit::Spades)];
+        room.players[1].down_cards = vec![card(Two, Suit::Spades), card(Four, Suit::Diamonds)];
+        room.pot = 1000;
+        let before: Vec<u64> = room.players.iter().map(|p| p.chips).collect();
+
+        crate::do_showdown(&mut room);
+        let paid: u64 = room.players.iter().zip(&before).map(|(p, b)| p.chips - b).sum();
+        assert_eq!(paid, 950);
+        assert_eq!(room.raked_total, 50);
+        assert_eq!(game::public_room(&room).raked_total, 50);
+
+        // A capped rake stops at the cap, including when everyone folds
+        room.rake_cap = Some(20);
+        crate::start_hand(&mut room);
+        room.pot = 1000;
+        let folder = room.players[room.to_act_seat].id;
+        let total_before: u64 = room.players.iter().map(|p| p.chips).sum();
+        crate::player_fold(&mut room, folder);
+        let total_after: u64 = room.players.iter().map(|p| p.chips).sum();
+        assert_eq!(total_after - total_before, 980);
+        assert_eq!(room.raked_total, 70);
+
+        println!("✅ Rake test passed");
+        println!("   - 5% of a 1000 pot raked, 950 paid out");
+        println!("   - Cap respected when the last player is awarded the pot");
+    }
+
+    /// With a maximum buy-in, seating and rebuys stop at the cap, while
+    /// winnings above it are left alone
+    #[test]
+    fn test_rebuy_capped_by_max_buyin() {
+        let mut room = game::Room::new("Capped Room".to_string());
+        room.max_buyin = Some(800);
+        let mut receivers = vec![];
+        for (i, chips) in [700, 1500].into_iter().enumerate() {
+            receivers.push(seat_with_rx(&mut room, &format!("Player {}", i + 1), chips).1);
+        }
+        let (near_cap, winner) = (room.players[0].id, room.players[1].id);
+
+        // Near the cap: topped up to 800, not to REBUY_CHIPS
+        crate::handle_rebuy(&mut room, near_cap);
+        assert_eq!(room.players[0].chips, 800);
+        crate::handle_rebuy(&mut room, near_cap);
+        assert_eq!(room.players[0].chips, 800);
+        assert!(std::iter::from_fn(|| receivers[0].try_recv().ok())
+            .any(|m| matches!(m, ServerToClient::Error { .. })));
+
+        // Won past the cap: nothing to rebuy, stack untouched
+        crate::handle_rebuy(&mut room, winner);
+        assert_eq!(room.players[1].chips, 1500);
+
+        // A spectator taking a seat starts at the cap
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let watcher = Uuid::new_v4();
+        room.spectators.push(game::Spectator { id: watcher, name: "Watcher".to_string(), tx });
+        crate::handle_take_seat(&mut room, watcher);
+        assert_eq!(room.players.iter().find(|p| p.id == watcher).map(|p| p.chips), Some(800));
+
+        println!("✅ Max buy-in test passed");
+        println!("   - Rebuy tops up to the cap, not beyond");
+        println!("   - New seats start at the cap; winnings aren't clamped");
+    }
+
+    /// The host can close a table between hands: everyone at it is told and
+    /// the room and its registry entry are gone
+    #[tokio::test]
+    async fn test_close_table_notifies_and_removes() {
+        let (state, _temp_dir) = create_test_state();
+        let (tx_out, _rx_out) = mpsc::unbounded_channel();
+        let mut room = game::Room::new("Closing".to_string());
+        let mut receivers = vec![];
+        let mut ids = vec![];
+        for name in ["Host", "Guest"] {
+            let (id, rx) = seat_with_rx(&mut room, name, 1000);
+            receivers.push(rx);
+            ids.push(id);
+        }
+        let (tx, mut watcher_rx) = mpsc::unbounded_channel();
+        room.spectators.push(game::Spectator { id: Uuid::new_v4(), name: "Watcher".to_string(), tx });
+        state.inner.lock().insert("Closing".to_string(), room);
+        state.distributed_tables.lock().insert(
+            "Closing".to_string(),
+            TableInfo {
+                name: "Closing".to_string(),
+                game_variant: GameVariant::SevenTwentySeven,
+                player_count: 2,
+                phase: Phase::Lobby,
+                server_port: Some(9100),
+            },
+        );
+
+        // A guest can't close it
+        let mut joined = Some("Closing".to_string());
+        crate::route_cmd(ClientToServer::CloseTable, &state, &mut joined, ids[1], &tx_out).await;
+        assert!(state.inner.lock().contains_key("Closing"));
+        assert!(std::iter::from_fn(|| receivers[1].try_recv().ok())
+            .any(|m| matches!(m, ServerToClient::Error { .. })));
+
+        crate::route_cmd(ClientToServer::CloseTable, &state, &mut joined, ids[0], &tx_out).await;
+        assert!(!state.inner.lock().contains_key("Closing"));
+        assert!(!state.distributed_tables.lock().contains_key("Closing"));
+        assert_eq!(joined, None);
+        for rx in receivers.iter_mut().chain(std::iter::once(&mut watcher_rx)) {
+            let told = std::iter::from_fn(|| rx.try_recv().ok())
+                .any(|m| matches!(m, ServerToClient::Removed { reason } if reason.contains("closed the table")));
+            assert!(told, "every occupant hears the table closed");
+        }
+
+        println!("✅ Close table test passed");
+        println!("   - Only the host can close; players and spectators notified");
+        println!("   - Room and registry entry removed");
+    }
+
+    /// A kicked player is told they were removed before their seat goes;
+    /// only the host can kick, and only between hands
+    #[tokio::test]
+    async fn test_kicked_player_receives_removed() {
+        let (state, _temp_dir) = create_test_state();
+        let (tx_out, _rx_out) = mpsc::unbounded_channel();
+        let mut room = game::Room::new("Kicking".to_string());
+        let mut receivers = vec![];
+        let mut ids = vec![];
+        for name in ["Host", "Rowdy", "Quiet"] {
+            let (id, rx) = seat_with_rx(&mut room, name, 1000);
+            receivers.push(rx);
+            ids.push(id);
+        }
+        state.inner.lock().insert("Kicking".to_string(), room);
+        let seated = |state: &crate::AppState| state.inner.lock().get("Kicking").unwrap().players.len();
+        let mut joined = Some("Kicking".to_string());
+
+        // Not the host: refused
+        crate::route_cmd(ClientToServer::KickPlayer { player_id: ids[2] }, &state, &mut joined, ids[1], &tx_out).await;
+        assert_eq!(seated(&state), 3);
+
+        crate::route_cmd(ClientToServer::KickPlayer { player_id: ids[1] }, &state, &mut joined, ids[0], &tx_out).await;
+        assert_eq!(seated(&state), 2);
+        let removed = std::iter::from_fn(|| receivers[1].try_recv().ok())
+            .find_map(|m| match m {
+                ServerToClient::Removed { reason } => Some(reason),
+                _ => None,
+            })
+            .expect("kicked player told");
+        assert!(removed.contains("dealer"), "{removed}");
+        assert!(std::iter::from_fn(|| receivers[2].try_recv().ok())
+            .all(|m| !matches!(m, ServerToClient::Removed { .. })), "others stay");
+
+        println!("✅ Kick test passed");
+        println!("   - Kicked player receives Removed with a reason");
+        println!("   - Non-hosts can't kick");
+    }
+
+    /// Un-readying in the lobby holds off the auto-start; once dealt it's
+    /// refused
+    #[tokio::test]
+    async fn test_sit_unready_prevents_auto_start() {
+        let (state, _temp_dir) = create_test_state();
+        let (tx_out, _rx_out) = mpsc::unbounded_channel();
+        let mut room = game::Room::new("Dithering".to_string());
+        let mut receivers = vec![];
+        let mut ids = vec![];
+        for name in ["Ann", "Bob"] {
+            let (id, rx) = seat_with_rx(&mut room, name, 1000);
+            receivers.push(rx);
+            ids.push(id);
+        }
+        for p in &mut room.players {
+            p.ready = false;
+        }
+        state.inner.lock().insert("Dithering".to_string(), room);
+        let phase = |state: &crate::AppState| state.inner.lock().get("Dithering").unwrap().phase.clone();
+        let mut joined = Some("Dithering".to_string());
+
+        crate::route_cmd(ClientToServer::SitReady, &state, &mut joined, ids[0], &tx_out).await;
+        crate::route_cmd(ClientToServer::SitUnready, &state, &mut joined, ids[0], &tx_out).await;
+        crate::route_cmd(ClientToServer::SitReady, &state, &mut joined, ids[1], &tx_out).await;
+        assert_eq!(phase(&state), Phase::Lobby, "Ann took it back, so no auto-start");
+
+        crate::route_cmd(ClientToServer::SitReady, &state, &mut joined, ids[0], &tx_out).await;
+        assert_eq!(phase(&state), Phase::Acting);
+
+        // Too late once the hand is dealt
+        crate::route_cmd(ClientToServer::SitUnready, &state, &mut joined, ids[0], &tx_out).await;
+        assert!(std::iter::from_fn(|| receivers[0].try_recv().ok())
+            .any(|m| matches!(m, ServerToClient::Error { .. })));
+        assert_eq!(phase(&state), Phase::Acting);
+
+        println!("✅ Sit unready test passed");
+        println!("   - Retracting readiness prevents the auto-start");
+        println!("   - Refused once the hand has begun");
+    }
+
+    /// Test that a delegated dealer, not whoever sits in dealer_seat, is the one allowed to start
+    #[test]
+    fn test_start_hand_uses_delegated_dealer() {
+        let mut room = game::Room::new("Delegated Start Room".to_string());
+        for i in 0..3 {
+            seat(&mut room, &format!("Player {}", i + 1), 1000);
+        }
+
+        // No delegation yet: the dealer seat decides
+        room.dealer_seat = 0;
+        assert_eq!(crate::acting_dealer_seat(&room), 0);
+
+        // Dealer handed to seat 2 while dealer_seat still points at seat 0
+        room.current_dealer_id = Some(room.players[2].id);
+        assert_eq!(room.dealer_seat, 0);
+        assert_eq!(crate::acting_dealer_seat(&room), 2);
+
+        // A delegated dealer who has left falls back to the dealer seat
+        room.current_dealer_id = Some(Uuid::new_v4());
+        assert_eq!(crate::acting_dealer_seat(&room), 0);
+
+        println!("✅ Delegated start test passed");
+        println!("   - Delegated dealer's seat is the one that may start");
+        println!("   - Falls back to dealer_seat without a seated delegate");
+    }
+
+    /// Test that a chat history fetch merges lounge and global chat, oldest first, up to the limit
+    #[tokio::test]
+    async fn test_fetch_chat_history_merges_scopes() {
+        let (state, _temp_dir) = create_test_state();
+        let (tx_out, mut rx_out) = mpsc::unbounded_channel();
+        for (scope, message, timestamp) in [
+            (MessageScope::Group, "lounge one", "2025-01-01T10:00:00Z"),
+            (MessageScope::Global, "global one", "2025-01-01T10:01:00Z"),
+            (MessageScope::Group, "lounge two", "2025-01-01T10:02:00Z"),
+            (MessageScope::Match, "table talk", "2025-01-01T10:03:00Z"),
+        ] {
+            let stored = StoredMessage {
+                player_name: "Alice".to_string(),
+                message: message.to_string(),
+                scope,
+                room: (scope == MessageScope::Match).then(|| "Table".to_string()),
+                timestamp: timestamp.to_string(),
+                recipient: None,
+            };
+            state.message_store.store_message(&stored).await.unwrap();
+        }
+
+        let mut joined_room = None;
+        crate::route_cmd(ClientToServer::FetchChatHistory { limit: 2 }, &state, &mut joined_room, Uuid::new_v4(), &tx_out).await;
+
+        match rx_out.try_recv() {
+            Ok(ServerToClient::ChatHistory { messages }) => {
+                let texts: Vec<&str> = messages.iter().map(|m| m.message.as_str()).collect();
+                assert_eq!(texts, ["global one", "lounge two"]);
+            }
+            other => panic!("expected ChatHistory, got {:?}", other),
+        }
+
+        println!("✅ Chat history fetch test passed");
+        println!("   - Group and Global merged by time, table chat left out");
+        println!("   - Limit keeps the most recent, oldest first");
+    }
+
+    /// Test that the server measures player names in characters, matching the client
+    #[tokio::test]
+    async fn test_join_name_length_in_characters() {
+        let (state, _temp_dir) = create_test_state();
+
+        // 20 CJK characters is 60 bytes but a valid name
+        let (tx_out, mut rx_out) = mpsc::unbounded_channel();
+        let mut joined_room = None;
+        let name = "漢".repeat(20);
+        crate::route_cmd(ClientToServer::Join { room: "Names".to_string(), name: name.clone() }, &state, &mut joined_room, Uuid::new_v4(), &tx_out).await;
+        assert_eq!(joined_room.as_deref(), Some("Names"));
+        assert!(std::iter::from_fn(|| rx_out.try_recv().ok()).any(|m| matches!(m, ServerToClient::Joined { .. })));
+        assert_eq!(state.inner.lock()["Names"].players[0].name, name);
+
+        // One more is too long
+        let (tx_out, mut rx_out) = mpsc::unbounded_channel();
+        let mut joined_room = None;
+        crate::route_cmd(ClientToServer::Join { room: "Names".to_string(), name: "漢".repeat(21) }, &state, &mut joined_room, Uuid::new_v4(), &tx_out).await;
+        assert!(joined_room.is_none());
+        assert!(matches!(rx_out.try_recv(), Ok(ServerToClient::Error { .. })));
+        assert_eq!(state.inner.lock()["Names"].players.len(), 1);
+
+        println!("✅ Join name length test passed");
+        println!("   - Multibyte names measured in characters");
+        println!("   - Over-long names refused before joining");
+    }
+
+    /// Test that a second "Alice" joining the same room gets a distinct name
+    #[tokio::test]
+    async fn test_duplicate_name_in_room_is_disambiguated() {
+        let (state, _temp_dir) = create_test_state();
+        let mut outs = vec![];
+        for name in ["Alice", "alice", "Alice"] {
+            let (tx_out, rx_out) = mpsc::unbounded_channel();
+            let mut joined_room = None;
+            crate::route_cmd(ClientToServer::Join { room: "Twins".to_string(), name: name.to_string() }, &state, &mut joined_room, Uuid::new_v4(), &tx_out).await;
+            assert_eq!(joined_room.as_deref(), Some("Twins"));
+            outs.push(rx_out);
+        }
+
+        let names: Vec<String> = state.inner.lock()["Twins"].players.iter().map(|p| p.name.clone()).collect();
+        assert_eq!(names, ["Alice", "alice (2)", "Alice (3)"]);
+
+        // The renamed player is told, and everyone sees the resolved name
+        assert!(std::iter::from_fn(|| outs[1].try_recv().ok())
+            .any(|m| matches!(m, ServerToClient::Info { message } if message.contains("alice (2)"))));
+        let seen_by_first = std::iter::from_fn(|| outs[0].try_recv().ok())
+            .filter_map(|m| match m {
+                ServerToClient::UpdateState { snapshot } => Some(snapshot),
+                _ => None,
+            })
+            .last()
+            .unwrap();
+        assert!(seen_by_first.players.iter().any(|p| p.name == "Alice (3)"));
+
+        // With suffixing off the duplicate is refused instead
+        let room = &state.inner.lock()["Twins"];
+        assert!(crate::unique_name_in_room(room, Uuid::new_v4(), "ALICE", false).is_err());
+        assert_eq!(crate::unique_name_in_room(room, Uuid::new_v4(), "Bob", false).as_deref(), Ok("Bob"));
+
+        println!("✅ Duplicate name test passed");
+        println!("   - Repeated names get (2), (3) suffixes, ignoring case");
+        println!("   - Rejected instead when suffixing is off");
+    }
+
+    /// Test that a check/fold pre-action checks when free, lasts one street, and folds to a bet
+    #[test]
+    fn test_check_fold_pre_action_across_streets() {
+        let mut room = game::Room::new("Pre-action Room".to_string());
+        room.game_variant = GameVariant::TexasHoldem;
+        for i in 0..3 {
+            seat(&mut room, &format!("Player {}", i + 1), 1000);
+        }
+        crate::start_hand(&mut room);
+        assert!(room.in_betting);
+
+        // The cautious player is last to act on the flop
+        let first = room.to_act_seat;
+        let cautious_seat = (first + 2) % 3;
+        let cautious = room.players[cautious_seat].id;
+        crate::handle_set_pre_action(&mut room, cautious, Some(PreAction::CheckFold));
+
+        // Flop: checked round to them, so they check automatically
+        let flop_round = room.round;
+        for _ in 0..2 {
+            let id = room.players[room.to_act_seat].id;
+            crate::player_check(&mut room, id);
+        }
+        assert_eq!(room.round, flop_round + 1, "street over without waiting");
+        assert!(room.actions.iter().any(|a| a.player_id == cautious && a.kind == ActionKind::Check));
+        assert!(room.pre_actions.is_empty(), "pre-action doesn't carry to the turn");
+
+        // Turn: re-sent (as the client does each street), then someone bets
+        crate::handle_set_pre_action(&mut room, cautious, Some(PreAction::CheckFold));
+        let bettor = room.players[room.to_act_seat].id;
+        crate::player_bet_or_raise(&mut room, bettor, false);
+        let caller = room.players[room.to_act_seat].id;
+        assert_ne!(caller, cautious);
+        crate::player_call(&mut room, caller);
+        assert!(room.players[cautious_seat].folded, "folds to the bet");
+
+        println!("✅ Check/fold pre-action test passed");
+        println!("   - Checks automatically when nobody has bet");
+        println!("   - Cleared at the end of the street");
+        println!("   - Folds to a bet on the next street when re-sent");
+    }
+
+    /// Test that finished hands are counted, with their average pot, and show up on /metrics
+    #[test]
+    fn test_room_stats_track_hands_and_pots() {
+        let mut room = game::Room::new("Stats Room".to_string());
+        room.game_variant = GameVariant::TexasHoldem;
+        for i in 0..2 {
+            seat(&mut room, &format!("Player {}", i + 1), 1000);
+        }
+        assert_eq!(room.stats.hands_played, 0);
+        assert_eq!(room.stats.avg_pot(), 0.0);
+
+        // First hand: folded straight away, only the antes in the pot
+        crate::start_hand(&mut room);
+        let antes = room.pot;
+        let folder = room.players[room.to_act_seat].id;
+        crate::player_fold(&mut room, folder);
+        assert_eq!(room.stats.hands_played, 1);
+        assert_eq!(room.stats.avg_pot(), antes as f64);
+
+        // Second hand: a bet and a fold on the flop
+        crate::start_hand(&mut room);
+        let bettor = room.players[room.to_act_seat].id;
+        crate::player_bet_or_raise(&mut room, bettor, false);
+        let bigger = room.pot;
+        let folder = room.players[room.to_act_seat].id;
+        crate::player_fold(&mut room, folder);
+        assert_eq!(room.stats.hands_played, 2);
+        assert_eq!(room.stats.avg_pot(), (antes + bigger) as f64 / 2.0);
+        assert!(room.hand_started_at.is_none());
+
+        let mut rooms = HashMap::new();
+        rooms.insert(room.name.clone(), room);
+        let metrics = crate::metrics_text(&rooms);
+        assert!(metrics.contains("cctmog_rooms 1\n"));
+        assert!(metrics.contains("cctmog_room_hands_played{room=\"Stats Room\"} 2\n"));
+        assert!(metrics.contains(&format!("cctmog_room_avg_pot{{room=\"Stats Room\"}} {:.2}", (antes + bigger) as f64 / 2.0)));
+
+        println!("✅ Room stats test passed");
+        println!("   - Hand count and average pot updated as hands finish");
+        println!("   - Reported per room on /metrics");
+    }
+
+    /// Test that a room switched from Hold'em to 7/27 between hands never shows the old board
+    #[test]
+    fn test_switch_to_seven_twenty_seven_clears_board() {
+        let mut room = game::Room::new("Switch Room".to_string());
+        room.game_variant = GameVariant::TexasHoldem;
+        for i in 0..2 {
+            seat(&mut room, &format!("Player {}", i + 1), 1000);
+        }
+        crate::start_hand(&mut room);
+        let folder = room.players[room.to_act_seat].id;
+        crate::player_fold(&mut room, folder);
+        assert_eq!(room.phase, Phase::Comments);
+        assert_eq!(game::public_room(&room).community_cards.len(), 3, "flop still shown after the hand");
+
+        // Stale board left behind: the snapshot hides it under 7/27
+        room.game_variant = GameVariant::SevenTwentySeven;
+        assert!(!room.community_cards.is_empty());
+        assert!(game::board(&room).is_empty());
+        let snapshot = game::public_room(&room);
+        assert!(snapshot.community_cards.is_empty());
+        assert_eq!(snapshot.street, None);
+
+        // Switching the normal way clears it outright
+        room.game_variant = GameVariant::TexasHoldem;
+        let host = room.players[0].id;
+        crate::handle_select_game_variant(&mut room, host, GameVariant::SevenTwentySeven);
+        assert!(room.community_cards.is_empty());
+
+        crate::start_hand(&mut room);
+        assert!(room.community_cards.is_empty());
+        assert!(game::public_room(&room).community_cards.is_empty());
+
+        println!("✅ Variant switch board test passed");
+        println!("   - 7/27 snapshots never carry a board");
+        println!("   - Changing variant drops the old board");
+    }
+
+    /// Test that the lounge roster shows who is hosting and who is at which table
+    #[tokio::test]
+    async fn test_lounge_roster_shows_room_and_hosting() {
+        let (state, _temp_dir) = create_test_state();
+        let (tx_out, mut rx_out) = mpsc::unbounded_channel();
+
+        let mut alice_room = None;
+        crate::route_cmd(ClientToServer::Join { room: "Roster Room".to_string(), name: "Alice".to_string() }, &state, &mut alice_room, Uuid::new_v4(), &tx_out).await;
+        let mut carol_room = None;
+        let carol = Uuid::new_v4();
+        crate::route_cmd(ClientToServer::JoinLounge { name: "Carol".to_string() }, &state, &mut carol_room, carol, &tx_out).await;
+        crate::route_cmd(ClientToServer::VolunteerToHost { port: 9100 }, &state, &mut carol_room, carol, &tx_out).await;
+        while rx_out.try_recv().is_ok() {}
+
+        let mut bob_room = None;
+        crate::route_cmd(ClientToServer::ListLoungePlayers, &state, &mut bob_room, Uuid::new_v4(), &tx_out).await;
+        let players = std::iter::from_fn(|| rx_out.try_recv().ok())
+            .find_map(|m| match m {
+                ServerToClient::LoungePlayers { players } => Some(players),
+                _ => None,
+            })
+            .expect("roster sent");
+        assert_eq!(players, vec![
+            LoungePlayer { name: "Carol".to_string(), hosting: true, room: None },
+            LoungePlayer { name: "Alice".to_string(), hosting: false, room: Some("Roster Room".to_string()) },
+        ]);
+
+        println!("✅ Lounge roster test passed");
+        println!("   - Lounge members listed first, with hosting status");
+        println!("   - Table players listed with their room");
+    }
+
+    /// Test that spectator table chat can be kept away from the seated players
+    #[tokio::test]
+    async fn test_spectator_chat_isolated_from_players() {
+        let (state, _temp_dir) = create_test_state();
+        let mut room = game::Room::new("Quiet Table".to_string());
+        room.spectator_chat_to_players = false;
+        let (_, mut player_rx) = seat_with_rx(&mut room, "Player", 1000);
+        let mut spectator_rxs = vec![];
+        for name in ["Watcher 1", "Watcher 2"] {
+            let (tx, rx) = mpsc::unbounded_channel();
+            room.spectators.push(game::Spectator { id: Uuid::new_v4(), name: name.to_string(), tx });
+            spectator_rxs.push(rx);
+        }
+        let watcher = room.spectators[0].id;
+        state.inner.lock().insert("Quiet Table".to_string(), room);
+
+        let (tx_out, _rx_out) = mpsc::unbounded_channel();
+        let mut joined_room = Some("Quiet Table".to_string());
+        let chat = ClientToServer::Chat { message: "nice fold".to_string(), scope: MessageScope::Match };
+        crate::route_cmd(chat, &state, &mut joined_room, watcher, &tx_out).await;
+
+        let got_chat = |rx: &mut mpsc::UnboundedReceiver<ServerToClient>| {
+            std::iter::from_fn(|| rx.try_recv().ok()).any(|m| matches!(m, ServerToClient::ChatMessage { .. }))
+        };
+        assert!(got_chat(&mut spectator_rxs[1]), "other spectators see it");
+        assert!(!got_chat(&mut player_rx), "seated players don't");
+
+        println!("✅ Spectator chat isolation test passed");
+        println!("   - Spectator table chat stays among spectators when the table says so");
+    }
+
+    /// Test that a seat status is length-checked, shown to everyone and clearable
+    #[test]
+    fn test_set_status_validates_and_clears() {
+        let mut room = game::Room::new("Status Room".to_string());
+        let (id, mut rx) = seat_with_rx(&mut room, "Alice", 1000);
+
+        crate::handle_set_status(&mut room, id, Some("  BRB  ".to_string()));
+        assert_eq!(room.players[0].status.as_deref(), Some("BRB"));
+        assert_eq!(game::public_room(&room).players[0].status.as_deref(), Some("BRB"));
+
+        // Too long: refused, old status kept
+        while rx.try_recv().is_ok() {}
+        crate::handle_set_status(&mut room, id, Some("x".repeat(21)));
+        assert!(matches!(rx.try_recv(), Ok(ServerToClient::Error { .. })));
+        assert_eq!(room.players[0].status.as_deref(), Some("BRB"));
+
+        // None and blank both clear it
+        crate::handle_set_status(&mut room, id, None);
+        assert_eq!(room.players[0].status, None);
+        crate::handle_set_status(&mut room, id, Some("back".to_string()));
+        crate::handle_set_status(&mut room, id, Some("   ".to_string()));
+        assert_eq!(room.players[0].status, None);
+
+        println!("✅ Seat status test passed");
+        println!("   - Status trimmed and shown in the public snapshot");
+        println!("   - Over 20 characters rejected; None or blank clears");
+    }
+
+    /// Test that a table waiting for a dealer after comments can elect to start
+    #[test]
+    fn test_elect_to_start_from_waiting_for_dealer() {
+        let mut room = game::Room::new("Waiting Room".to_string());
+        let mut ids = vec![];
+        for i in 0..4 {
+            ids.push(seat(&mut room, &format!("Player {}", i + 1), 1000));
+        }
+        room.phase = Phase::WaitingForDealer;
+
+        crate::handle_elect_to_start(&mut room, ids[0]);
+        assert_eq!(room.phase, Phase::WaitingForDealer);
+        assert_eq!(game::public_room(&room).elected_count, 1);
+        for id in &ids[1..] {
+            crate::handle_elect_to_start(&mut room, *id);
+        }
+        assert_eq!(room.phase, Phase::DealerSelection);
+
+        // Not mid-hand, though
+        room.phase = Phase::Acting;
+        room.elected_players.clear();
+        crate::handle_elect_to_start(&mut room, ids[0]);
+        assert!(room.elected_players.is_empty());
+
+        println!("✅ Elect from WaitingForDealer test passed");
+        println!("   - Elections count while waiting for a dealer");
+        println!("   - Everyone electing moves on to dealer selection");
+    }
+
+    /// Test that only tables without a board can deal from a multi-deck shoe
+    #[tokio::test]
+    async fn test_shoe_refused_for_community_games() {
+        let (state, _temp_dir) = create_test_state();
+        let (tx_out, mut rx_out) = mpsc::unbounded_channel();
+        let create = |name: &str, game_variant| ClientToServer::CreateTable {
+            name: name.to_string(),
+            game_variant,
+            ante: 10,
+            limit_small: 10,
+            limit_big: 20,
+            max_raises: 3,
+            min_players: None,
+            rake_percent: 0,
+            rake_cap: None,
+            max_buyin: None,
+            deck_count: Some(2),
+            spectator_chat_to_players: None,
+            muck_window_secs: None,
+            max_draw_rounds: None,
+            auto_dealer: false,
+            rabbit_hunt: false,
+        };
+
+        let mut none = None;
+        crate::route_cmd(create("Shoe Holdem", GameVariant::TexasHoldem), &state, &mut none, Uuid::new_v4(), &tx_out).await;
+        assert!(matches!(rx_out.try_recv(), Ok(ServerToClient::Error { .. })));
+        assert!(!state.inner.lock().contains_key("Shoe Holdem"));
+
+        let mut none = None;
+        crate::route_cmd(create("Shoe 727", GameVariant::SevenTwentySeven), &state, &mut none, Uuid::new_v4(), &tx_out).await;
+        assert_eq!(state.inner.lock()["Shoe 727"].deck_count, 2);
+
+        println!("✅ Shoe variant test passed");
+        println!("   - Multi-deck shoes refused for community-card games");
+        println!("   - Allowed for 7/27");
+    }
+
+    /// Test that the Showdown message says who was paid what, at showdown and
+    /// when everyone else folds
+    #[test]
+    fn test_showdown_sends_payouts() {
+        use Rank::*;
+        use Suit::*;
+        let card = |rank, suit| Card { rank, suit, face_up: false };
+
+        let mut room = game::Room::new("Payout Room".to_string());
+        room.game_variant = GameVariant::TexasHoldem;
+        let mut receivers = vec![];
+        for i in 0..2 {
+            receivers.push(seat_with_rx(&mut room, &format!("Player {}", i + 1), 1000).1);
+        }
+        room.muck_window = None;
+        room.pot = 100;
+        room.players[0].down_cards = vec![card(Ace, Spades), card(Ace, Clubs)];
+        room.players[1].down_cards = vec![card(Two, Spades), card(Seven, Clubs)];
+        room.community_cards = vec![card(King, Hearts), card(Nine, Diamonds), card(Five, Clubs), card(Four, Hearts), card(Jack, Spades)];
+        let winner = room.players[0].id;
+        let payouts = |rx: &mut mpsc::UnboundedReceiver<ServerToClient>| {
+            std::iter::from_fn(|| rx.try_recv().ok())
+                .find_map(|m| match m {
+                    ServerToClient::Showdown { payouts, .. } => Some(payouts),
+                    _ => None,
+                })
+                .expect("showdown sent")
+        };
+
+        crate::do_showdown(&mut room);
+        assert_eq!(payouts(&mut receivers[1]), vec![(winner, 100)]);
+
+        // Everyone else folds: the last player's pot is reported too
+        room.pot = 60;
+        room.players[1].folded = true;
+        let last = room.players[0].id;
+        crate::award_last_player_and_reset(&mut room);
+        assert_eq!(payouts(&mut receivers[1]), vec![(last, 60)]);
+
+        println!("✅ Showdown payouts test passed");
+        println!("   - Payouts sent with the showdown and with a fold-out win");
+    }
+
+    /// A split pot that doesn't divide evenly gives the odd chip to the first
+    /// winner in seat order instead of losing it
+    #[test]
+    fn test_split_pot_odd_chip() {
+        use Rank::*;
+        use Suit::*;
+        let card = |rank, suit| Card { rank, suit, face_up: false };
+
+        let mut room = game::Room::new("Odd Chip Room".to_string());
+        room.game_variant = GameVariant::TexasHoldem;
+        for i in 0..3 {
+            seat(&mut room, &format!("Player {}", i + 1), 1000);
+            room.players[i].ready = false;
+        }
+        room.players[1].folded = true;
+        room.muck_window = None;
+        room.pot = 101;
+        // Broadway on the board: both live players play it and chop
+        room.community_cards = vec![card(Ace, Spades), card(King, Hearts), card(Queen, Diamonds), card(Jack, Clubs), card(Ten, Spades)];
+        room.players[0].down_cards = vec![card(Two, Hearts), card(Three, Clubs)];
+        room.players[1].down_cards = vec![card(Four, Spades), card(Six, Clubs)];
+        room.players[2].down_cards = vec![card(Four, Diamonds), card(Five, Hearts)];
+
+        crate::do_showdown(&mut room);
+        let chips: Vec<u64> = room.players.iter().map(|p| p.chips).collect();
+        assert_eq!(chips, [1051, 1000, 1050]);
+        assert_eq!(chips.iter().sum::<u64>(), 3101, "no chips lost to rounding");
+
+        println!("✅ Split pot odd chip test passed");
+        println!("   - 101 chips chopped 51/50, odd chip to the first seat");
+    }
+
+    /// A short all-in stack can only win what it covered: it takes the main
+    /// pot and the side pot goes to the best hand among the players above it
+    #[test]
+    fn test_all_in_side_pot() {
+        use Rank::*;
+        use Suit::*;
+        let card = |rank, suit| Card { rank, suit, face_up: false };
+
+        let mut room = game::Room::new("Side Pot Room".to_string());
+        room.game_variant = GameVariant::TexasHoldem;
+        let short = seat(&mut room, "Short", 0);
+        let big = seat(&mut room, "Big", 700);
+        let cover = seat(&mut room, "Cover", 700);
+        room.muck_window = None;
+        room.contributed = [(short, 100), (big, 300), (cover, 300)].into_iter().collect();
+        room.pot = 700;
+        room.community_cards = vec![card(Two, Clubs), card(Seven, Diamonds), card(Nine, Hearts), card(Jack, Clubs), card(King, Diamonds)];
+        room.players[0].down_cards = vec![card(Ace, Hearts), card(Ace, Spades)];
+        room.players[1].down_cards = vec![card(King, Hearts), card(Queen, Spades)];
+        room.players[2].down_cards = vec![card(Three, Hearts), card(Four, Spades)];
+
+        // All-in with nothing behind: no bet or raise on offer
+        room.phase = Phase::Acting;
+        room.in_betting = true;
+        room.to_act_seat = 0;
+        assert_eq!(game::turn_options(&room, 0).can_bet, None);
+        room.to_act_seat = 1;
+        assert!(game::turn_options(&room, 1).can_bet.is_some());
+
+        crate::do_showdown(&mut room);
+        let chips: Vec<u64> = room.players.iter().map(|p| p.chips).collect();
+        assert_eq!(chips, [300, 1100, 700], "aces take the 300 main pot, kings the 400 side pot");
+
+        println!("✅ All-in side pot test passed");
+        println!("   - Short stack wins only the main pot it covered");
+        println!("   - Side pot goes to the best covering hand");
+        println!("   - No bet offered to a player with no chips");
+    }
+
+    /// Test that whoever starts the hand still has their turn options last,
+    /// with no state update arriving after YourTurn
+    #[tokio::test]
+    async fn test_start_hand_turn_options_not_overwritten() {
+        let (state, _temp_dir) = create_test_state();
+        let mut room = game::Room::new("Starter Room".to_string());
+        let mut receivers = vec![];
+        for i in 0..2 {
+            receivers.push(seat_with_rx(&mut room, &format!("Player {}", i + 1), 1000).1);
+        }
+        // The delegated dealer sits left of the button, so they start and act first
+        let starter = room.players[1].id;
+        room.current_dealer_id = Some(starter);
+        state.inner.lock().insert("Starter Room".to_string(), room);
+
+        let (tx_out, _rx_out) = mpsc::unbounded_channel();
+        let mut joined_room = Some("Starter Room".to_string());
+        crate::route_cmd(ClientToServer::StartHand, &state, &mut joined_room, starter, &tx_out).await;
+        assert_eq!(state.inner.lock()["Starter Room"].to_act_seat, 1);
+
+        let last = std::iter::from_fn(|| receivers[1].try_recv().ok()).last();
+        assert!(matches!(last, Some(ServerToClient::YourTurn { .. })), "YourTurn comes last, got {:?}", last);
+
+        println!("✅ Start hand turn options test passed");
+        println!("   - No UpdateState after YourTurn for a starter who acts first");
+    }
+
+    /// Spectators go through the same name rules as players, so taking a
+    /// seat can't bring a bad or duplicate name to the table
+    #[tokio::test]
+    async fn test_spectator_name_checked_before_taking_seat() {
+        let (state, _temp_dir) = create_test_state();
+        let (tx_out, _rx_out) = mpsc::unbounded_channel();
+        let mut alice_room = None;
+        crate::route_cmd(ClientToServer::Join { room: "Rail".to_string(), name: "Alice".to_string() }, &state, &mut alice_room, Uuid::new_v4(), &tx_out).await;
+
+        // A name the shared rules refuse never reaches the rail
+        let (tx_out, mut rx_out) = mpsc::unbounded_channel();
+        let mut joined_room = None;
+        crate::route_cmd(ClientToServer::JoinAsSpectator { room: "Rail".to_string(), name: "   ".to_string() }, &state, &mut joined_room, Uuid::new_v4(), &tx_out).await;
+        assert!(joined_room.is_none());
+        assert!(matches!(rx_out.try_recv(), Ok(ServerToClient::Error { .. })));
+        assert!(state.inner.lock()["Rail"].spectators.is_empty());
+
+        // A second Alice watches under a distinct name and keeps it when seated
+        let (tx_out, mut rx_out) = mpsc::unbounded_channel();
+        let mut joined_room = None;
+        let watcher = Uuid::new_v4();
+        crate::route_cmd(ClientToServer::JoinAsSpectator { room: "Rail".to_string(), name: "  alice ".to_string() }, &state, &mut joined_room, watcher, &tx_out).await;
+        assert_eq!(joined_room.as_deref(), Some("Rail"));
+        assert_eq!(state.inner.lock()["Rail"].spectators[0].name, "alice (2)");
+        assert!(std::iter::from_fn(|| rx_out.try_recv().ok())
+            .any(|m| matches!(m, ServerToClient::Info { message } if message.contains("alice (2)"))));
+
+        crate::route_cmd(ClientToServer::TakeSeat, &state, &mut joined_room, watcher, &tx_out).await;
+        let names: Vec<String> = state.inner.lock()["Rail"].players.iter().map(|p| p.name.clone()).collect();
+        assert_eq!(names, ["Alice", "alice (2)"]);
+
+        println!("✅ Spectator name check test passed");
+        println!("   - Invalid names refused before spectating");
+        println!("   - Duplicate names suffixed on the rail and kept when seated");
+    }
+
+    /// The muck window is a table option: created tables hold the reveal for
+    /// it, 0 reveals at once and overlong windows are refused
+    #[tokio::test]
+    async fn test_create_table_muck_window() {
+        let (state, _temp_dir) = create_test_state();
+        let (tx_out, mut rx_out) = mpsc::unbounded_channel();
+        let create = |name: &str, muck_window_secs| ClientToServer::CreateTable {
+            name: name.to_string(),
+            game_variant: GameVariant::SevenTwentySeven,
+            ante: 10,
+            limit_small: 10,
+            limit_big: 20,
+            max_raises: 3,
+            min_players: None,
+            rake_percent: 0,
+            rake_cap: None,
+            max_buyin: None,
+            deck_count: None,
+            spectator_chat_to_players: None,
+            muck_window_secs,
+            max_draw_rounds: None,
+            auto_dealer: false,
+            rabbit_hunt: false,
+        };
+
+        for (name, secs) in [("Muck Ten", Some(10)), ("Muck Off", Some(0)), ("Muck Unset", None)] {
+            let mut none = None;
+            crate::route_cmd(create(name, secs), &state, &mut none, Uuid::new_v4(), &tx_out).await;
+        }
+        {
+            let rooms = state.inner.lock();
+            assert_eq!(rooms["Muck Ten"].muck_window, Some(std::time::Duration::from_secs(10)));
+            assert_eq!(rooms["Muck Off"].muck_window, None);
+            assert_eq!(rooms["Muck Unset"].muck_window, None);
+        }
+        while rx_out.try_recv().is_ok() {}
+
+        let mut none = None;
+        crate::route_cmd(create("Muck Forever", Some(3600)), &state, &mut none, Uuid::new_v4(), &tx_out).await;
+        assert!(matches!(rx_out.try_recv(), Ok(ServerToClient::Error { .. })));
+        assert!(!state.inner.lock().contains_key("Muck Forever"));
+
+        println!("✅ Create table muck window test passed");
+        println!("   - Window applied in seconds, 0 or unset reveals at once");
+        println!("   - Overlong windows refused");
+    }
+
+    /// A draw round cap chosen at creation is kept by the table
+    #[tokio::test]
+    async fn test_create_table_draw_round_cap() {
+        let (state, _temp_dir) = create_test_state();
+        let (tx_out, mut rx_out) = mpsc::unbounded_channel();
+        let create = |name: &str, max_draw_rounds| ClientToServer::CreateTable {
+            name: name.to_string(),
+            game_variant: GameVariant::SevenTwentySeven,
+            ante: 10,
+            limit_small: 10,
+            limit_big: 20,
+            max_raises: 3,
+            min_players: None,
+            rake_percent: 0,
+            rake_cap: None,
+            max_buyin: None,
+            deck_count: None,
+            spectator_chat_to_players: None,
+            muck_window_secs: None,
+            max_draw_rounds,
+            auto_dealer: false,
+            rabbit_hunt: false,
+        };
+
+        let mut none = None;
+        crate::route_cmd(create("Four Rounds", Some(4)), &state, &mut none, Uuid::new_v4(), &tx_out).await;
+        assert_eq!(state.inner.lock()["Four Rounds"].max_draw_rounds, Some(4));
+        while rx_out.try_recv().is_ok() {}
+
+        let mut none = None;
+        crate::route_cmd(create("No Rounds", Some(0)), &state, &mut none, Uuid::new_v4(), &tx_out).await;
+        assert!(matches!(rx_out.try_recv(), Ok(ServerToClient::Error { .. })));
+        assert!(!state.inner.lock().contains_key("No Rounds"));
+
+        println!("✅ Create table draw round cap test passed");
+        println!("   - Cap applied to the new table, zero refused");
+    }
+
+    /// Tables created with auto_dealer skip the manual dealer pick
+    #[tokio::test]
+    async fn test_create_table_auto_dealer() {
+        let (state, _temp_dir) = create_test_state();
+        let (tx_out, _rx_out) = mpsc::unbounded_channel();
+        let create = |name: &str, auto_dealer| ClientToServer::CreateTable {
+            name: name.to_string(),
+            game_variant: GameVariant::SevenTwentySeven,
+            ante: 10,
+            limit_small: 10,
+            limit_big: 20,
+            max_raises: 3,
+            min_players: None,
+            rake_percent: 0,
+            rake_cap: None,
+            max_buyin: None,
+            deck_count: None,
+            spectator_chat_to_players: None,
+            muck_window_secs: None,
+            max_draw_rounds: None,
+            auto_dealer,
+            rabbit_hunt: false,
+        };
+
+        for (name, auto) in [("Auto Deal", true), ("Hand Picked", false)] {
+            let mut none = None;
+            crate::route_cmd(create(name, auto), &state, &mut none, Uuid::new_v4(), &tx_out).await;
+        }
+        assert!(state.inner.lock()["Auto Deal"].auto_dealer);
+        assert!(!state.inner.lock()["Hand Picked"].auto_dealer);
+
+        println!("✅ Create table auto dealer test passed");
+        println!("   - auto_dealer carried onto the new table");
+    }
+
+    /// Rabbit hunting is chosen when the table is created
+    #[tokio::test]
+    async fn test_create_table_rabbit_hunt() {
+        let (state, _temp_dir) = create_test_state();
+        let (tx_out, _rx_out) = mpsc::unbounded_channel();
+        let create = |name: &str, rabbit_hunt| ClientToServer::CreateTable {
+            name: name.to_string(),
+            game_variant: GameVariant::TexasHoldem,
+            ante: 10,
+            limit_small: 10,
+            limit_big: 20,
+            max_raises: 3,
+            min_players: None,
+            rake_percent: 0,
+            rake_cap: None,
+            max_buyin: None,
+            deck_count: None,
+            spectator_chat_to_players: None,
+            muck_window_secs: None,
+            max_draw_rounds: None,
+            auto_dealer: false,
+            rabbit_hunt,
+        };
+
+        for (name, hunt) in [("Rabbit Hunt", true), ("No Hunting", false)] {
+            let mut none = None;
+            crate::route_cmd(create(name, hunt), &state, &mut none, Uuid::new_v4(), &tx_out).await;
+        }
+        assert!(state.inner.lock()["Rabbit Hunt"].rabbit_hunt);
+        assert!(!state.inner.lock()["No Hunting"].rabbit_hunt);
+
+        println!("✅ Create table rabbit hunt test passed");
+        println!("   - rabbit_hunt carried onto the new table");
+    }
+
+    /// A connection lost mid-hand folds and sits the player out, keeping
+    /// the seat until the hand is over; in the lobby it frees the seat at once
+    #[tokio::test]
+    async fn test_disconnect_mid_hand_defers_removal() {
+        let (state, _temp_dir) = create_test_state();
+        let mut room = game::Room::new("Dropout Room".to_string());
+        room.game_variant = GameVariant::TexasHoldem;
+        let ids: Vec<Uuid> = (0..4).map(|i| seat(&mut room, &format!("Player {}", i + 1), 1000)).collect();
+        crate::start_hand(&mut room);
+        let first = room.to_act_seat;
+        let (on_turn, bystander) = (ids[first], ids[(first + 2) % 4]);
+        state.inner.lock().insert("Dropout Room".to_string(), room);
+        let joined = Some("Dropout Room".to_string());
+
+        // Dropping on their turn folds them and moves the action on
+        crate::disconnect(&state, &joined, on_turn).await;
+        {
+            let rooms = state.inner.lock();
+            let r = &rooms["Dropout Room"];
+            assert_eq!(r.players.len(), 4, "seat kept while the hand is on");
+            assert!(r.players[first].folded);
+            assert!(r.sitting_out.contains(&on_turn));
+            assert_ne!(r.to_act_seat, first);
+            assert_eq!(r.phase, Phase::Acting);
+        }
+
+        // Dropping out of turn folds them without taking the turn
+        let to_act = state.inner.lock()["Dropout Room"].to_act_seat;
+        crate::disconnect(&state, &joined, bystander).await;
+        {
+            let rooms = state.inner.lock();
+            let r = &rooms["Dropout Room"];
+            assert_eq!(r.to_act_seat, to_act);
+            assert_eq!(game::alive_seats(r).len(), 2);
+        }
+
+        // Once the hand ends both seats are freed
+        let folder = {
+            let rooms = state.inner.lock();
+            let r = &rooms["Dropout Room"];
+            r.players[r.to_act_seat].id
+        };
+        crate::with_room(&state, "Dropout Room", |r| crate::player_fold(r, folder));
+        {
+            let rooms = state.inner.lock();
+            let r = &rooms["Dropout Room"];
+            assert_eq!(r.phase, Phase::Comments);
+            assert_eq!(r.players.len(), 2);
+            assert!(r.departed.is_empty());
+        }
+
+        // Between hands a dropped player is unseated straight away
+        crate::disconnect(&state, &joined, folder).await;
+        assert_eq!(state.inner.lock()["Dropout Room"].players.len(), 1);
+
+        println!("✅ Mid-hand disconnect test passed");
+        println!("   - Dropped player folded and sat out, turn moves on");
+        println!("   - Seat freed once the hand is over");
+        println!("   - Lobby/between-hands drop unseats at once");
+    }
+
+    /// Illegal betting actions come back as coded errors instead of no-ops
+    #[test]
+    fn test_illegal_betting_actions_rejected() {
+        let mut room = game::Room::new("Rejection Room".to_string());
+        room.game_variant = GameVariant::TexasHoldem;
+        let mut receivers = vec![];
+        for i in 0..3 {
+            receivers.push(seat_with_rx(&mut room, &format!("Player {}", i + 1), 1000).1);
+        }
+        crate::start_hand(&mut room);
+
+        let opener = room.to_act_seat;
+        let opener_id = room.players[opener].id;
+        crate::player_bet_or_raise(&mut room, opener_id, false);
+
+        let seat = room.to_act_seat;
+        let id = room.players[seat].id;
+        for rx in receivers.iter_mut() {
+            while rx.try_recv().is_ok() {}
+        }
+        let last_error = |rx: &mut mpsc::UnboundedReceiver<ServerToClient>| {
+            let mut err = None;
+            while let Ok(msg) = rx.try_recv() {
+                if let ServerToClient::Error { code, .. } = msg {
+                    err = Some(code);
+                }
+            }
+            err
+        };
+
+        // Facing a bet: can't check, and can't open another bet
+        crate::player_check(&mut room, id);
+        assert_eq!(last_error(&mut receivers[seat]), Some(ErrorCode::CheckFacingBet));
+        crate::player_bet_or_raise(&mut room, id, false);
+        assert_eq!(last_error(&mut receivers[seat]), Some(ErrorCode::BetAlreadyOpen));
+        assert_eq!(room.to_act_seat, seat, "rejected actions don't move the turn");
+
+        // Acting out of turn is reported too
+        crate::player_call(&mut room, opener_id);
+        assert_eq!(last_error(&mut receivers[opener]), Some(ErrorCode::NotYourTurn));
+
+        println!("✅ Illegal betting action test passed");
+        println!("   - Check facing a bet rejected with CheckFacingBet");
+        println!("   - Bet when a bet exists rejected with BetAlreadyOpen");
+    }
+
+    /// A 7/27 player holding seven cards can't draw an eighth and is told why
+    #[test]
+    fn test_take_card_blocked_at_max_cards() {
+        let mut room = game::Room::new("Max Cards Room".to_string());
+        let mut receivers = vec![];
+        for i in 0..2 {
+            receivers.push(seat_with_rx(&mut room, &format!("Player {}", i + 1), 1000).1);
+        }
+        crate::start_hand(&mut room);
+        assert!(!room.in_betting);
+
+        // Low cards so the hand can't bust; seven in total
+        let seat = room.to_act_seat;
+        let ace = Card { rank: Rank::Ace, suit: Suit::Spades, face_up: false };
+        room.players[seat].up_cards = vec![Card { face_up: true, ..ace }];
+        room.players[seat].down_cards = vec![ace; 6];
+        let id = room.players[seat].id;
+        while receivers[seat].try_recv().is_ok() {}
+
+        crate::player_take_card(&mut room, id);
+
+        assert_eq!(room.players[seat].down_cards.len(), 6, "no eighth card dealt");
+        assert_eq!(room.to_act_seat, seat, "still this player's turn");
+        let code = std::iter::from_fn(|| receivers[seat].try_recv().ok()).find_map(|m| match m {
+            ServerToClient::Error { code, .. } => Some(code),
+            _ => None,
+        });
+        assert_eq!(code, Some(ErrorCode::MaxCardsReached));
+
+        println!("✅ Max cards test passed");
+        println!("   - Eighth card refused with MaxCardsReached");
+    }
+
+    /// A 7/27 table capped at three rounds shows down after the third
+    /// betting round even though nobody has stood
+    #[test]
+    fn test_max_draw_rounds_forces_showdown() {
+        let mut room = game::Room::new("Capped Room".to_string());
+        room.max_draw_rounds = Some(3);
+        for i in 0..2 {
+            seat(&mut room, &format!("Player {}", i + 1), 1000);
+        }
+        crate::start_hand(&mut room);
+
+        for round in 1..=3 {
+            assert_eq!(room.round, round);
+            assert_eq!(room.phase, Phase::Acting);
+
+            // Everyone has drawn (nobody stands) → betting round
+            room.draw_acted = vec![true; room.players.len()];
+            crate::advance_after_draw_action(&mut room);
+            assert!(room.in_betting);
+
+            // Check it round
+            for _ in 0..room.players.len() {
+                let id = room.players[room.to_act_seat].id;
+                crate::player_check(&mut room, id);
+            }
+            assert!(room.players.iter().all(|p| !p.standing && !p.folded));
+        }
+
+        assert_eq!(room.phase, Phase::Comments, "showdown after the third round");
+
+        println!("✅ Draw round cap test passed");
+        println!("   - Showdown forced after 3 rounds with nobody standing");
+    }
+
+    /// Malformed and oversized frames are rejected with a bounded, informative error
+    #[test]
+    fn test_malformed_command_feedback() {
+        let (code, msg) = crate::decode_command("\"NoSuchCommand\"", crate::MAX_FRAME_BYTES).unwrap_err();
+        assert_eq!(code, ErrorCode::MalformedCommand);
+        assert!(msg.starts_with("bad json: "));
+        assert!(msg.contains("NoSuchCommand"), "error should name the bad variant: {}", msg);
+
+        let (code, msg) = crate::decode_command("not json at all", crate::MAX_FRAME_BYTES).unwrap_err();
+        assert_eq!(code, ErrorCode::MalformedCommand);
+        assert!(msg.contains("line 1"), "error should locate the problem: {}", msg);
+
+        // A huge unknown tag is echoed back only up to the truncation limit
+        let long_tag = format!("\"{}\"", "x".repeat(10_000));
+        let (_, msg) = crate::decode_command(&long_tag, crate::MAX_FRAME_BYTES).unwrap_err();
+        assert!(msg.chars().count() <= "bad json: ".len() + crate::MAX_DECODE_ERROR_CHARS + 1);
+        assert!(msg.ends_with('…'));
+
+        let oversized = "x".repeat(crate::MAX_FRAME_BYTES + 1);
+        let (code, msg) = crate::decode_command(&oversized, crate::MAX_FRAME_BYTES).unwrap_err();
+        assert_eq!(code, ErrorCode::FrameTooLarge);
+        assert!(msg.len() < 100);
+
+        assert!(crate::decode_command("\"SitReady\"", crate::MAX_FRAME_BYTES).is_ok());
+
+        println!("✅ Malformed command feedback test passed");
+        println!("   - Parse errors carry truncated serde detail");
+        println!("   - Oversized frames are refused before parsing");
+    }
+
+    /// An over-limit frame on a live socket gets a FrameTooLarge error and the socket is closed
+    #[tokio::test]
+    async fn test_oversized_frame_closes_socket() {
+        use futures::{SinkExt, StreamExt};
+        use tokio_tungstenite::tungstenite::Message;
+
+        let (mut state, _temp_dir) = create_test_state();
+        state.socket.max_frame_bytes = 1024;
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, crate::app_router(state)).await.unwrap();
+        });
+
+        let (mut ws, _) = tokio_tungstenite::connect_async(format!("ws://{}/ws", addr)).await.unwrap();
+        let recv = |msg: Option<Result<Message, _>>| -> Option<ServerToClient> {
+            match msg {
+                Some(Ok(Message::Text(t))) => serde_json::from_str(&t).ok(),
+                _ => None,
+            }
+        };
+        assert!(matches!(recv(ws.next().await), Some(ServerToClient::Hello { .. })));
+
+        // Under the limit but malformed: error, socket stays open
+        ws.send(Message::Text("{".into())).await.unwrap();
+        match recv(ws.next().await) {
+            Some(ServerToClient::Error { code, .. }) => assert_eq!(code, ErrorCode::MalformedCommand),
+            other => panic!("expected MalformedCommand, got {:?}", other),
+        }
+
+        ws.send(Message::Text("x".repeat(1500))).await.unwrap();
+        match recv(ws.next().await) {
+            Some(ServerToClient::Error { code, message }) => {
+                assert_eq!(code, ErrorCode::FrameTooLarge);
+                assert!(message.contains("max 1024"));
+            }
+            other => panic!("expected FrameTooLarge, got {:?}", other),
+        }
+
+        let closed = tokio::time::timeout(std::time::Duration::from_secs(2), ws.next()).await.unwrap();
+        assert!(!matches!(closed, Some(Ok(Message::Text(_)))), "socket should be closed after an oversized frame");
+
+        println!("✅ Oversized frame test passed");
+        println!("   - Frames over the configured limit get FrameTooLarge");
+        println!("   - The server drops the connection afterwards");
+    }
+
+    /// A client that stops answering pings is dropped from its seat; one that keeps reading stays
+    #[tokio::test]
+    async fn test_silent_socket_dropped_after_pong_timeout() {
+        use futures::{SinkExt, StreamExt};
+        use tokio_tungstenite::tungstenite::Message;
+
+        let (mut state, _temp_dir) = create_test_state();
+        state.socket.ping_interval = std::time::Duration::from_millis(50);
+        state.socket.pong_timeout = std::time::Duration::from_millis(200);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server_state = state.clone();
+        tokio::spawn(async move {
+            axum::serve(listener, crate::app_router(server_state)).await.unwrap();
+        });
+
+        let join = |name: &str| {
+            Message::Text(serde_json::to_string(&ClientToServer::Join { room: "ping".into(), name: name.into() }).unwrap())
+        };
+        let url = format!("ws://{}/ws", addr);
+
+        // Stub that joins and then never reads again, so it never pongs
+        let (mut silent, _) = tokio_tungstenite::connect_async(&url).await.unwrap();
+        silent.next().await.unwrap().unwrap();
+        silent.send(join("Silent")).await.unwrap();
+
+        // Well-behaved client: keeps reading, so tungstenite answers each ping
+        let (mut live, _) = tokio_tungstenite::connect_async(&url).await.unwrap();
+        live.next().await.unwrap().unwrap();
+        live.send(join("Live")).await.unwrap();
+        tokio::spawn(async move { while let Some(Ok(_)) = live.next().await {} });
+
+        let seated = |state: &crate::AppState| -> Vec<String> {
+            state.inner.lock().get("ping").map(|r| r.players.iter().map(|p| p.name.clone()).collect()).unwrap_or_default()
+        };
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        assert_eq!(seated(&state).len(), 2);
+
+        tokio::time::sleep(std::time::Duration::from_millis(600)).await;
+        assert_eq!(seated(&state), vec!["Live".to_string()], "silent socket should be dropped");
+        drop(silent);
+
+        println!("✅ Keep-alive test passed");
+        println!("   - Socket without pongs dropped after the timeout");
+        println!("   - Responsive socket keeps its seat");
+    }
+
+    /// A latency probe comes straight back with the same nonce
+    #[tokio::test]
+    async fn test_ping_answered_with_pong() {
+        use futures::{SinkExt, StreamExt};
+        use tokio_tungstenite::tungstenite::Message;
+
+        let (state, _temp_dir) = create_test_state();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, crate::app_router(state)).await.unwrap();
+        });
+
+        let (mut ws, _) = tokio_tungstenite::connect_async(format!("ws://{}/ws", addr)).await.unwrap();
+        ws.next().await.unwrap().unwrap(); // Hello
+
+        let ping = serde_json::to_string(&ClientToServer::Ping { nonce: 42 }).unwrap();
+        ws.send(Message::Text(ping)).await.unwrap();
+        match ws.next().await {
+            Some(Ok(Message::Text(t))) => match serde_json::from_str(&t).unwrap() {
+                ServerToClient::Pong { nonce } => assert_eq!(nonce, 42),
+                other => panic!("expected Pong, got {:?}", other),
+            },
+            other => panic!("expected a text frame, got {:?}", other),
+        }
+
+        println!("✅ Ping/pong test passed");
+        println!("   - Ping answered with a Pong carrying the same nonce");
+    }
+
     /// Test scoring functions for 7/27 variant
     #[test]
     fn test_seven_twenty_seven_scoring() {
@@ -1142,6 +4196,7 @@ mod server_tests {
             ],
             ready: true,
             committed_round: 100,
+            status: None,
             tx,
         });
 